@@ -0,0 +1,183 @@
+//! A global allocator that counts what it does, so chapter 4's claims (a move doesn't allocate,
+//! `clone` copies the heap data) show up as numbers.
+//!
+//! The library only provides it; a binary opts in with `#[global_allocator]`, as this crate's
+//! does when built with the `count-allocations` feature:
+//!
+//! ```text
+//! cargo run --features count-allocations -- run 4
+//! ```
+//!
+//! Counts are kept per thread, so what one section does isn't mixed up with other threads
+//! (such as other tests running at the same time).
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::ops::Sub;
+
+thread_local! {
+    // `const` initializers and no destructors, so touching these never allocates itself.
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static REALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static DEALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static BYTES: Cell<u64> = const { Cell::new(0) };
+}
+
+fn bump(counter: &'static std::thread::LocalKey<Cell<u64>>, by: usize) {
+    // `try_with` fails only while the thread is being torn down; those calls go uncounted.
+    let _ = counter.try_with(|count| count.set(count.get() + by as u64));
+}
+
+/// Hands every request to the system allocator, counting it on the way.
+pub struct CountingAllocator;
+
+/// Whether [`CountingAllocator`] is the global allocator of this program.
+pub fn is_installed() -> bool {
+    let (boxed, counts) = Counts::during(|| std::hint::black_box(Box::new(0u8)));
+    drop(boxed);
+    counts.allocations > 0
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        bump(&ALLOCATIONS, 1);
+        bump(&BYTES, layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        bump(&ALLOCATIONS, 1);
+        bump(&BYTES, layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        bump(&REALLOCATIONS, 1);
+        bump(&BYTES, new_size.saturating_sub(layout.size()));
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        bump(&DEALLOCATIONS, 1);
+        System.dealloc(ptr, layout)
+    }
+}
+
+/// What this thread has asked of the allocator. Subtract two of them to get what happened in
+/// between.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Counts {
+    pub allocations: u64,
+    pub reallocations: u64,
+    pub deallocations: u64,
+    /// Bytes asked for by allocations, plus what reallocations grew by.
+    pub bytes: u64,
+}
+
+impl Counts {
+    /// The totals so far on this thread; all zero when counting isn't enabled.
+    pub fn now() -> Counts {
+        let get = |counter: &'static std::thread::LocalKey<Cell<u64>>| {
+            counter.try_with(Cell::get).unwrap_or(0)
+        };
+        Counts {
+            allocations: get(&ALLOCATIONS),
+            reallocations: get(&REALLOCATIONS),
+            deallocations: get(&DEALLOCATIONS),
+            bytes: get(&BYTES),
+        }
+    }
+
+    /// The counts for running `f`, along with what it returned.
+    pub fn during<T>(f: impl FnOnce() -> T) -> (T, Counts) {
+        let before = Counts::now();
+        let result = f();
+        (result, Counts::now() - before)
+    }
+}
+
+impl Sub for Counts {
+    type Output = Counts;
+
+    fn sub(self, earlier: Counts) -> Counts {
+        Counts {
+            allocations: self.allocations - earlier.allocations,
+            reallocations: self.reallocations - earlier.reallocations,
+            deallocations: self.deallocations - earlier.deallocations,
+            bytes: self.bytes - earlier.bytes,
+        }
+    }
+}
+
+fn plural(count: u64, noun: &str) -> String {
+    format!("{} {}{}", count, noun, if count == 1 { "" } else { "s" })
+}
+
+impl fmt::Display for Counts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}, {}, {}, {}",
+            plural(self.allocations, "allocation"),
+            plural(self.reallocations, "reallocation"),
+            plural(self.deallocations, "deallocation"),
+            plural(self.bytes, "byte")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_installed, Counts};
+
+    #[test]
+    fn counts_subtract_and_display() {
+        let later = Counts {
+            allocations: 3,
+            reallocations: 1,
+            deallocations: 2,
+            bytes: 21,
+        };
+        let earlier = Counts {
+            allocations: 2,
+            reallocations: 1,
+            deallocations: 0,
+            bytes: 16,
+        };
+        assert_eq!(
+            (later - earlier).to_string(),
+            "1 allocation, 0 reallocations, 2 deallocations, 5 bytes"
+        );
+    }
+
+    #[test]
+    fn a_move_does_not_allocate_but_a_clone_does() {
+        if !is_installed() {
+            assert_eq!(
+                Counts::during(|| String::from("hello")).1,
+                Counts::default()
+            );
+            return;
+        }
+
+        let (s1, made) = Counts::during(|| String::from("hello"));
+        assert_eq!((made.allocations, made.bytes), (1, 5));
+
+        let (s2, moved) = Counts::during(move || s1);
+        assert_eq!(moved, Counts::default());
+
+        let (s3, cloned) = Counts::during(|| s2.clone());
+        assert_eq!((cloned.allocations, cloned.bytes), (1, 5));
+
+        let ((), dropped) = Counts::during(move || drop((s2, s3)));
+        assert_eq!(dropped.deallocations, 2);
+
+        let (_, copied) = Counts::during(|| {
+            let x = 5;
+            let y = x;
+            x + y
+        });
+        assert_eq!(copied, Counts::default());
+    }
+}
@@ -8,3 +8,106 @@ pub mod chapter08;
 pub mod chapter09;
 pub mod chapter10;
 pub mod chapter11;
+
+use std::io::{self, Write};
+
+use chapter02::c02;
+use chapter03::c03;
+use chapter04::c04;
+use chapter05::c05;
+use chapter06::c06;
+use chapter07::c07;
+use chapter08::c08;
+use chapter09::c09;
+use chapter10::c10;
+use chapter11::c11;
+
+/// The signature every section has: it writes what it prints to `out`.
+pub type SectionFn = fn(&mut dyn Write) -> io::Result<()>;
+
+/// A named piece of a chapter that can be run on its own.
+pub struct Section {
+    name: &'static str,
+    run: SectionFn,
+}
+
+impl Section {
+    pub const fn new(name: &'static str, run: SectionFn) -> Section {
+        Section { name, run }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn run(&self, out: &mut dyn Write) -> io::Result<()> {
+        (self.run)(out)
+    }
+}
+
+/// A chapter of the book: a number, a title and the ordered sections that make it up.
+pub trait Chapter {
+    fn number(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    fn sections(&self) -> &'static [Section];
+
+    fn section(&self, name: &str) -> Option<&'static Section> {
+        self.sections()
+            .iter()
+            .find(|section| section.name() == name)
+    }
+
+    fn heading(&self) -> String {
+        format!("*** Chapter {} - {} ***", self.number(), self.title())
+    }
+
+    fn run(&self, out: &mut dyn Write) -> io::Result<()> {
+        writeln!(out, "{}", self.heading())?;
+
+        for section in self.sections() {
+            section.run(out)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub const CHAPTERS: &[&dyn Chapter] = &[
+    &c02::GuessTheNumber,
+    &c03::CommonConcepts,
+    &c04::Ownership,
+    &c05::Structs,
+    &c06::Enums,
+    &c07::PackagesCratesModules,
+    &c08::CommonCollections,
+    &c09::ErrorHandling,
+    &c10::GenericsTraitsLifetimes,
+    &c11::WritingAutomatedTests,
+];
+
+pub fn find(number: u8) -> Option<&'static dyn Chapter> {
+    CHAPTERS
+        .iter()
+        .copied()
+        .find(|chapter| chapter.number() == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find, CHAPTERS};
+
+    #[test]
+    fn chapters_are_registered_in_order() {
+        let numbers: Vec<u8> = CHAPTERS.iter().map(|chapter| chapter.number()).collect();
+        assert_eq!(numbers, (2..=11).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn sections_are_addressable_by_name() {
+        let chapter = find(10).unwrap();
+        assert!(chapter.section("lifetime_elision").is_some());
+        assert!(chapter.section("no_such_section").is_none());
+    }
+}
@@ -1,48 +1,40 @@
 pub mod c02 {
 
-    use rand::Rng;
-    use std::cmp::Ordering;
-    use std::io;
-
-    pub fn guessing_game() {
-        println!("*** Chapter 2 - Guess the Number! ***");
-
-        let secret_number = rand::thread_rng().gen_range(1..101);
-        let mut attempts = 1;
-
-        loop {
-            // Using a ! means that you’re calling a macro instead of a normal function.
-            println!("Please input your guess.");
-
-            // In Rust, variables are immutable by default
-            let mut guess = String::new(); // String is UTF-8 encoded
-
-            io::stdin()
-                .read_line(&mut guess) // Returns io::Result
-                .expect("Failed to read line");
-
-            // Can "shadow" the previous value of guess with a new one.
-            // Used in situations in which you want to convert a value from one type to another type.
-            let guess: u32 = match guess.trim().parse() {
-                Ok(num) => num,
-                Err(_) => continue,
-                // Switching from an expect call to a match expression is how you generally
-                // move from crashing on an error to handling the error.
-            };
-
-            match guess.cmp(&secret_number) {
-                Ordering::Less => println!("Too small!"),
-                Ordering::Greater => println!("Too big!"),
-                Ordering::Equal => {
-                    println!("You guessed the number after {} attempts!", attempts);
-                    break;
-                }
-            }
-
-            println!("You guessed: {}", guess);
-            attempts += 1;
+    use std::io::{self, Write};
+
+    use crate::chapters::{Chapter, Section};
+    use crate::guessing_game::GuessingGame;
+
+    const SECTIONS: &[Section] = &[Section::new("play", play)];
+
+    pub struct GuessTheNumber;
+
+    impl Chapter for GuessTheNumber {
+        fn number(&self) -> u8 {
+            2
         }
 
-        println!("The secret number is: {}", secret_number);
+        fn title(&self) -> &'static str {
+            "Guess the Number!"
+        }
+
+        fn sections(&self) -> &'static [Section] {
+            SECTIONS
+        }
+    }
+
+    pub fn guessing_game(out: &mut dyn Write) -> io::Result<()> {
+        GuessTheNumber.run(out)
+    }
+
+    fn play(out: &mut dyn Write) -> io::Result<()> {
+        // The game loop itself lives in `guessing_game::GuessingGame`, which takes its RNG,
+        // input and output as parameters so it can be tested; here we plug in the real ones.
+        let mut game = GuessingGame::new(&mut rand::thread_rng());
+
+        let stdin = io::stdin();
+        game.play(stdin.lock(), out)?;
+
+        Ok(())
     }
 }
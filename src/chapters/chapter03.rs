@@ -1,40 +1,68 @@
 pub mod c03 {
-    pub fn common_concepts() {
-        println!("*** Chapter 3 - Common Concepts ***");
+    use std::io::{self, Write};
 
-        // Shadowing
-        shadowing();
+    use crate::chapters::{Chapter, Section};
+    use crate::exercises::{fibonacci, CumulativeSong, Temperature};
+    use crate::inspect::Inspection;
 
+    const SECTIONS: &[Section] = &[
+        // Shadowing
+        Section::new("shadowing", shadowing),
         // Data Types
-        data_types();
-
+        Section::new("data_types", data_types),
         // Functions
-        functions();
-
+        Section::new("functions", functions),
         // Control Flow
-        control_flow();
+        Section::new("control_flow", control_flow),
+        Section::new("if_in_let_statement", if_in_let_statement),
+        // Summary (exercises)
+        Section::new("temperature", temperature),
+        Section::new("fibonacci", fibonacci_numbers),
+        Section::new("carol", carol),
+    ];
+
+    pub struct CommonConcepts;
+
+    impl Chapter for CommonConcepts {
+        fn number(&self) -> u8 {
+            3
+        }
+
+        fn title(&self) -> &'static str {
+            "Common Concepts"
+        }
+
+        fn sections(&self) -> &'static [Section] {
+            SECTIONS
+        }
+    }
+
+    pub fn common_concepts(out: &mut dyn Write) -> io::Result<()> {
+        CommonConcepts.run(out)
     }
 
-    fn shadowing() {
+    fn shadowing(out: &mut dyn Write) -> io::Result<()> {
         // We can _shadow_ a variable by using the same variable’s name
         // and repeating the use of the let keyword as follows:
         let x = 5;
         let x = x + 1;
         let x = x * 2;
-        println!("The value of x is {}", x);
+        writeln!(out, "The value of x is {}", x)?;
 
         // Shadowing spares us from having to come up with different names,
         // such as spaces_str and spaces_num; instead, we can reuse the same name:
         let spaces = "    "; // str
         let spaces = spaces.len(); // usize
-        println!("There are {} spaces in there", spaces);
+        writeln!(out, "There are {} spaces in there", spaces)?;
 
         // However, if we try to use mut for this, as shown here, we’ll get a compile-time error:
         // let mut spaces = "   ";
         // spaces = spaces.len();
+
+        Ok(())
     }
 
-    fn data_types() {
+    fn data_types(out: &mut dyn Write) -> io::Result<()> {
         // Rust -> Statically typed (must know types of all variables at compile time)
 
         // Scalar Types -> Single value (int, float, bool, char)
@@ -56,18 +84,33 @@ pub mod c03 {
         let f1 = 2.0; // f64 (default)
                       // let f2: f32 = 3.0; // f32
 
-        fn print_type_of<T>(_: &T) {
-            println!("{}", std::any::type_name::<T>())
+        // The book's `print_type_of`, on top of `inspect!`, which also knows the type's size and
+        // alignment (and whether it is Copy, needs drop, and what its bytes are).
+        fn print_type_of(out: &mut dyn Write, report: Inspection) -> io::Result<()> {
+            writeln!(
+                out,
+                "{}, {} bytes, aligned to {}",
+                report.type_name, report.size, report.align
+            )
         }
 
-        println!("f1 type:");
-        print_type_of(&f1); // f64 (default)
+        writeln!(out, "f1 type:")?;
+        print_type_of(out, crate::inspect!(f1))?; // f64 (default)
 
         // Char:
 
-        // let heart_eyed_cat = '😻';
+        let heart_eyed_cat = '😻';
         // Rust’s char type is four bytes in size and represents a Unicode Scalar Value, which means
         // it can represent a lot more than just ASCII. Accented letters; Chinese, Japanese, and Korean characters
+        writeln!(
+            out,
+            "{} is U+{:X}: a {}-byte char, {} bytes of UTF-8 and {} UTF-16 units",
+            heart_eyed_cat,
+            heart_eyed_cat as u32,
+            std::mem::size_of_val(&heart_eyed_cat),
+            heart_eyed_cat.len_utf8(),
+            heart_eyed_cat.len_utf16()
+        )?;
 
         // ==========================================================================
 
@@ -78,35 +121,41 @@ pub mod c03 {
         // Tuples have a fixed length: once declared, they cannot grow or shrink in size.
 
         let tup: (i32, f64, u8) = (500, 6.4, 1); // Each position in the tuple has a type
-        println!("tup.0 type:");
-        print_type_of(&tup.0);
+        writeln!(out, "tup.0 type:")?;
+        print_type_of(out, crate::inspect!(tup.0))?;
         // The variable tup binds to the entire tuple, because a tuple is considered a single compound element.
         // We can use pattern matching to destructure a tuple value:
         let (_, y, _) = tup; // _destructuring_
-        println!("The value of y is: {}", y);
+        writeln!(out, "The value of y is: {}", y)?;
 
         // Array:
 
         let a = [1, 2, 3, 4, 5]; // Allocated in stack (not heap)
         let a: [i32; 5] = [1, 2, 3, 4, 5];
         let a = [3; 5]; // [3, 3, 3, 3, 3]
-        println!("Value of first element in `a`: {}", a[0]);
+        writeln!(out, "Value of first element in `a`: {}", a[0])?;
+
+        Ok(())
     }
 
-    fn functions() {
-        fn another_function() {
-            println!("Another function.");
-            another_function_with_params(42);
+    fn functions(out: &mut dyn Write) -> io::Result<()> {
+        fn another_function(out: &mut dyn Write) -> io::Result<()> {
+            writeln!(out, "Another function.")?;
+            another_function_with_params(out, 42)
         }
 
-        fn another_function_with_params(x: i32) {
-            println!("The answer to life the universe and everything is {}", x);
+        fn another_function_with_params(out: &mut dyn Write, x: i32) -> io::Result<()> {
+            writeln!(
+                out,
+                "The answer to life the universe and everything is {}",
+                x
+            )
         }
 
-        another_function();
-        expression(); // Calling a function before declaring it.
+        another_function(out)?;
+        expression(out)?; // Calling a function before declaring it.
 
-        fn expression() {
+        fn expression(out: &mut dyn Write) -> io::Result<()> {
             let x = 5;
 
             let y = {
@@ -115,29 +164,31 @@ pub mod c03 {
                       // !!! EXPRESSIONS DO NOT INCLUDE SEMICOLONS !!!
             };
 
-            println!("The value of y is {}", y);
-            println!("The value of x is still {}", x);
+            writeln!(out, "The value of y is {}", y)?;
+            writeln!(out, "The value of x is still {}", x)?;
 
             // You can return early from a function by using the return keyword and specifying a value,
             // but most functions return the last expression implicitly:
             let five = five();
-            println!("The value of five is {}", five);
+            writeln!(out, "The value of five is {}", five)
         }
 
         fn five() -> i32 {
             5
         }
+
+        Ok(())
     }
 
-    fn control_flow() {
+    fn control_flow(out: &mut dyn Write) -> io::Result<()> {
         let number = 3;
 
         if number != 0 {
-            println!("Not zero");
+            writeln!(out, "Not zero")?;
         } else if number % 2 == 0 {
-            println!("Even!");
+            writeln!(out, "Even!")?;
         } else {
-            println!("Odd!");
+            writeln!(out, "Odd!")?;
         }
 
         // While:
@@ -151,21 +202,47 @@ pub mod c03 {
         // Range:
 
         for number in (1..4).rev() {
-            println!("{}!", number);
+            writeln!(out, "{}!", number)?;
         }
-        println!("LIFTOFF!!!");
+        writeln!(out, "LIFTOFF!!!")?;
 
-        if_in_let_statement();
+        Ok(())
     }
 
-    fn if_in_let_statement() {
+    fn if_in_let_statement(out: &mut dyn Write) -> io::Result<()> {
         let condition = true;
         let number = if condition { 5 } else { 6 };
 
-        println!("The value is {}", number);
+        writeln!(out, "The value is {}", number)?;
 
         // let number = if condition { 5 } else { "six" };
         // Wouldn't compile.
         // Rust needs to know at compile time what type the number variable is.
+
+        Ok(())
+    }
+
+    // The chapter ends by suggesting three programs to write. They live in `crate::exercises`,
+    // with tests.
+
+    fn temperature(out: &mut dyn Write) -> io::Result<()> {
+        for &fahrenheit in &[-40.0, 32.0, 98.6, 212.0] {
+            let temperature = Temperature::Fahrenheit(fahrenheit);
+            writeln!(out, "{} = {}", temperature, temperature.convert())?;
+        }
+
+        Ok(())
+    }
+
+    fn fibonacci_numbers(out: &mut dyn Write) -> io::Result<()> {
+        for n in &[1, 2, 10, 50, 100, 200] {
+            writeln!(out, "F({}) = {}", n, fibonacci(*n))?;
+        }
+
+        Ok(())
+    }
+
+    fn carol(out: &mut dyn Write) -> io::Result<()> {
+        CumulativeSong::twelve_days_of_christmas().render(out)
     }
 }
@@ -1,37 +1,54 @@
 pub mod c04 {
-    pub fn ownership() {
-        println!("*** Chapter 4 - Ownership ***");
-        // Intro to Ownership
-        ownership_intro();
+    use std::io::{self, Write};
 
-        // The String Type
-        string_type();
+    use crate::chapters::{Chapter, Section};
+    use crate::trace::Tracer;
+    use crate::words;
 
+    const SECTIONS: &[Section] = &[
+        // Intro to Ownership
+        Section::new("ownership_intro", ownership_intro),
+        // The String Type
+        Section::new("string_type", string_type),
         // Memory and Allocation:
-        memory_allocation();
-
+        Section::new("memory_allocation", memory_allocation),
         // Ways Variables and Data Interact
-        _move(); // Prefixed with underscore since `move` is a keyword.
-        clone();
-        copy();
-
+        Section::new("_move", _move),
+        Section::new("clone", clone),
+        Section::new("copy", copy),
         // OWNERSHIP and FUNCTIONS
-        ownership_functions();
-
+        Section::new("ownership_functions", ownership_functions),
         // REFERENCES and BORROWING
-        references_and_borrowing();
-
+        Section::new("references_and_borrowing", references_and_borrowing),
         // MUTABLE REFERENCES
-        mutable_references();
-
+        Section::new("mutable_references", mutable_references),
         // DANGLING REFERENCES
-        dangling_references();
-
+        Section::new("dangling_references", dangling_references),
         // SLICE
-        slices();
+        Section::new("slices", slices),
+    ];
+
+    pub struct Ownership;
+
+    impl Chapter for Ownership {
+        fn number(&self) -> u8 {
+            4
+        }
+
+        fn title(&self) -> &'static str {
+            "Ownership"
+        }
+
+        fn sections(&self) -> &'static [Section] {
+            SECTIONS
+        }
     }
 
-    fn ownership_intro() {
+    pub fn ownership(out: &mut dyn Write) -> io::Result<()> {
+        Ownership.run(out)
+    }
+
+    fn ownership_intro(out: &mut dyn Write) -> io::Result<()> {
         // let mut guess = String::new();
 
         // Ownership enables Rust to make memory safety guarantees without needing a garbage collector.
@@ -47,24 +64,38 @@ pub mod c04 {
 
         // The types covered previously are all stored on the stack and popped off the stack when their scope is over.
         // We want to look at data that is stored on the heap and explore how Rust knows when to clean up that data, hence...
+
+        Ok(())
     }
 
-    fn string_type() {
+    fn string_type(out: &mut dyn Write) -> io::Result<()> {
         // The String Type
 
         let mut s = String::from("hello");
 
         s.push_str(", world!"); // push_str() appends a literal to a String
 
-        println!("{}", s); // This will print `hello, world!`
+        writeln!(out, "{}", s)?; // This will print `hello, world!`
 
         let s = String::from("hello");
         let s2 = s;
         // ^ Here a `move` occurs because `s` has type `String`, which does not implement the `Copy` trait
-        // println!("{}", s); // ERROR: value borrowed after move
+        // writeln!(out, "{}", s)?; // ERROR: value borrowed after move
+
+        // `Traced` logs what happens to a value, so we can watch the String live and die:
+        let trace = Tracer::new();
+        {
+            let _scope = trace.scope("string_type");
+            let mut s = trace.track("s", String::from("hello"));
+            s.push_str(", world!");
+            trace.note(&format!("s is now {:?}", *s));
+        } // <- s is dropped here, at the closing curly bracket
+        trace.render(out)?;
+
+        Ok(())
     }
 
-    fn memory_allocation() {
+    fn memory_allocation(out: &mut dyn Write) -> io::Result<()> {
         let _s = String::from("hello");
         // ^ Actual String, not literal
         // When a variable goes out of scope, Rust calls the 'drop' function for us.
@@ -72,44 +103,58 @@ pub mod c04 {
 
         // Allocating Memory
         let x = Box::new(5);
-        println!("The value of x is: {}", x);
+        writeln!(out, "The value of x is: {}", x)?;
 
         // Allocating Memory on the Stack
         let y = 5;
-        println!("The value of y is: {}", y);
+        writeln!(out, "The value of y is: {}", y)?;
 
         // Allocating Memory on the Heap
         let z = String::from("Hello");
-        println!("The value of z is: {}", z);
+        writeln!(out, "The value of z is: {}", z)?;
+
+        Ok(())
     }
 
-    fn _move() {
+    fn _move(out: &mut dyn Write) -> io::Result<()> {
         // Try to use s1 after s2 is created:
         let s1 = String::from("hello");
         let _s2 = s1;
-        // println!("{}, world!", s1);
+        // writeln!(out, "{}, world!", s1)?;
         // -> Error! ^^ value used here after move
         // ... It invalidates the first variable (Move).
         // Instead of trying to copy the allocated memory, Rust considers s1 to no longer be valid.
         // With only s2 valid, when it goes out of scope, it alone will free the memory.
 
+        let trace = Tracer::new();
+        {
+            let _scope = trace.scope("_move");
+            let s1 = trace.track("s1", String::from("hello"));
+            let _s2 = s1.moved("s2");
+        } // <- only s2 is dropped: s1 no longer owns anything
+        trace.render(out)?;
+
         // [!!!] Rust will never automatically create "deep" copies of your data.
+
+        Ok(())
     }
 
-    fn clone() {
+    fn clone(out: &mut dyn Write) -> io::Result<()> {
         // Clone: Common method to deeply copy the heap data of the String (not just the stack data).
 
         let s1 = String::from("hello");
         let s2 = s1.clone();
-        println!("s1 = {}, s2 = {}", s1, s2);
+        writeln!(out, "s1 = {}, s2 = {}", s1, s2)?;
+
+        Ok(())
     }
 
-    fn copy() {
+    fn copy(out: &mut dyn Write) -> io::Result<()> {
         // Stack-Only Data: Copy
 
         let x = 5;
         let y = x;
-        println!("x = {}, y = {}", x, y);
+        writeln!(out, "x = {}, y = {}", x, y)?;
 
         // This works though, because types such as integers that have a known size at compile time
         // are stored entirely on the stack, so copies of the actual values are quick to make.
@@ -123,9 +168,11 @@ pub mod c04 {
         // * chat
         // * Floating point types (f64)
         // * Tuples (if they only contain types that are also Copy)
+
+        Ok(())
     }
 
-    fn ownership_functions() {
+    fn ownership_functions(out: &mut dyn Write) -> io::Result<()> {
         let s1 = String::from("hello");
 
         // When a variable that includes data on the heap goes out of scope,
@@ -139,9 +186,11 @@ pub mod c04 {
 
             (s, length)
         }
+
+        Ok(())
     }
 
-    fn references_and_borrowing() {
+    fn references_and_borrowing(out: &mut dyn Write) -> io::Result<()> {
         let s_ref = String::from("reference");
         let len = calculate_length_with_reference(&s_ref);
         fn calculate_length_with_reference(s: &String) -> usize {
@@ -153,9 +202,11 @@ pub mod c04 {
         // BORROWING: Having references as function parameters.
         // Borrowed vaues CANNOT be modified... "error[E0596]: cannot borrow immutable borrowed content `*some_string` as mutable"
         // We are not allowed to modify something we have a reference to.
+
+        Ok(())
     }
 
-    fn mutable_references() {
+    fn mutable_references(out: &mut dyn Write) -> io::Result<()> {
         fn change(some_string: &mut String) {
             some_string.push_str(", world")
         }
@@ -173,9 +224,11 @@ pub mod c04 {
         }
         let r2 = &mut s;
         // Also: Cannot hav a mut ref while we have a an immutable one.
+
+        Ok(())
     }
 
-    fn dangling_references() {
+    fn dangling_references(out: &mut dyn Write) -> io::Result<()> {
         // let ref_to_nothing = dangle();
         // fn dangle() -> &String {
         //     let s = String::from("The compiler won't let me dangle");
@@ -199,9 +252,11 @@ pub mod c04 {
         // * Refs must always be valid
 
         // Diff kind of refs: Slices...
+
+        Ok(())
     }
 
-    fn slices() {
+    fn slices(out: &mut dyn Write) -> io::Result<()> {
         // The SLICE type:
         // * Does not have ownership
         // * Lets you ref a contiguous sequence of elements in a collection
@@ -231,6 +286,26 @@ pub mod c04 {
             &s[..]
         }
 
+        writeln!(out, "first_word({:?}) = {:?}", s, first_word(&s))?;
+
+        // It only knows the ASCII space, though. `crate::words` takes a `&str` and splits on any
+        // whitespace or punctuation, still handing out slices of the original text:
+        let text = "hello\u{a0}world, 你好。";
+        writeln!(
+            out,
+            "first_word({:?}) = {:?}",
+            text,
+            first_word(&text.to_string())
+        )?;
+        writeln!(
+            out,
+            "words::first_word({:?}) = {:?}",
+            text,
+            words::first_word(text)
+        )?;
+        let all: Vec<_> = words::words(text).zip(words::spans(text)).collect();
+        writeln!(out, "words::words with spans: {:?}", all)?;
+
         // STRING LITERALES are SLICES
         let s = "Hello, world!";
         // The type of s here is &str: it’s a slice pointing to that specific point of the binary.
@@ -238,5 +313,7 @@ pub mod c04 {
 
         // STRING SLICES as params
         // &str allows us to use the fn for on both String and &str (slice) values.
+
+        Ok(())
     }
 }
@@ -1,7 +1,38 @@
 pub mod c05 {
-    pub fn structs() {
-        println!("*** Chapter 5 - Structs ***");
+    use std::io::{self, Write};
 
+    use crate::chapters::{Chapter, Section};
+
+    const SECTIONS: &[Section] = &[
+        Section::new("main_1", main_1),
+        Section::new("main_2", main_2),
+        Section::new("main_3", main_3),
+        Section::new("main_4", main_4),
+        Section::new("main_5", main_5),
+        Section::new("main_6", main_6),
+    ];
+
+    pub struct Structs;
+
+    impl Chapter for Structs {
+        fn number(&self) -> u8 {
+            5
+        }
+
+        fn title(&self) -> &'static str {
+            "Structs"
+        }
+
+        fn sections(&self) -> &'static [Section] {
+            SECTIONS
+        }
+    }
+
+    pub fn structs(out: &mut dyn Write) -> io::Result<()> {
+        Structs.run(out)
+    }
+
+    fn main_1(out: &mut dyn Write) -> io::Result<()> {
         // Defining and Instatiating Structs
 
         // Structs are more flexible than tuples: you don’t have to rely on the order of the data to specify or access the values of an instance.
@@ -99,10 +130,10 @@ pub mod c05 {
         // let width1 = 30;
         // let height1 = 50;
 
-        // println!(
+        // writeln!(out,
         //     "The area of the rectangle is {} square pixels.",
         //     area(width1, height1)
-        // );
+        // )?;
 
         // fn area(width: u32, height: u32) -> u32 {
         //     width * height
@@ -112,10 +143,10 @@ pub mod c05 {
 
         // let rect1 = (30, 50);
 
-        // println!(
+        // writeln!(out,
         //     "The area of the rectangle is {} square pixels.",
         //     area(rect1)
-        // );
+        // )?;
 
         // fn area(dimensions: (u32, u32)) -> u32 {
         //     dimensions.0 * dimensions.1
@@ -133,10 +164,10 @@ pub mod c05 {
         //     height: 50,
         // };
 
-        // println!(
+        // writeln!(out,
         //     "The area of the rectangle is {} square pixels.",
         //     area(&rect1)
-        // );
+        // )?;
 
         // fn area(rectangle: &Rectangle) -> u32 {
         //     rectangle.width * rectangle.height
@@ -147,7 +178,7 @@ pub mod c05 {
         // Adding Useful Functionality with Derived Traits
 
         // If we try:
-        // println!("rect1 is {}", rect1);
+        // writeln!(out, "rect1 is {}", rect1)?;
         // We get:
         // error[E0277]: `Rectangle` doesn't implement `std::fmt::Display`
 
@@ -166,17 +197,17 @@ pub mod c05 {
             height: 50,
         };
 
-        println!("rect1 is {:?}", rect1);
+        writeln!(out, "rect1 is {:?}", rect1)?;
         // Putting the specifier :? inside the curly brackets tells println! we want to use an output format called Debug.
 
         // Another way to print out a value using the Debug format is by using the dbg! macro [TODO](Skipping for now)
 
         // In addition to the Debug trait, Rust has provided a number of traits for us to use with the `derive` attribute that can add useful behavior to our custom types. We'll cover how to implement these traits with custom behavior as well as how to create your own traits in Chapter 10.
 
-        main_2();
+        Ok(())
     }
 
-    fn main_2() {
+    fn main_2(out: &mut dyn Write) -> io::Result<()> {
         // Method Syntax
 
         // The syntax for methods is the same as for functions. However, methods are different from functions in that they're defined within the context of a struct, and their first parameter is always `self`, which is a reference to the struct that the method is defined on.
@@ -203,19 +234,20 @@ pub mod c05 {
             height: 51,
         };
 
-        println!(
+        writeln!(
+            out,
             "The area of the rectangle is {} square pixels.",
             rect.area()
-        );
+        )?;
 
         // Methods can take ownership of `self`, borrow `self` immutably as done here, or borrow `self` mutably, just as they can any other paramater.
 
         // ^ We didn't want to take ownership, since we just wanted to read the data in the struct, so we used &self.
 
-        main_3();
+        Ok(())
     }
 
-    fn main_3() {
+    fn main_3(out: &mut dyn Write) -> io::Result<()> {
         // We can choose to give a method the same name as one of the struct's fields:
 
         #[derive(Debug)]
@@ -236,7 +268,11 @@ pub mod c05 {
         };
 
         if rect1.width() {
-            println!("The rectangle has a nonzero width; it is {}", rect1.width);
+            writeln!(
+                out,
+                "The rectangle has a nonzero width; it is {}",
+                rect1.width
+            )?;
         }
 
         // --------------------------------------------------------------------------------------------
@@ -249,10 +285,11 @@ pub mod c05 {
         // (&p1).distance(&p2);
 
         // The first one looks much cleaner. This automatic referencing behavior works because methods have a clear receiver—the type of self. Given the receiver and name of a method, Rust can figure out definitively whether the method is reading (&self), mutating (&mut self), or consuming (self). The fact that Rust makes borrowing implicit for method receivers is a big part of making ownership ergonomic in practice.
-        main_4();
+
+        Ok(())
     }
 
-    fn main_4() {
+    fn main_4(out: &mut dyn Write) -> io::Result<()> {
         // --------------------------------------------------------------------------------------------
 
         // Methods with More Parameters
@@ -280,8 +317,8 @@ pub mod c05 {
             height: 45,
         };
 
-        println!("Can rect1 hold rect2? {}", rect1.can_hold(&rect2)); // true
-        println!("Can rect1 hold rect3? {}", rect1.can_hold(&rect3)); // false
+        writeln!(out, "Can rect1 hold rect2? {}", rect1.can_hold(&rect2))?; // true
+        writeln!(out, "Can rect1 hold rect3? {}", rect1.can_hold(&rect3))?; // false
 
         // We can tell what the type of the parameter will be by looking at the code that calls the method: rect1.can_hold(&rect2) passes in &rect2, which is an immutable borrow to rect2, an instance of Rectangle. This makes sense because we only need to _read_ rect2.
 
@@ -293,10 +330,11 @@ pub mod c05 {
                 self.width > other.width && self.height > other.height
             }
         }
-        main_5();
+
+        Ok(())
     }
 
-    fn main_5() {
+    fn main_5(out: &mut dyn Write) -> io::Result<()> {
         // Associated Functions
 
         // All functions defined within an impl block are called associated functions because they’re associated with the type named after the impl. We can define associated functions that don’t have self as their first parameter (and thus are not methods) because they don’t need an instance of the type to work with. We’ve already used one function like this, the String::from function, that’s defined on the String type.
@@ -322,10 +360,10 @@ pub mod c05 {
         // let sq = Rectangle::square(3);
         // This function is namespaced by the struct: the :: syntax is used for both associated functions and namespaces created by modules.
 
-        main_6();
+        Ok(())
     }
 
-    fn main_6() {
+    fn main_6(out: &mut dyn Write) -> io::Result<()> {
         // Multiple impl Blocks
 
         #[derive(Debug)]
@@ -347,6 +385,8 @@ pub mod c05 {
         }
 
         // There’s no reason to separate these methods into multiple impl blocks here, but this is valid syntax. We’ll see a case in which multiple impl blocks are useful in Chapter 10, where we discuss generic types and traits.
+
+        Ok(())
     }
 
     // Summary
@@ -1,33 +1,48 @@
 pub mod c06 {
-    pub fn enums() {
-        println!("*** Chapter 6 - Enums ***");
+    use std::io::{self, Write};
 
-        // Enums and Pattern Matching
-        defining_enums();
+    use crate::chapters::{Chapter, Section};
 
+    const SECTIONS: &[Section] = &[
+        // Enums and Pattern Matching
+        Section::new("defining_enums", defining_enums),
         // The Match Control Flow Operator
-        match_control_flow_operator();
-
+        Section::new("match_control_flow_operator", match_control_flow_operator),
         // Running multiple lines of code in a match arm
-        multiple_lines_match();
-
+        Section::new("multiple_lines_match", multiple_lines_match),
         // Patterns that Bind to Values
-        pattern_matching();
-
+        Section::new("pattern_matching", pattern_matching),
         // Matching with Option<T>
-        option_matching();
-
+        Section::new("option_matching", option_matching),
         // Matches are Exhaustive
-        exhaustive_matches();
-
+        Section::new("exhaustive_matches", exhaustive_matches),
         // The _ Placeholder
-        underscore_placeholder();
-
+        Section::new("underscore_placeholder", underscore_placeholder),
         // Concise Control Flow with the `if let` Syntax
-        if_let();
+        Section::new("if_let", if_let),
+    ];
+
+    pub struct Enums;
+
+    impl Chapter for Enums {
+        fn number(&self) -> u8 {
+            6
+        }
+
+        fn title(&self) -> &'static str {
+            "Enums"
+        }
+
+        fn sections(&self) -> &'static [Section] {
+            SECTIONS
+        }
+    }
+
+    pub fn enums(out: &mut dyn Write) -> io::Result<()> {
+        Enums.run(out)
     }
 
-    pub fn defining_enums() {
+    pub fn defining_enums(out: &mut dyn Write) -> io::Result<()> {
         // Defining an Enum:
         enum IpAddrKind {
             V4,
@@ -103,9 +118,11 @@ pub mod c06 {
         // In this example, we’ve created a variable m that has the value
         // Message::Write(String::from("hello")), and that is what self will be in the body
         // of the call method when m.call() runs.
+
+        Ok(())
     }
 
-    fn match_control_flow_operator() {
+    fn match_control_flow_operator(out: &mut dyn Write) -> io::Result<()> {
         // The power of match comes from the expressiveness of the patterns and
         // the fact that the compiler confirms that all possible cases are handled.
 
@@ -125,35 +142,39 @@ pub mod c06 {
             }
         }
 
-        println!("Value for penny: {}", value_in_cents(Coin::Penny));
-        println!("Value for quarter: {}", value_in_cents(Coin::Quarter));
+        writeln!(out, "Value for penny: {}", value_in_cents(Coin::Penny))?;
+        writeln!(out, "Value for quarter: {}", value_in_cents(Coin::Quarter))?;
 
         // If a pattern matches the value, the code associated with that pattern is executed.
+
+        Ok(())
     }
 
-    fn multiple_lines_match() {
+    fn multiple_lines_match(out: &mut dyn Write) -> io::Result<()> {
         enum Salutation {
             Hello,
             Hi,
         }
 
-        fn greet(greeting: Salutation) {
+        fn greet(out: &mut dyn Write, greeting: Salutation) -> io::Result<()> {
             // => { multi-line }
             // => single-line
             match greeting {
                 Salutation::Hello => {
-                    println!("Hello...");
-                    println!(" World!");
+                    writeln!(out, "Hello...")?;
+                    writeln!(out, " World!")
                 }
-                Salutation::Hi => println!("Hi!"),
+                Salutation::Hi => writeln!(out, "Hi!"),
             }
         }
 
-        greet(Salutation::Hello);
-        greet(Salutation::Hi);
+        greet(out, Salutation::Hello)?;
+        greet(out, Salutation::Hi)?;
+
+        Ok(())
     }
 
-    fn pattern_matching() {
+    fn pattern_matching(out: &mut dyn Write) -> io::Result<()> {
         #[derive(Debug)] // so we can inspect the state in a minute
         enum UsState {
             Alabama,
@@ -168,26 +189,25 @@ pub mod c06 {
             Quarter(UsState), // <- Quarter variant also holds a UsState value
         }
 
-        fn value_in_cents(coin: Coin) -> u8 {
-            match coin {
+        fn value_in_cents(out: &mut dyn Write, coin: Coin) -> io::Result<u8> {
+            Ok(match coin {
                 Coin::Penny => 1,
                 Coin::Nickel => 5,
                 Coin::Dime => 10,
                 Coin::Quarter(state) => {
-                    println!("State quarter from {:?}!", state);
+                    writeln!(out, "State quarter from {:?}!", state)?;
                     25
                 }
-            }
+            })
         }
 
-        println!(
-            "Value for {:?}'s quarter: {}",
-            UsState::Alabama,
-            value_in_cents(Coin::Quarter(UsState::Alabama))
-        );
+        let cents = value_in_cents(out, Coin::Quarter(UsState::Alabama))?;
+        writeln!(out, "Value for {:?}'s quarter: {}", UsState::Alabama, cents)?;
+
+        Ok(())
     }
 
-    fn option_matching() {
+    fn option_matching(out: &mut dyn Write) -> io::Result<()> {
         fn plus_one(x: Option<i32>) -> Option<i32> {
             match x {
                 None => None,
@@ -198,31 +218,35 @@ pub mod c06 {
         let five = Some(5);
 
         let six = plus_one(five);
-        println!("5 + 1 = {:?}", six); // Some(6)
+        writeln!(out, "5 + 1 = {:?}", six)?; // Some(6)
 
         let none = plus_one(None);
-        println!("None + 1 = {:?}", none); // None
+        writeln!(out, "None + 1 = {:?}", none)?; // None
 
         // You’ll see this pattern a lot:
         // -> match against an enum
         // -> bind a variable to the data inside
         // -> execute code based on it
+
+        Ok(())
     }
 
-    fn exhaustive_matches() {
+    fn exhaustive_matches(out: &mut dyn Write) -> io::Result<()> {
         // error[E0004]: non-exhaustive patterns: `None` not covered
 
         // ^ we didn’t cover every possible case!
         // Matches in Rust are exhaustive: we must exhaust every possibility
         // in order for the code to be valid.
+
+        Ok(())
     }
 
-    fn underscore_placeholder() {
+    fn underscore_placeholder(out: &mut dyn Write) -> io::Result<()> {
         // If we don't want to list out every possible value, we can the special pattern `_`:
         let some_u8_value = 0u8;
         match some_u8_value {
-            1 => println!("one"),
-            2 => println!("two"),
+            1 => writeln!(out, "one")?,
+            2 => writeln!(out, "two")?,
             _ => (), // wildcard pattern => unit value
                      // (we do nothing for all the other possible values)
         }
@@ -236,16 +260,18 @@ pub mod c06 {
             }
         }
 
-        println!("Match 1: {:?}", match_me(&1));
-        println!("Match 3: {:?}", match_me(&3));
-        println!("Match 42: {:?}", match_me(&42));
+        writeln!(out, "Match 1: {:?}", match_me(&1))?;
+        writeln!(out, "Match 3: {:?}", match_me(&3))?;
+        writeln!(out, "Match 42: {:?}", match_me(&42))?;
+
+        Ok(())
     }
 
-    fn if_let() {
+    fn if_let(out: &mut dyn Write) -> io::Result<()> {
         let some_u8_value = Some(3u8);
         // When we only care about a single value, we can use if let to match it:
         if let Some(3) = some_u8_value {
-            println!("if let three");
+            writeln!(out, "if let three")?;
         }
 
         // Choosing between match and if let depends on what you’re doing in
@@ -270,11 +296,13 @@ pub mod c06 {
         let coin = Coin::Quarter(UsState::Alaska);
         let mut count = 0;
         if let Coin::Quarter(state) = coin {
-            println!("State quarter from {:?}!", state);
+            writeln!(out, "State quarter from {:?}!", state)?;
             count += 1;
         } else {
-            println!("I don't know coin {:?}!", coin);
+            writeln!(out, "I don't know coin {:?}!", coin)?;
         }
-        println!("count: {}", count);
+        writeln!(out, "count: {}", count)?;
+
+        Ok(())
     }
 }
@@ -1,15 +1,35 @@
 pub mod c07 {
-    pub fn packages_crates_modules() {
-        println!("*** Chapter 7 - Packages, Crates, Modules ***");
+    use std::io::{self, Write};
 
-        // Packages and Crates
-        pkg_crates();
+    use crate::chapters::{Chapter, Section};
 
+    const SECTIONS: &[Section] = &[
+        // Packages and Crates
+        Section::new("pkg_crates", pkg_crates),
         // Modules to Control Scope and Privacy
-        modules();
-
+        Section::new("modules", modules),
         // Paths for Referring to an Item in the Module Tree
-        paths();
+        Section::new("paths", paths),
+    ];
+
+    pub struct PackagesCratesModules;
+
+    impl Chapter for PackagesCratesModules {
+        fn number(&self) -> u8 {
+            7
+        }
+
+        fn title(&self) -> &'static str {
+            "Packages, Crates, Modules"
+        }
+
+        fn sections(&self) -> &'static [Section] {
+            SECTIONS
+        }
+    }
+
+    pub fn packages_crates_modules(out: &mut dyn Write) -> io::Result<()> {
+        PackagesCratesModules.run(out)
 
         // Starting Relative Paths with Super
         // super::serve_order();
@@ -17,7 +37,7 @@ pub mod c07 {
         // -> Have fewer places to update code in the future if it gets moved to a different module.
     }
 
-    fn pkg_crates() {
+    fn pkg_crates(out: &mut dyn Write) -> io::Result<()> {
         // A _package_ is one or more crates that provide a set of functionalities.
 
         // Rules:
@@ -30,9 +50,11 @@ pub mod c07 {
 
         // If a pkg contains src/main.rs and src/lib.rs, it has two crates:
         // a library and a binary, both with the same name as the pkg.
+
+        Ok(())
     }
 
-    fn modules() {
+    fn modules(out: &mut dyn Write) -> io::Result<()> {
         // Modules let us organize code within a crate into groups for readibility and easy reuse.
         // -> also control the privacy (private/public) of the code.
 
@@ -58,14 +80,18 @@ pub mod c07 {
         //              |> (...)
         //          |> serving
         //              |> (...)
+
+        Ok(())
     }
 
-    fn paths() {
+    fn paths(out: &mut dyn Write) -> io::Result<()> {
         // pub fn eat_at_restaurant() {
         //     // Absolute path
         //     crate::front_of_house::hosting::add_to_waitlist();
         //     // Relative path
         //     front_of_house::hosting::add_to_waitlist();
         // }
+
+        Ok(())
     }
 }
@@ -1,18 +1,39 @@
 pub mod c08 {
-    pub fn common_collections() {
-        println!("*** Chapter 8 - Common Collections ***");
+    use std::io::{self, Write};
 
-        // Storing Lists of Values with Vectors
-        vectors();
+    use crate::chapters::{Chapter, Section};
+    use crate::trace::Tracer;
 
+    const SECTIONS: &[Section] = &[
+        // Storing Lists of Values with Vectors
+        Section::new("vectors", vectors),
         // Storing UTF-8 Encoded Text with Strings
-        strings();
-
+        Section::new("strings", strings),
         // Storing Keys with Associated Values in Hash Maps
-        hash_maps();
+        Section::new("hash_maps", hash_maps),
+    ];
+
+    pub struct CommonCollections;
+
+    impl Chapter for CommonCollections {
+        fn number(&self) -> u8 {
+            8
+        }
+
+        fn title(&self) -> &'static str {
+            "Common Collections"
+        }
+
+        fn sections(&self) -> &'static [Section] {
+            SECTIONS
+        }
+    }
+
+    pub fn common_collections(out: &mut dyn Write) -> io::Result<()> {
+        CommonCollections.run(out)
     }
 
-    fn vectors() {
+    fn vectors(out: &mut dyn Write) -> io::Result<()> {
         // Creating a new Vector:
         let v: Vec<i32> = Vec::new();
         // vec! macro for convenience:
@@ -31,16 +52,24 @@ pub mod c08 {
             let v = vec![1, 2, 3, 4];
             // do stuff with v
         } // <- goes out of scope and its freed here
+          // Traced, the vector is dropped first and then each element, in order:
+        let trace = Tracer::new();
+        {
+            let _scope = trace.scope("block");
+            let elements = (1..=4).map(|n| trace.track(&format!("v[{}]", n - 1), n));
+            let v = trace.track("v", elements.collect::<Vec<_>>());
+        }
+        trace.render(out)?;
 
         // Reading Elements of Vectors:
         {
             let v = vec![1, 2, 3, 4, 5];
             let third: &i32 = &v[2];
-            println!("The third element is {}", third);
+            writeln!(out, "The third element is {}", third)?;
 
             match v.get(2) {
-                Some(third) => println!("The third element is {}", third),
-                None => println!("There is no third element."),
+                Some(third) => writeln!(out, "The third element is {}", third)?,
+                None => writeln!(out, "There is no third element.")?,
             }
 
             // let does_not_exist = &v[100];
@@ -55,7 +84,7 @@ pub mod c08 {
         {
             let v = vec![100, 32, 57];
             for val in &v {
-                println!("{}", val);
+                writeln!(out, "{}", val)?;
             }
         }
 
@@ -65,7 +94,7 @@ pub mod c08 {
             for val in &mut v {
                 *val += 50;
             }
-            println!("{:?}", v);
+            writeln!(out, "{:?}", v)?;
         }
 
         // Using an Enum to Store Multiple Types:
@@ -86,11 +115,13 @@ pub mod c08 {
             SpreadsheetCell::Float(33.01),
         ];
         // ^ Defining an enum to store values of different types in one vector.
-        println!("Vec + enums ftw! {:?}", row);
+        writeln!(out, "Vec + enums ftw! {:?}", row)?;
         // Using an enum plus a match expression means that Rust will ensure at compile time that every possible case is handled
+
+        Ok(())
     }
 
-    fn strings() {
+    fn strings(out: &mut dyn Write) -> io::Result<()> {
         // New Rustaceans commonly get stuck on strings for a combination of three reasons:
         // * Rust’s propensity for exposing possible errors
         // * Strings being a more complicated data structure than many programmers give them credit for
@@ -110,14 +141,14 @@ pub mod c08 {
 
         {
             let data = "initial contents";
-            println!("{}", data);
+            writeln!(out, "{}", data)?;
 
             let s = data.to_string();
-            println!("{}", s);
+            writeln!(out, "{}", s)?;
 
             // to_string() method also works on a literal directly:
             let s = "initial contents".to_string();
-            println!("{}", s);
+            writeln!(out, "{}", s)?;
             // ^ Using the String::from function to create a String from a string literal
         }
 
@@ -127,14 +158,14 @@ pub mod c08 {
             // Appending with push and push_str
             let mut s = String::from("foo");
             s.push_str("bar");
-            println!("{}", s);
+            writeln!(out, "{}", s)?;
         }
 
         {
             // Adding one char
             let mut s = String::from("lo");
             s.push('l');
-            println!("{}", s);
+            writeln!(out, "{}", s)?;
         }
 
         {
@@ -163,22 +194,28 @@ pub mod c08 {
             let s2 = String::from("tac");
             let s3 = String::from("toe");
             let s = format!("{}-{}-{}", s1, s2, s3);
-            println!("{}", s);
+            writeln!(out, "{}", s)?;
         }
 
         // Methods for Iterating over Strings
         {
             // .chars() method:
             for c in "Здравствуйте".chars() {
-                println!("{}", c);
+                writeln!(out, "{}", c)?;
             }
         }
+
+        Ok(())
     }
 
-    fn hash_maps() {
+    fn hash_maps(out: &mut dyn Write) -> io::Result<()> {
+        use std::collections::BTreeMap;
         use std::collections::HashMap;
         // HashMap<K, V> stores a mapping of keys of type K to values of type V.
 
+        // HashMap iteration order is arbitrary (and changes between runs), so the
+        // maps below are copied into a BTreeMap, which is sorted by key, before printing.
+
         // Creating a New Hash Map:
         {
             let mut scores = HashMap::new();
@@ -190,15 +227,15 @@ pub mod c08 {
             // Insert if key does not have a value yet:
             scores.entry(String::from("Red")).or_insert(120);
 
-            println!("{:?}", scores);
+            writeln!(out, "{:?}", scores.iter().collect::<BTreeMap<_, _>>())?;
 
             // Accessing values:
             let team_name = String::from("Blue");
             let score = scores.get(&team_name);
 
             // Iterate:
-            for (k, v) in scores {
-                println!("{}: {}", k, v);
+            for (k, v) in scores.into_iter().collect::<BTreeMap<_, _>>() {
+                writeln!(out, "{}: {}", k, v)?;
             }
 
             // Overwriting after borrowing values by iteration fails:
@@ -215,7 +252,9 @@ pub mod c08 {
                 *count += 1
             }
 
-            println!("{:?}", map);
+            writeln!(out, "{:?}", map.iter().collect::<BTreeMap<_, _>>())?;
         }
+
+        Ok(())
     }
 }
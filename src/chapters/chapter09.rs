@@ -4,32 +4,51 @@ pub mod c09 {
     use std::io;
     use std::io::ErrorKind;
     use std::io::Read;
+    use std::io::Write;
 
-    pub fn error_handling() {
-        println!("*** Chapter 9 - Error Handling ***");
+    use crate::chapters::{Chapter, Section};
 
+    const SECTIONS: &[Section] = &[
         // Using panic! and backtrace
-        panic();
-
+        Section::new("panic", panic),
         // Recoverable errors with Result
-        // result();
+        // Section::new("result", result),
 
         // Matching on different errors
-        // result_error_match();
+        // Section::new("result_error_match", result_error_match),
 
         // (Chapter 13 will go deeper into closures)
-        // unwrap_or_else();
+        // Section::new("unwrap_or_else", unwrap_or_else),
 
         // Shortcuts for panic on error: unwrap and expect
-        // expect();
+        // Section::new("expect", expect),
 
         // Using ? operator for propagating errors
-        question_mark();
-
+        Section::new("question_mark", question_mark),
         // TODO: Come back to this chapter later
+    ];
+
+    pub struct ErrorHandling;
+
+    impl Chapter for ErrorHandling {
+        fn number(&self) -> u8 {
+            9
+        }
+
+        fn title(&self) -> &'static str {
+            "Error Handling"
+        }
+
+        fn sections(&self) -> &'static [Section] {
+            SECTIONS
+        }
+    }
+
+    pub fn error_handling(out: &mut dyn Write) -> io::Result<()> {
+        ErrorHandling.run(out)
     }
 
-    fn panic() {
+    fn panic(out: &mut dyn Write) -> io::Result<()> {
         // panic!("crash and burn");
 
         let vec = vec![1, 2, 3];
@@ -38,6 +57,8 @@ pub mod c09 {
 
         // To enable the backtrace:
         // $ RUST_BACKTRACE=1 cargo run
+
+        Ok(())
     }
 
     fn result() {
@@ -87,7 +108,7 @@ pub mod c09 {
         let f = File::open("hello.txt").expect("Failed to open hello.txt");
     }
 
-    fn question_mark() {
+    fn question_mark(out: &mut dyn Write) -> io::Result<()> {
         fn read_username_from_file() -> Result<String, io::Error> {
             // If the value is an Err, the Err will be returned from the whole function
             // as if we had used the return keyword so the error value gets propagated
@@ -118,5 +139,7 @@ pub mod c09 {
         // ^ Using fs::read_to_string instead of opening and then reading the file.
 
         // The ? operator can only be used in functions that return a Result.
+
+        Ok(())
     }
 }
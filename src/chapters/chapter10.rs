@@ -1,46 +1,87 @@
 pub mod c10 {
     use std::fmt::Display;
+    use std::io::{self, Write};
 
-    pub fn generics_traits_lifetimes() {
-        println!("*** Chapter 10 - Generic Types, Traits, and Lifetimes ***");
+    use crate::chapters::{Chapter, Section};
 
+    const SECTIONS: &[Section] = &[
         // Functions: Avoid code duplication
-        find_largest();
-        find_largest_refactored();
-
+        Section::new("find_largest", find_largest),
+        Section::new("find_largest_refactored", find_largest_refactored),
         // Generic data types
-        generics_in_functions();
-        generics_in_structs();
-        generics_in_enums();
-        generics_in_methods();
-        generics_in_methods_2();
-
+        Section::new("generics_in_functions", generics_in_functions),
+        Section::new("generics_in_structs", generics_in_structs),
+        Section::new("generics_in_enums", generics_in_enums),
+        Section::new("generics_in_methods", generics_in_methods),
+        Section::new("generics_in_methods_2", generics_in_methods_2),
         // Traits: Defining shared behavior
-        traits_definition();
-        traits_implementation();
-        traits_default_behavior();
-        traits_as_params();
-        traits_bound_syntax_sugar();
-        traits_plus();
-        traits_bounds_with_where_clauses();
-        traits_largest_function_with_trait_bounds();
-        trait_bounds_to_conditionally_implement_methods();
-
+        Section::new("traits_definition", traits_definition),
+        Section::new("traits_implementation", traits_implementation),
+        Section::new("traits_default_behavior", traits_default_behavior),
+        Section::new("traits_as_params", traits_as_params),
+        Section::new("traits_bound_syntax_sugar", traits_bound_syntax_sugar),
+        Section::new("traits_plus", traits_plus),
+        Section::new(
+            "traits_bounds_with_where_clauses",
+            traits_bounds_with_where_clauses,
+        ),
+        Section::new(
+            "traits_largest_function_with_trait_bounds",
+            traits_largest_function_with_trait_bounds,
+        ),
+        Section::new(
+            "trait_bounds_to_conditionally_implement_methods",
+            trait_bounds_to_conditionally_implement_methods,
+        ),
         // Lifetimes: Validating references
-        lifetimes_to_prevent_dangling_refs();
-        generic_lifetimes_in_functions();
-        lifetime_annotation_syntax();
-        lifetime_annotations_in_function_signatures();
-        thinking_in_terms_of_lifetimes();
-        lifetime_annotations_in_struct_definitions();
-        lifetime_elision();
-        static_lifetime();
-
+        Section::new(
+            "lifetimes_to_prevent_dangling_refs",
+            lifetimes_to_prevent_dangling_refs,
+        ),
+        Section::new(
+            "generic_lifetimes_in_functions",
+            generic_lifetimes_in_functions,
+        ),
+        Section::new("lifetime_annotation_syntax", lifetime_annotation_syntax),
+        Section::new(
+            "lifetime_annotations_in_function_signatures",
+            lifetime_annotations_in_function_signatures,
+        ),
+        Section::new(
+            "thinking_in_terms_of_lifetimes",
+            thinking_in_terms_of_lifetimes,
+        ),
+        Section::new(
+            "lifetime_annotations_in_struct_definitions",
+            lifetime_annotations_in_struct_definitions,
+        ),
+        Section::new("lifetime_elision", lifetime_elision),
+        Section::new("static_lifetime", static_lifetime),
         // All together: Generic Type Paramaters + Trait Bounds + Lifetimes
-        all_together();
+        Section::new("all_together", all_together),
+    ];
+
+    pub struct GenericsTraitsLifetimes;
+
+    impl Chapter for GenericsTraitsLifetimes {
+        fn number(&self) -> u8 {
+            10
+        }
+
+        fn title(&self) -> &'static str {
+            "Generic Types, Traits, and Lifetimes"
+        }
+
+        fn sections(&self) -> &'static [Section] {
+            SECTIONS
+        }
+    }
+
+    pub fn generics_traits_lifetimes(out: &mut dyn Write) -> io::Result<()> {
+        GenericsTraitsLifetimes.run(out)
     }
 
-    fn find_largest() {
+    fn find_largest(out: &mut dyn Write) -> io::Result<()> {
         let number_list = vec![34, 50, 25, 100, 65];
         let mut largest = number_list[0];
         for number in number_list {
@@ -49,10 +90,12 @@ pub mod c10 {
             }
         }
 
-        println!("The largest number is {}", largest);
+        writeln!(out, "The largest number is {}", largest)?;
+
+        Ok(())
     }
 
-    fn find_largest_refactored() {
+    fn find_largest_refactored(out: &mut dyn Write) -> io::Result<()> {
         fn largest(list: &[i32]) -> i32 {
             let mut largest = list[0];
             for &item in list.iter() {
@@ -66,14 +109,16 @@ pub mod c10 {
 
         let number_list = vec![34, 50, 25, 100, 65];
         let result = largest(&number_list);
-        println!("The largest number is {}", result);
+        writeln!(out, "The largest number is {}", result)?;
 
         let number_list = vec![102, 34, 6000, 89, 54, 2, 43, 8];
         let result = largest(&number_list);
-        println!("The largest number is {}", result);
+        writeln!(out, "The largest number is {}", result)?;
+
+        Ok(())
     }
 
-    fn generics_in_functions() {
+    fn generics_in_functions(out: &mut dyn Write) -> io::Result<()> {
         // fn largest<T>(list: &[T]) -> T {
         //     let mut largest = list[0];
 
@@ -101,9 +146,11 @@ pub mod c10 {
 
             largest
         }
+
+        Ok(())
     }
 
-    fn generics_in_structs() {
+    fn generics_in_structs(out: &mut dyn Write) -> io::Result<()> {
         struct Point<T> {
             x: T,
             y: T,
@@ -113,9 +160,11 @@ pub mod c10 {
             x: T,
             y: U,
         }
+
+        Ok(())
     }
 
-    fn generics_in_enums() {
+    fn generics_in_enums(out: &mut dyn Write) -> io::Result<()> {
         enum Option<T> {
             Some(T),
             None,
@@ -126,9 +175,11 @@ pub mod c10 {
             Ok(T),
             Err(E),
         }
+
+        Ok(())
     }
 
-    fn generics_in_methods() {
+    fn generics_in_methods(out: &mut dyn Write) -> io::Result<()> {
         struct Point<T> {
             x: T,
             y: T,
@@ -142,7 +193,7 @@ pub mod c10 {
 
         let p = Point { x: 5, y: 10 };
 
-        println!("p.x = {}", p.x());
+        writeln!(out, "p.x = {}", p.x())?;
 
         // We could implement methods only on Point<f32> instances rather than on Point<T>
         // instances with a generic type.
@@ -151,9 +202,11 @@ pub mod c10 {
                 (self.x.powi(2) + self.y.powi(2)).sqrt()
             }
         }
+
+        Ok(())
     }
 
-    fn generics_in_methods_2() {
+    fn generics_in_methods_2(out: &mut dyn Write) -> io::Result<()> {
         // Generic type params in a struct definition aren't always
         // the same as those you use in that struct's method signatures.
         struct Point<T, U> {
@@ -173,10 +226,12 @@ pub mod c10 {
         let p1 = Point { x: 5, y: 10.4 };
         let p2 = Point { x: "Hello", y: 'c' };
         let p3 = p1.mixup(p2);
-        println!("p3.x = {}, p3.y = {}", p3.x, p3.y);
+        writeln!(out, "p3.x = {}, p3.y = {}", p3.x, p3.y)?;
+
+        Ok(())
     }
 
-    fn traits_definition() {
+    fn traits_definition(out: &mut dyn Write) -> io::Result<()> {
         // Traits are like interfaces in other languages, but with some differences...__rust_force_expr!
 
         // Defining a Trait
@@ -188,9 +243,11 @@ pub mod c10 {
 
         // The compiler will enforce that any type that has the Summary trait will have
         // the method `summarize` defined with this signature exactly.
+
+        Ok(())
     }
 
-    fn traits_implementation() {
+    fn traits_implementation(out: &mut dyn Write) -> io::Result<()> {
         pub trait Summary {
             fn summarize(&self) -> String;
         }
@@ -227,10 +284,12 @@ pub mod c10 {
             retweet: false,
         };
 
-        println!("1 new tweet: {}", tweet.summarize());
+        writeln!(out, "1 new tweet: {}", tweet.summarize())?;
+
+        Ok(())
     }
 
-    fn traits_default_behavior() {
+    fn traits_default_behavior(out: &mut dyn Write) -> io::Result<()> {
         // // Sometimes it's useful to have default behavior for some/all of the methods in a trait.
         // // Then we can keep or verride each method's default behavior.
         // pub trait Summary {
@@ -269,10 +328,12 @@ pub mod c10 {
             retweet: false,
         };
 
-        println!("1 new tweet: {}", tweet.summarize());
+        writeln!(out, "1 new tweet: {}", tweet.summarize())?;
+
+        Ok(())
     }
 
-    fn traits_as_params() {
+    fn traits_as_params(out: &mut dyn Write) -> io::Result<()> {
         pub trait Summary {
             fn summarize_author(&self) -> String;
 
@@ -282,15 +343,17 @@ pub mod c10 {
         }
 
         // Using traits to define functions that accept many different types.
-        pub fn notify(item: impl Summary) {
-            //                ^
+        pub fn notify(out: &mut dyn Write, item: impl Summary) -> io::Result<()> {
+            //                                 ^
             // Instead of a concrete type for the item param, we specify the `impl` keyword and the trait name.
             // This param accepts any type that implements the specified trait.
-            println!("Breaking news! {}", item.summarize());
+            writeln!(out, "Breaking news! {}", item.summarize())
         }
+
+        Ok(())
     }
 
-    fn traits_bound_syntax_sugar() {
+    fn traits_bound_syntax_sugar(out: &mut dyn Write) -> io::Result<()> {
         pub trait Summary {
             fn summarize_author(&self) -> String;
 
@@ -300,31 +363,40 @@ pub mod c10 {
         }
         // `impl` works for straightforward cases.
         //   ^    it's syntax sugar for a longer form: the _trait bound_
-        pub fn notify<T: Summary>(item: T) {
-            println!("Breaking news! {}", item.summarize());
+        pub fn notify<T: Summary>(out: &mut dyn Write, item: T) -> io::Result<()> {
+            writeln!(out, "Breaking news! {}", item.summarize())
         }
         // `impl` Trait syntax is convenient in simple cases.
         // Trait bound syuntax can express more complexity in other cases.
         // E.g.: We can have two params that implement Summary. Using the `impl` syntax, we can write:
-        pub fn notify2(item1: impl Summary, item2: impl Summary) {
-            println!(
+        pub fn notify2(
+            out: &mut dyn Write,
+            item1: impl Summary,
+            item2: impl Summary,
+        ) -> io::Result<()> {
+            writeln!(
+                out,
                 "Breaking news! {} and {}",
                 item1.summarize(),
                 item2.summarize()
-            );
+            )
         }
         // If we instead wanted to force both params to have the same type, that's only possible using a trait bound:
         // pub fn notify<T: Summary>(item1: T, item2: T) {}
+
+        Ok(())
     }
 
-    fn traits_plus() {
+    fn traits_plus(out: &mut dyn Write) -> io::Result<()> {
         // To specify more than one trait bound, use `+`:
         // pub fn notify(item: impl Summary + Display) {
         //     // ...
         // }
+
+        Ok(())
     }
 
-    fn traits_bounds_with_where_clauses() {
+    fn traits_bounds_with_where_clauses(out: &mut dyn Write) -> io::Result<()> {
         // Similar to C#, Rust has alternate syntax for specifying trait bounds inside a `where` clause after the function signature.
         // Instead of:
         // fn some_function<T: Display + Clone, U: Clone + Debug>(t: T, u: U) -> i32 {}
@@ -336,9 +408,11 @@ pub mod c10 {
         // {
         //     ...
         // }
+
+        Ok(())
     }
 
-    fn traits_largest_function_with_trait_bounds() {
+    fn traits_largest_function_with_trait_bounds(out: &mut dyn Write) -> io::Result<()> {
         fn largest<T: PartialOrd + Copy>(list: &[T]) -> T {
             let mut largest = list[0];
 
@@ -354,11 +428,13 @@ pub mod c10 {
         let number_list = vec![34, 50, 25, 100, 65];
 
         let result = largest(&number_list);
-        println!("The largest number is {}", result);
+        writeln!(out, "The largest number is {}", result)?;
         let char_list = vec!['y', 'm', 'a', 'q'];
 
         let result = largest(&char_list);
-        println!("The largest char is {}", result);
+        writeln!(out, "The largest char is {}", result)?;
+
+        Ok(())
     }
     // ^ If we don't want to restrict the largest function to the types that implement the Copy trait, we could specify that
     // T has the trait bound Clone instead of Copy. Then we could clone each value in the slice when we want the `largest`
@@ -367,7 +443,7 @@ pub mod c10 {
     // If we change the return type to &T instead of T, thereby changing the body of the function to return a reference,
     // we wouldn't need the Clone or Copy trait bounds and we could avoid heap allocations.
 
-    fn trait_bounds_to_conditionally_implement_methods() {
+    fn trait_bounds_to_conditionally_implement_methods(out: &mut dyn Write) -> io::Result<()> {
         // By using a trait bound with an impl block that uses generic type params, we can implement methods conditionally
         // for types that implement the specified traits.
         struct Pair<T> {
@@ -383,20 +459,20 @@ pub mod c10 {
         }
         // Generic bound by traits
         impl<T: Display + PartialOrd> Pair<T> {
-            fn cmp_display(&self) {
+            fn cmp_display(&self, out: &mut dyn Write) -> io::Result<()> {
                 if self.x >= self.y {
-                    if self.x >= self.y {
-                        println!("The largest member is x = {}", self.x);
-                    } else {
-                        println!("The largest member is y = {}", self.y);
-                    }
+                    writeln!(out, "The largest member is x = {}", self.x)
+                } else {
+                    writeln!(out, "The largest member is y = {}", self.y)
                 }
             }
         }
         // ^ Conditionally implement methods on a generic type depending on trait bounds.
+
+        Ok(())
     }
 
-    fn lifetimes_to_prevent_dangling_refs() {
+    fn lifetimes_to_prevent_dangling_refs(out: &mut dyn Write) -> io::Result<()> {
         // The main aim of lifetimes is to prevent dangling references, which cause
         // a program to reference data other than the data it's intended to reference.
 
@@ -408,7 +484,7 @@ pub mod c10 {
         //         r = &x; // [E0597] `x` does not live long enough.
         //     } // <- `x` droppped there while still borrowed
 
-        //     println!("r: {}", r);
+        //     writeln!(out, "r: {}", r)?;
         // }
 
         // That code won't compile because the value `r` is referring to has gone out of scope before we try to use it.
@@ -417,9 +493,11 @@ pub mod c10 {
 
         // Borrow checker: compares scopes to determine whether all borrows are valid.
         // The data should have >= lifetime than the reference pointing to it.
+
+        Ok(())
     }
 
-    fn generic_lifetimes_in_functions() {
+    fn generic_lifetimes_in_functions(out: &mut dyn Write) -> io::Result<()> {
         // If we try to implement the longest function like this, it won't compile:
         // fn longest(x: &str, y: &str) -> &str {
         //     if x.len() > y.len() {
@@ -440,18 +518,22 @@ pub mod c10 {
         // To fix this, we'll add generic lifetime params
         // that define the relationship between the references.
         // ****************************************************
+
+        Ok(())
     }
 
-    fn lifetime_annotation_syntax() {
+    fn lifetime_annotation_syntax(out: &mut dyn Write) -> io::Result<()> {
         // Functions can accept references with any lifetime by specifying a generic lifetime parameter.
 
         // Examples:
         // &i32        // a reference
         // &'a i32     // a reference with an explicit lifetime
         // &'a mut i32 // a mutable reference with an explicit lifetime
+
+        Ok(())
     }
 
-    fn lifetime_annotations_in_function_signatures() {
+    fn lifetime_annotations_in_function_signatures(out: &mut dyn Write) -> io::Result<()> {
         // All the refs in the params and the return value must have the same lifetime.
         fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
             // ^ both params are string slices, so they have the same lifetime
@@ -463,7 +545,7 @@ pub mod c10 {
             }
         }
 
-        println!("Longest: {}", longest("1", "22"));
+        writeln!(out, "Longest: {}", longest("1", "22"))?;
 
         // When we specify the lifetime params in this function signature,
         // we're not changing the lifetimes of any values passed in or returned...
@@ -478,7 +560,7 @@ pub mod c10 {
         {
             let string2 = String::from("xyz");
             let result = longest(string1.as_str(), string2.as_str());
-            println!("The longest string is '{}'", result)
+            writeln!(out, "The longest string is '{}'", result)?
         }
 
         // But this won't compile:
@@ -490,12 +572,14 @@ pub mod c10 {
         //         result = longest(string1.as_str(), string2.as_str());
         //         //                                          ^ `string2` does not live long enough
         //         //                                          ^ `string2` dropped here while still borrowed
-        //         println!("The longest string is {}", result);
+        //         writeln!(out, "The longest string is {}", result)?;
         //     } // <- borrowed value needs to live until here
         // }
+
+        Ok(())
     }
 
-    fn thinking_in_terms_of_lifetimes() {
+    fn thinking_in_terms_of_lifetimes(out: &mut dyn Write) -> io::Result<()> {
         // The way you need to specify lifetime params depends on what your fn is doing.
 
         // The following code compiles:
@@ -521,9 +605,11 @@ pub mod c10 {
         // Lifetime syntax is about connecting the lifetimes of various params and return values of functions.
         // Once they're connected, Rust has enough information to allow memory-safe operations and disallow
         // operations that would create dangling pointers or otherwise violate memory safety.
+
+        Ok(())
     }
 
-    fn lifetime_annotations_in_struct_definitions() {
+    fn lifetime_annotations_in_struct_definitions(out: &mut dyn Write) -> io::Result<()> {
         // It's possible for structs to hold refs:
         // (1)
         struct ImportantExcerpt<'a> {
@@ -543,9 +629,11 @@ pub mod c10 {
         // (2) As with generic data types, we declare the name of the generic lifetime param
         //      inside angle brackets after the name of the struct so we can use the lifetime
         //      parameter in the body of the struct definition.
+
+        Ok(())
     }
 
-    fn lifetime_elision() {
+    fn lifetime_elision(out: &mut dyn Write) -> io::Result<()> {
         // * Every reference has a lifetime.
         // * You need to specify lifetime parameters for functions or structs that use references.
         // * In some situations, the borrow checker infers the lifetimes so programmers don't need to
@@ -555,9 +643,11 @@ pub mod c10 {
         //      ^ If your code fits these cases, you won't need to write the lifetimes explicitly.
         // * Lifetimes on function or method params: "input lifetimes"
         // * Lifetimes on return values: "output lifetimes"
+
+        Ok(())
     }
 
-    fn static_lifetime() {
+    fn static_lifetime(out: &mut dyn Write) -> io::Result<()> {
         // All string literals have the 'static lifetime:
         let s: &'static str = "I have a static lifetime.";
         // ^ the text of this string is stored directly in the binary of your program,
@@ -565,20 +655,27 @@ pub mod c10 {
 
         // Before specifying 'static as the lifetime for a reference, think about whether
         // the reference you have actually lives the entire lifetime of your program or not.
+
+        Ok(())
     }
 
-    fn all_together() {
+    fn all_together(out: &mut dyn Write) -> io::Result<()> {
         // Specifying in one function: Generic type params + trait counds + lifetimes
         {
-            fn logenst_with_an_announcement<'a, T>(x: &'a str, y: &'a str, ann: T) -> &'a str
+            fn logenst_with_an_announcement<'a, T>(
+                out: &mut dyn Write,
+                x: &'a str,
+                y: &'a str,
+                ann: T,
+            ) -> io::Result<&'a str>
             where
                 T: Display,
             {
-                println!("Announcement! {}", ann);
+                writeln!(out, "Announcement! {}", ann)?;
                 if x.len() > y.len() {
-                    x
+                    Ok(x)
                 } else {
-                    y
+                    Ok(y)
                 }
             }
             // * Extra parameter named ann of the generic type T
@@ -586,5 +683,7 @@ pub mod c10 {
             // * Because lifetimes are a type of generic, the declarations of the lifetime parameter 'a and
             //      the generic type parameter T go in the same list inside the angle brackets after the function name.
         }
+
+        Ok(())
     }
 }
@@ -1,12 +1,35 @@
 pub mod c11 {
-    pub fn writing_automated_tests() {
-        // How to Run Tests
-        anatomy_of_a_test_fn()
+    use std::io::{self, Write};
+
+    use crate::chapters::{Chapter, Section};
 
+    const SECTIONS: &[Section] = &[
+        // How to Run Tests
+        Section::new("anatomy_of_a_test_fn", anatomy_of_a_test_fn),
         // TODO
+    ];
+
+    pub struct WritingAutomatedTests;
+
+    impl Chapter for WritingAutomatedTests {
+        fn number(&self) -> u8 {
+            11
+        }
+
+        fn title(&self) -> &'static str {
+            "Writing Automated Tests"
+        }
+
+        fn sections(&self) -> &'static [Section] {
+            SECTIONS
+        }
+    }
+
+    pub fn writing_automated_tests(out: &mut dyn Write) -> io::Result<()> {
+        WritingAutomatedTests.run(out)
     }
 
-    fn anatomy_of_a_test_fn() {
+    fn anatomy_of_a_test_fn(out: &mut dyn Write) -> io::Result<()> {
         // To change a function into a test function, add #[test] on the line before `fn`.
 
         // Run tests:
@@ -14,9 +37,11 @@ pub mod c11 {
 
         // When we make a new lib project with Cargo, a test module with a test fn in it is automatically generated for us.
         // $ cargo new adder --lib
+
+        Ok(())
     }
 
-    fn adder(num1: i32, num2: i32) -> i32 {
+    pub fn adder(num1: i32, num2: i32) -> i32 {
         num1 + num2
     }
 
@@ -27,6 +52,10 @@ pub mod c11 {
     }
 
     impl Rectangle {
+        pub fn new(length: u32, width: u32) -> Rectangle {
+            Rectangle { length, width }
+        }
+
         pub fn can_hold(&self, other: &Rectangle) -> bool {
             self.length > other.length && self.width > other.width
         }
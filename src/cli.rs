@@ -0,0 +1,1216 @@
+use std::env;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::allocations;
+use crate::chapters::{self, Chapter, Section};
+use crate::exercises::{self, CumulativeSong, Temperature};
+use crate::float::{self, Float, Width};
+use crate::guessing_game::blitz::{self, Stopwatch, TimeLimit, Timer};
+use crate::guessing_game::bulls_and_cows::{self, BullsAndCows, Knuth};
+use crate::guessing_game::daily::{self, Daily, Date};
+use crate::guessing_game::hints;
+use crate::guessing_game::leaderboard::{self, GameRecord, Leaderboard};
+use crate::guessing_game::reverse::ReverseGame;
+use crate::guessing_game::solver::{self, STRATEGIES};
+use crate::guessing_game::transcript::{self, Replay, Transcript};
+use crate::guessing_game::Difficulty;
+use crate::guessing_game::{GameConfig, GameResult, GuessingGame};
+use crate::inspect;
+use crate::interpreter;
+use crate::literal;
+use crate::overflow::{self, Op};
+use crate::unicode;
+
+pub const USAGE: &str = "\
+Usage:
+    the-rust-programming-language list [--sections]
+    the-rust-programming-language run <chapter|all> [--section <name>] [--time]
+    the-rust-programming-language play [--difficulty <easy|normal|hard|MIN-MAX>]
+                                       [--max-attempts <n>] [--seed <n>]
+                                       [--player <name>] [--scores <file>]
+                                       [--hints <hot-cold,warmer,parity,reveal[:n]>]
+                                       [--transcript <file>] [--blitz <time> | --per-guess <time>]
+    the-rust-programming-language replay <transcript>
+    the-rust-programming-language daily [--date YYYY-MM-DD] [--vary-range] [--max-attempts <n>]
+                                        [--player <name>] [--scores <file>]
+    the-rust-programming-language bulls [--digits <n>] [--max-attempts <n>] [--seed <n>] [--solver]
+    the-rust-programming-language temperature <degrees><C|F>...
+    the-rust-programming-language fibonacci <n>
+    the-rust-programming-language carol [--song <file>]
+    the-rust-programming-language inspect
+    the-rust-programming-language literal <literal>...
+    the-rust-programming-language overflow <operation> <a> [b]
+    the-rust-programming-language float [--f32] <value>...
+    the-rust-programming-language float [--f32] --explain <a+b|a-b|a*b>
+    the-rust-programming-language char <text>...
+    the-rust-programming-language repl
+    the-rust-programming-language leaderboard [--player <name>] [--scores <file>]
+    the-rust-programming-language solve [--strategy <binary|random|linear|all>] [--games <n>]
+                                        [--difficulty <level>] [--max-attempts <n>] [--seed <n>]
+    the-rust-programming-language reverse [--difficulty <level>] [--strategy <name>] [--seed <n>]
+
+Examples:
+    run 4
+    run all
+    run 6 --section if_let
+    run 10 --time
+    temperature 98.6F -40C
+    fibonacci 1000
+    carol --song rushes.txt
+    inspect
+    literal 0b1111_0000 255u8 -1i64 b'A'
+    overflow add 200 100
+    overflow neg -128
+    overflow shl 1 8
+    float 0.1 1e-310 -0 inf
+    float --f32 16777217
+    float --explain 0.1+0.2
+    char 😻 é 👍🏽
+    repl
+    play --difficulty hard --max-attempts 14
+    play --difficulty 1-50 --player ferris
+    play --hints warmer,parity,reveal:2
+    play --transcript session.txt
+    play --blitz 60s
+    play --per-guess 10s --difficulty hard
+    replay session.txt
+    daily --max-attempts 7
+    daily --date 2024-02-29 --vary-range
+    bulls --digits 3
+    bulls --solver --seed 7
+    leaderboard --player ferris
+    solve --games 10000 --difficulty hard
+    reverse --difficulty easy";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    List {
+        sections: bool,
+    },
+    Run {
+        target: Target,
+        section: Option<String>,
+        time: bool,
+    },
+    Play(PlayOptions),
+    Daily {
+        daily: Daily,
+        max_attempts: Option<u32>,
+        player: Option<String>,
+        scores: Option<PathBuf>,
+    },
+    Bulls(BullsOptions),
+    /// Temperatures to convert to the other scale.
+    Temperature {
+        temperatures: Vec<Temperature>,
+    },
+    Fibonacci {
+        n: u32,
+    },
+    /// Sings a cumulative song: the one in `song`, or "The Twelve Days of Christmas".
+    Carol {
+        song: Option<PathBuf>,
+    },
+    /// A memory-layout table of every chapter 3 type.
+    Inspect,
+    /// Integer literals to parse and convert.
+    Literal {
+        literals: Vec<String>,
+    },
+    /// An operation applied to the operands in every integer type.
+    Overflow {
+        op: Op,
+        operands: Vec<String>,
+    },
+    /// The IEEE 754 layout of each value, or an explanation of how `explain` rounds.
+    Float {
+        width: Width,
+        values: Vec<String>,
+        explain: Option<String>,
+    },
+    /// Texts to take apart `char` by `char`.
+    Char {
+        texts: Vec<String>,
+    },
+    /// Reads let-bindings and expressions from stdin and evaluates them.
+    Repl,
+    Leaderboard {
+        player: Option<String>,
+        scores: Option<PathBuf>,
+    },
+    Replay {
+        transcript: PathBuf,
+    },
+    Solve(SolveOptions),
+    Reverse {
+        difficulty: Difficulty,
+        strategy: String,
+        seed: Option<u64>,
+    },
+    Help,
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct PlayOptions {
+    pub config: GameConfig,
+    /// Seeds the secret number; a random seed is used when absent.
+    pub seed: Option<u64>,
+    /// The name results are saved under; defaults to the `USER` environment variable.
+    pub player: Option<String>,
+    /// The scores file; defaults to [`leaderboard::DEFAULT_PATH`].
+    pub scores: Option<PathBuf>,
+    /// Hint names as understood by [`hints::by_name`], shown in this order.
+    pub hints: Vec<String>,
+    /// Where to write a transcript of the session, if anywhere.
+    pub transcript: Option<PathBuf>,
+    /// Makes it a blitz game: guesses are timed and the game is lost when time runs out.
+    pub time_limit: Option<TimeLimit>,
+    /// Set for a daily challenge, so its result is saved as that day's.
+    pub daily: Option<Date>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BullsOptions {
+    /// How many digits the code has.
+    pub digits: usize,
+    pub max_attempts: Option<u32>,
+    pub seed: Option<u64>,
+    /// Let Knuth's minimax strategy crack the code instead of reading guesses.
+    pub solver: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SolveOptions {
+    pub config: GameConfig,
+    /// A strategy name, or `None` to compare all of them.
+    pub strategy: Option<String>,
+    pub games: usize,
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Target {
+    All,
+    Chapter(u8),
+}
+
+pub fn parse(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter().map(String::as_str);
+
+    match args.next() {
+        None | Some("help") | Some("--help") | Some("-h") => Ok(Command::Help),
+        Some("list") => match args.next() {
+            None => Ok(Command::List { sections: false }),
+            Some("--sections") => Ok(Command::List { sections: true }),
+            Some(other) => Err(format!("unexpected argument `{}`", other)),
+        },
+        Some("run") => {
+            let target = match args.next() {
+                Some("all") => Target::All,
+                Some(number) => match number.parse() {
+                    Ok(number) => Target::Chapter(number),
+                    Err(_) => return Err(format!("`{}` is not a chapter number", number)),
+                },
+                None => return Err(String::from("`run` needs a chapter number or `all`")),
+            };
+
+            let mut section = None;
+            let mut time = false;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--section" | "-s" => match args.next() {
+                        Some(name) => section = Some(String::from(name)),
+                        None => return Err(String::from("`--section` needs a name")),
+                    },
+                    "--time" => time = true,
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+
+            if section.is_some() && target == Target::All {
+                return Err(String::from("`--section` needs a single chapter"));
+            }
+
+            Ok(Command::Run {
+                target,
+                section,
+                time,
+            })
+        }
+        Some("play") => {
+            let mut options = PlayOptions::default();
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--difficulty" | "-d" => {
+                        options.config.difficulty = value(arg, args.next())?.parse()?
+                    }
+                    "--max-attempts" => {
+                        options.config.max_attempts = Some(attempt_limit(arg, args.next())?)
+                    }
+                    "--seed" => options.seed = Some(number(arg, args.next())?),
+                    "--player" => options.player = Some(player_name(value(arg, args.next())?)?),
+                    "--scores" => options.scores = Some(PathBuf::from(value(arg, args.next())?)),
+                    "--transcript" => {
+                        options.transcript = Some(PathBuf::from(value(arg, args.next())?))
+                    }
+                    "--blitz" => {
+                        let budget = blitz::parse_duration(value(arg, args.next())?)?;
+                        options.time_limit = Some(TimeLimit::Total(budget));
+                    }
+                    "--per-guess" => {
+                        let limit = blitz::parse_duration(value(arg, args.next())?)?;
+                        options.time_limit = Some(TimeLimit::PerGuess(limit));
+                    }
+                    "--hints" => {
+                        for name in value(arg, args.next())?.split(',') {
+                            // Built here only to reject unknown names before the game starts.
+                            hints::by_name(name)?;
+                            options.hints.push(name.to_string());
+                        }
+                    }
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+
+            // A replay can't reproduce how long each guess took, so timed feedback would differ.
+            if options.transcript.is_some() && options.time_limit.is_some() {
+                return Err(String::from(
+                    "`--transcript` can't be combined with `--blitz` or `--per-guess`",
+                ));
+            }
+            Ok(Command::Play(options))
+        }
+        Some("replay") => match (args.next(), args.next()) {
+            (Some(path), None) => Ok(Command::Replay {
+                transcript: PathBuf::from(path),
+            }),
+            (None, _) => Err(String::from("`replay` needs a transcript file")),
+            (Some(_), Some(other)) => Err(format!("unexpected argument `{}`", other)),
+        },
+        Some("daily") => {
+            let mut date = None;
+            let mut vary_range = false;
+            let mut max_attempts = None;
+            let mut player = None;
+            let mut scores = None;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--date" => date = Some(value(arg, args.next())?.parse()?),
+                    "--vary-range" => vary_range = true,
+                    "--max-attempts" => max_attempts = Some(attempt_limit(arg, args.next())?),
+                    "--player" => player = Some(player_name(value(arg, args.next())?)?),
+                    "--scores" => scores = Some(PathBuf::from(value(arg, args.next())?)),
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+            Ok(Command::Daily {
+                daily: Daily {
+                    date: date.unwrap_or_else(Date::today),
+                    vary_range,
+                },
+                max_attempts,
+                player,
+                scores,
+            })
+        }
+        Some("bulls") => {
+            let mut options = BullsOptions {
+                digits: 4,
+                max_attempts: None,
+                seed: None,
+                solver: false,
+            };
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--digits" => options.digits = number(arg, args.next())?,
+                    "--max-attempts" => {
+                        options.max_attempts = Some(attempt_limit(arg, args.next())?)
+                    }
+                    "--seed" => options.seed = Some(number(arg, args.next())?),
+                    "--solver" => options.solver = true,
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+
+            let max_digits = if options.solver {
+                bulls_and_cows::MAX_SOLVER_DIGITS
+            } else {
+                bulls_and_cows::MAX_DIGITS
+            };
+            if !(1..=max_digits).contains(&options.digits) {
+                return Err(format!("`--digits` has to be between 1 and {}", max_digits));
+            }
+            Ok(Command::Bulls(options))
+        }
+        Some("temperature") => {
+            let temperatures = args
+                .map(str::parse)
+                .collect::<Result<Vec<Temperature>, _>>()?;
+            if temperatures.is_empty() {
+                return Err(String::from("`temperature` needs at least one temperature"));
+            }
+            Ok(Command::Temperature { temperatures })
+        }
+        Some("fibonacci") => {
+            let n = number("fibonacci", args.next())?;
+            if n > exercises::fibonacci::MAX_N {
+                return Err(format!(
+                    "`fibonacci` goes up to {}",
+                    exercises::fibonacci::MAX_N
+                ));
+            }
+            match args.next() {
+                None => Ok(Command::Fibonacci { n }),
+                Some(other) => Err(format!("unexpected argument `{}`", other)),
+            }
+        }
+        Some("carol") => {
+            let mut song = None;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--song" => song = Some(PathBuf::from(value(arg, args.next())?)),
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+            Ok(Command::Carol { song })
+        }
+        Some("inspect") => match args.next() {
+            None => Ok(Command::Inspect),
+            Some(other) => Err(format!("unexpected argument `{}`", other)),
+        },
+        Some("literal") => {
+            let literals: Vec<String> = args.map(String::from).collect();
+            if literals.is_empty() {
+                return Err(String::from("`literal` needs at least one literal"));
+            }
+            Ok(Command::Literal { literals })
+        }
+        Some("overflow") => {
+            let op: Op = args
+                .next()
+                .ok_or_else(|| format!("`overflow` needs an operation: {}", Op::NAMES))?
+                .parse()?;
+            let operands: Vec<String> = args.map(String::from).collect();
+            let expected = if op.is_unary() { 1 } else { 2 };
+            if operands.len() != expected {
+                return Err(String::from(if op.is_unary() {
+                    "this operation takes one operand"
+                } else {
+                    "this operation takes two operands"
+                }));
+            }
+            for operand in &operands {
+                literal::parse_operand(operand)
+                    .map_err(|error| format!("`{}`: {}", operand, error))?;
+            }
+            Ok(Command::Overflow { op, operands })
+        }
+        Some("float") => {
+            let mut width = Width::F64;
+            let mut values = Vec::new();
+            let mut explain = None;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--f32" => width = Width::F32,
+                    "--explain" => explain = Some(value(arg, args.next())?.to_string()),
+                    value => values.push(value.to_string()),
+                }
+            }
+
+            match &explain {
+                Some(_) if !values.is_empty() => {
+                    return Err(String::from(
+                        "`--explain` takes a single expression, not values to inspect",
+                    ))
+                }
+                Some(expression) => {
+                    float::explain(expression, width)?;
+                }
+                None if values.is_empty() => {
+                    return Err(String::from("`float` needs at least one value"))
+                }
+                None => {
+                    for value in &values {
+                        Float::parse(value, width)?;
+                    }
+                }
+            }
+            Ok(Command::Float {
+                width,
+                values,
+                explain,
+            })
+        }
+        Some("char") => {
+            let texts: Vec<String> = args.map(String::from).collect();
+            if texts.is_empty() {
+                return Err(String::from("`char` needs some text"));
+            }
+            Ok(Command::Char { texts })
+        }
+        Some("repl") => match args.next() {
+            None => Ok(Command::Repl),
+            Some(other) => Err(format!("unexpected argument `{}`", other)),
+        },
+        Some("leaderboard") => {
+            let mut player = None;
+            let mut scores = None;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--player" => player = Some(player_name(value(arg, args.next())?)?),
+                    "--scores" => scores = Some(PathBuf::from(value(arg, args.next())?)),
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+            Ok(Command::Leaderboard { player, scores })
+        }
+        Some("solve") => {
+            let mut options = SolveOptions {
+                config: GameConfig::default(),
+                strategy: None,
+                games: 1000,
+                seed: None,
+            };
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--strategy" => match value(arg, args.next())? {
+                        "all" => options.strategy = None,
+                        name => options.strategy = Some(strategy_name(name)?),
+                    },
+                    "--games" => options.games = number(arg, args.next())?,
+                    "--difficulty" | "-d" => {
+                        options.config.difficulty = value(arg, args.next())?.parse()?
+                    }
+                    "--max-attempts" => {
+                        options.config.max_attempts = Some(attempt_limit(arg, args.next())?)
+                    }
+                    "--seed" => options.seed = Some(number(arg, args.next())?),
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+            Ok(Command::Solve(options))
+        }
+        Some("reverse") => {
+            let mut difficulty = Difficulty::default();
+            let mut strategy = String::from("binary");
+            let mut seed = None;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--difficulty" | "-d" => difficulty = value(arg, args.next())?.parse()?,
+                    "--strategy" => strategy = strategy_name(value(arg, args.next())?)?,
+                    "--seed" => seed = Some(number(arg, args.next())?),
+                    other => return Err(format!("unexpected argument `{}`", other)),
+                }
+            }
+            Ok(Command::Reverse {
+                difficulty,
+                strategy,
+                seed,
+            })
+        }
+        Some(other) => Err(format!("unknown command `{}`", other)),
+    }
+}
+
+fn value<'a>(flag: &str, value: Option<&'a str>) -> Result<&'a str, String> {
+    value.ok_or_else(|| format!("`{}` needs a value", flag))
+}
+
+fn strategy_name(name: &str) -> Result<String, String> {
+    if STRATEGIES.contains(&name) {
+        Ok(name.to_string())
+    } else {
+        Err(format!(
+            "unknown strategy `{}` (available: {})",
+            name,
+            STRATEGIES.join(", ")
+        ))
+    }
+}
+
+fn player_name(name: &str) -> Result<String, String> {
+    // Tabs and newlines would break the scores file, which is one tab-separated record per line.
+    if name.trim().is_empty() || name.contains(|c: char| c.is_control()) {
+        return Err(format!("`{}` is not a usable player name", name));
+    }
+    Ok(name.trim().to_string())
+}
+
+fn number<T: std::str::FromStr>(flag: &str, arg: Option<&str>) -> Result<T, String> {
+    let arg = value(flag, arg)?;
+    arg.parse()
+        .map_err(|_| format!("`{}` expects a number, got `{}`", flag, arg))
+}
+
+/// A game with no attempts would be lost before it started.
+fn attempt_limit(flag: &str, arg: Option<&str>) -> Result<u32, String> {
+    match number(flag, arg)? {
+        0 => Err(format!("`{}` has to be at least 1", flag)),
+        attempts => Ok(attempts),
+    }
+}
+
+pub fn execute(command: Command, out: &mut dyn Write) -> Result<(), String> {
+    match command {
+        Command::Help => writeln!(out, "{}", USAGE).map_err(write_error)?,
+        Command::List { sections } => {
+            for chapter in chapters::CHAPTERS {
+                writeln!(out, "{:>2}. {}", chapter.number(), chapter.title())
+                    .map_err(write_error)?;
+                if sections {
+                    for section in chapter.sections() {
+                        writeln!(out, "      {}", section.name()).map_err(write_error)?;
+                    }
+                }
+            }
+        }
+        Command::Run {
+            target: Target::All,
+            time,
+            ..
+        } => {
+            for chapter in chapters::CHAPTERS {
+                run_chapter(*chapter, out, time).map_err(write_error)?;
+            }
+        }
+        Command::Run {
+            target: Target::Chapter(number),
+            section,
+            time,
+        } => {
+            let chapter =
+                chapters::find(number).ok_or_else(|| format!("there is no chapter {}", number))?;
+
+            match section {
+                None => run_chapter(chapter, out, time).map_err(write_error)?,
+                Some(name) => {
+                    let section = chapter
+                        .section(&name)
+                        .ok_or_else(|| unknown_section(chapter, &name))?;
+                    run_section(section, out, time).map_err(write_error)?;
+                }
+            }
+        }
+        Command::Play(options) => {
+            play(&options, out).map_err(write_error)?;
+        }
+        Command::Daily {
+            daily,
+            max_attempts,
+            player,
+            scores,
+        } => {
+            let options = PlayOptions {
+                config: daily.config(max_attempts),
+                seed: Some(daily.seed()),
+                player,
+                scores,
+                daily: Some(daily.date),
+                ..PlayOptions::default()
+            };
+            writeln!(out, "Daily challenge for {}", daily.date).map_err(write_error)?;
+            let (game, result) = play(&options, out).map_err(write_error)?;
+
+            if !matches!(result, GameResult::Abandoned { .. }) {
+                writeln!(
+                    out,
+                    "\nShare your result:\n{}",
+                    daily::share(&daily, &game, result)
+                )
+                .map_err(write_error)?;
+            }
+        }
+        Command::Bulls(options) => bulls(&options, out).map_err(write_error)?,
+        Command::Replay { transcript } => replay(&transcript, out)?,
+        Command::Solve(options) => solve(&options, out).map_err(write_error)?,
+        Command::Reverse {
+            difficulty,
+            strategy,
+            seed,
+        } => {
+            let strategy = solver::by_name(&strategy, seed.unwrap_or_else(rand::random))
+                .expect("strategy names are checked while parsing");
+            let mut game = ReverseGame::new(difficulty.range(), strategy);
+            let stdin = io::stdin();
+            game.play(stdin.lock(), out).map_err(write_error)?;
+        }
+        Command::Temperature { temperatures } => {
+            for temperature in temperatures {
+                writeln!(out, "{} = {}", temperature, temperature.convert())
+                    .map_err(write_error)?;
+            }
+        }
+        Command::Fibonacci { n } => {
+            let number = exercises::fibonacci(n);
+            writeln!(out, "F({}) = {}", n, number).map_err(write_error)?;
+            if number.to_u128().is_none() {
+                writeln!(out, "({} digits; too large for a u128)", number.digits())
+                    .map_err(write_error)?;
+            }
+        }
+        Command::Carol { song } => {
+            let song = match song {
+                Some(path) => {
+                    let text = std::fs::read_to_string(&path)
+                        .map_err(|error| format!("could not read {}: {}", path.display(), error))?;
+                    CumulativeSong::parse(&text)
+                        .map_err(|error| format!("{}: {}", path.display(), error))?
+                }
+                None => CumulativeSong::twelve_days_of_christmas(),
+            };
+            song.render(out).map_err(write_error)?;
+        }
+        Command::Inspect => inspect::render_table(out).map_err(write_error)?,
+        Command::Literal { literals } => {
+            for (index, text) in literals.iter().enumerate() {
+                if index > 0 {
+                    writeln!(out).map_err(write_error)?;
+                }
+                writeln!(out, "{}", text).map_err(write_error)?;
+                match literal::parse(text) {
+                    Ok(literal) => literal.render(out).map_err(write_error)?,
+                    Err(error) => writeln!(out, "error: {}", error).map_err(write_error)?,
+                }
+            }
+        }
+        Command::Overflow { op, operands } => {
+            let parsed: Vec<_> = operands
+                .iter()
+                .map(|operand| literal::parse_operand(operand))
+                .collect::<Result<_, _>>()
+                .map_err(|error| error.to_string())?;
+            let rows = overflow::explore(op, &parsed[0], parsed.get(1));
+            overflow::render(
+                out,
+                op,
+                &operands[0],
+                operands.get(1).map(String::as_str),
+                &rows,
+            )
+            .map_err(write_error)?;
+        }
+        Command::Float {
+            width,
+            values,
+            explain,
+        } => {
+            if let Some(expression) = explain {
+                let explanation = float::explain(&expression, width)?;
+                writeln!(out, "{}", explanation).map_err(write_error)?;
+            }
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    writeln!(out).map_err(write_error)?;
+                }
+                let float = Float::parse(value, width)?;
+                float.render(out).map_err(write_error)?;
+                if let Some(note) = float::rounding_note(value, &float) {
+                    writeln!(out, "  rounding     {}", note).map_err(write_error)?;
+                }
+            }
+        }
+        Command::Char { texts } => {
+            for (index, text) in texts.iter().enumerate() {
+                if index > 0 {
+                    writeln!(out).map_err(write_error)?;
+                }
+                unicode::render(out, text).map_err(write_error)?;
+            }
+        }
+        Command::Repl => {
+            let stdin = io::stdin();
+            interpreter::repl(stdin.lock(), out).map_err(write_error)?;
+        }
+        Command::Leaderboard { player, scores } => {
+            let path = scores.unwrap_or_else(|| PathBuf::from(leaderboard::DEFAULT_PATH));
+            let board = Leaderboard::load(&path).map_err(|error| error.to_string())?;
+            board.render(out, player.as_deref()).map_err(write_error)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn run_chapter(chapter: &dyn Chapter, out: &mut dyn Write, time: bool) -> io::Result<()> {
+    writeln!(out, "{}", chapter.heading())?;
+
+    for section in chapter.sections() {
+        run_section(section, out, time)?;
+    }
+
+    Ok(())
+}
+
+fn run_section(section: &Section, out: &mut dyn Write, time: bool) -> io::Result<()> {
+    let start = Instant::now();
+    let (result, counts) = allocations::Counts::during(|| section.run(out));
+    result?;
+
+    // Timings and counts go to stderr so they never mix with what the examples print.
+    if time {
+        eprintln!("[{}: {:?}]", section.name(), start.elapsed());
+    }
+    if allocations::is_installed() {
+        eprintln!("[{}: {}]", section.name(), counts);
+    }
+
+    Ok(())
+}
+
+/// Plays one game on stdin and saves the result; hands back the finished game.
+fn play(options: &PlayOptions, out: &mut dyn Write) -> io::Result<(GuessingGame, GameResult)> {
+    let seed = options.seed.unwrap_or_else(rand::random);
+    // The transcript knows how to set up the game from the seed, so even unrecorded games are
+    // built through one; that way a recorded game can't drift from a played one.
+    let transcript = Transcript::new(seed, options.config, options.hints.clone(), unix_now());
+    let mut game = transcript
+        .game()
+        .expect("hint names are checked while parsing");
+    if options.transcript.is_some() {
+        game.record(transcript);
+    }
+
+    let range = game.range();
+    writeln!(
+        out,
+        "Guess the number between {} and {}!",
+        range.start(),
+        range.end()
+    )?;
+    if let Some(max_attempts) = options.config.max_attempts {
+        writeln!(out, "You have {} attempts.", max_attempts)?;
+    }
+    match options.time_limit {
+        Some(TimeLimit::Total(budget)) => writeln!(
+            out,
+            "You have {:.1}s for the whole game.",
+            budget.as_secs_f64()
+        )?,
+        Some(TimeLimit::PerGuess(limit)) => {
+            writeln!(out, "You have {:.1}s for each guess.", limit.as_secs_f64())?
+        }
+        None => (),
+    }
+
+    let stdin = io::stdin();
+    let start = Instant::now();
+    if options.time_limit.is_some() {
+        game.time(Timer::new(options.time_limit, Box::new(Stopwatch::start())));
+    }
+    let result = game.play(stdin.lock(), &mut *out)?;
+
+    if let Some(timer) = game.timer() {
+        writeln!(out, "Attempts: {}. {}.", game.attempts(), timer.stats())?;
+    }
+
+    if let (Some(path), Some(transcript)) = (&options.transcript, game.take_transcript()) {
+        transcript.save(path)?;
+        writeln!(
+            out,
+            "Wrote a transcript of this game to {}.",
+            path.display()
+        )?;
+    }
+
+    let (attempts, won) = match result {
+        GameResult::Won { attempts } => (attempts, true),
+        GameResult::Lost { attempts, .. }
+        | GameResult::CodeLost { attempts, .. }
+        | GameResult::OutOfTime { attempts, .. } => (attempts, false),
+        // Abandoned games never finished, so they don't go on the leaderboard.
+        GameResult::Abandoned { .. } => return Ok((game, result)),
+    };
+    let range = game.range();
+    let record = GameRecord {
+        player: options.player.clone().unwrap_or_else(default_player),
+        min: *range.start(),
+        max: *range.end(),
+        attempts,
+        duration: start.elapsed(),
+        timestamp: unix_now(),
+        won,
+        daily: options.daily,
+    };
+
+    let path = options
+        .scores
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(leaderboard::DEFAULT_PATH));
+    Leaderboard::append(&path, &record)?;
+    writeln!(
+        out,
+        "Saved {}'s result to {}.",
+        record.player,
+        path.display()
+    )?;
+
+    Ok((game, result))
+}
+
+fn bulls(options: &BullsOptions, out: &mut dyn Write) -> io::Result<()> {
+    let seed = options.seed.unwrap_or_else(rand::random);
+    let mut game = BullsAndCows::new(
+        &mut StdRng::seed_from_u64(seed),
+        options.digits,
+        options.max_attempts,
+    );
+
+    let start = Instant::now();
+    if options.solver {
+        writeln!(
+            out,
+            "Knuth's minimax strategy is cracking a {}-digit code (seed {}).",
+            options.digits, seed
+        )?;
+        bulls_and_cows::solve(&mut game, &mut Knuth::new(options.digits));
+    } else {
+        writeln!(
+            out,
+            "Guess the {}-digit code; every digit is different!",
+            options.digits
+        )?;
+        if let Some(max_attempts) = options.max_attempts {
+            writeln!(out, "You have {} attempts.", max_attempts)?;
+        }
+
+        let stdin = io::stdin();
+        if let GameResult::Abandoned { .. } = game.play(stdin.lock(), &mut *out)? {
+            return Ok(());
+        }
+    }
+
+    writeln!(out)?;
+    game.render_summary(out, start.elapsed())
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since| since.as_secs())
+        .unwrap_or(0)
+}
+
+fn replay(path: &Path, out: &mut dyn Write) -> Result<(), String> {
+    let transcript = Transcript::load(path).map_err(|error| error.to_string())?;
+    let replayed = transcript::replay(&transcript)?;
+
+    show_replay(&transcript, &replayed, out).map_err(write_error)?;
+
+    if !replayed.mismatches.is_empty() {
+        return Err(format!(
+            "{} of {} guesses in {} got different feedback",
+            replayed.mismatches.len(),
+            transcript.entries.len(),
+            path.display()
+        ));
+    }
+    if !replayed.matches(&transcript) {
+        return Err(format!(
+            "{} recorded the outcome {:?}, but the replay ended with {:?}",
+            path.display(),
+            transcript.result,
+            replayed.result
+        ));
+    }
+
+    writeln!(
+        out,
+        "Identical feedback for all {} guesses; the game ended the same way ({:?}).",
+        transcript.entries.len(),
+        replayed.result
+    )
+    .map_err(write_error)
+}
+
+fn show_replay(transcript: &Transcript, replayed: &Replay, out: &mut dyn Write) -> io::Result<()> {
+    let range = transcript.config.difficulty.range();
+    writeln!(
+        out,
+        "Replaying seed {} ({}-{}), started at {}:",
+        transcript.seed,
+        range.start(),
+        range.end(),
+        transcript.started
+    )?;
+
+    for (entry, feedback) in transcript.entries.iter().zip(&replayed.feedback) {
+        writeln!(out, "[+{}ms] > {}", entry.elapsed.as_millis(), entry.input)?;
+        write!(out, "{}", feedback)?;
+    }
+
+    for mismatch in &replayed.mismatches {
+        writeln!(
+            out,
+            "Guess {} (`{}`) differs:",
+            mismatch.guess, mismatch.input
+        )?;
+        writeln!(out, "  recorded: {:?}", mismatch.expected)?;
+        writeln!(out, "  replayed: {:?}", mismatch.actual)?;
+    }
+
+    Ok(())
+}
+
+fn solve(options: &SolveOptions, out: &mut dyn Write) -> io::Result<()> {
+    let seed = options.seed.unwrap_or_else(rand::random);
+    let range = options.config.difficulty.range();
+    writeln!(
+        out,
+        "{} games between {} and {} (seed {})",
+        options.games,
+        range.start(),
+        range.end(),
+        seed
+    )?;
+    writeln!(
+        out,
+        "{:<9} {:>7} {:>8} {:>8} {:>6} {:>7}",
+        "strategy", "games", "mean", "median", "worst", "losses"
+    )?;
+
+    for &name in STRATEGIES {
+        if options
+            .strategy
+            .as_deref()
+            .is_some_and(|wanted| wanted != name)
+        {
+            continue;
+        }
+
+        let mut strategy = solver::by_name(name, seed ^ 0x5eed).expect("known strategy");
+        // Every strategy faces the same sequence of secret numbers.
+        let mut secrets = StdRng::seed_from_u64(seed);
+        let stats = solver::simulate(
+            strategy.as_mut(),
+            &options.config,
+            options.games,
+            &mut secrets,
+        );
+        writeln!(out, "{:<9} {}", strategy.name(), stats)?;
+    }
+
+    Ok(())
+}
+
+fn default_player() -> String {
+    env::var("USER")
+        .or_else(|_| env::var("USERNAME"))
+        .ok()
+        .and_then(|name| player_name(&name).ok())
+        .unwrap_or_else(|| String::from("anonymous"))
+}
+
+fn write_error(error: io::Error) -> String {
+    format!("could not write output: {}", error)
+}
+
+fn unknown_section(chapter: &dyn Chapter, name: &str) -> String {
+    let names: Vec<&str> = chapter.sections().iter().map(Section::name).collect();
+    format!(
+        "chapter {} has no section `{}` (available: {})",
+        chapter.number(),
+        name,
+        names.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use super::{parse, Command, PlayOptions, Target};
+    use crate::exercises::Temperature;
+    use crate::float::Width;
+    use crate::guessing_game::blitz::TimeLimit;
+    use crate::guessing_game::{Difficulty, GameConfig};
+    use crate::overflow::Op;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn run_with_section_selects_a_single_section() {
+        assert_eq!(
+            parse(&args("run 6 --section if_let")),
+            Ok(Command::Run {
+                target: Target::Chapter(6),
+                section: Some(String::from("if_let")),
+                time: false,
+            })
+        );
+    }
+
+    #[test]
+    fn run_all_cannot_be_combined_with_a_section() {
+        assert!(parse(&args("run all --section if_let")).is_err());
+    }
+
+    #[test]
+    fn play_reads_difficulty_and_attempt_limit() {
+        assert_eq!(
+            parse(&args("play --difficulty 1-50 --max-attempts 6")),
+            Ok(Command::Play(PlayOptions {
+                config: GameConfig {
+                    difficulty: Difficulty::Custom { min: 1, max: 50 },
+                    max_attempts: Some(6),
+                },
+                seed: None,
+                player: None,
+                scores: None,
+                hints: Vec::new(),
+                transcript: None,
+                time_limit: None,
+                daily: None,
+            }))
+        );
+        assert!(parse(&args("play --max-attempts many")).is_err());
+        for command in ["play", "daily", "bulls", "solve"] {
+            assert_eq!(
+                parse(&args(&format!("{} --max-attempts 0", command))),
+                Err(String::from("`--max-attempts` has to be at least 1"))
+            );
+        }
+    }
+
+    #[test]
+    fn play_checks_hint_names() {
+        match parse(&args("play --hints hot-cold,reveal:2")) {
+            Ok(Command::Play(options)) => assert_eq!(options.hints, ["hot-cold", "reveal:2"]),
+            other => panic!("unexpected parse result: {:?}", other),
+        }
+        assert!(parse(&args("play --hints hot-cold,psychic")).is_err());
+    }
+
+    #[test]
+    fn play_reads_a_time_limit() {
+        match parse(&args("play --per-guess 1.5s")) {
+            Ok(Command::Play(options)) => assert_eq!(
+                options.time_limit,
+                Some(TimeLimit::PerGuess(Duration::from_millis(1500)))
+            ),
+            other => panic!("unexpected parse result: {:?}", other),
+        }
+        assert!(parse(&args("play --blitz forever")).is_err());
+        assert!(parse(&args("play --blitz 60s --transcript t.txt")).is_err());
+    }
+
+    #[test]
+    fn replay_takes_exactly_one_transcript() {
+        assert_eq!(
+            parse(&args("replay session.txt")),
+            Ok(Command::Replay {
+                transcript: PathBuf::from("session.txt"),
+            })
+        );
+        assert!(parse(&args("replay")).is_err());
+        assert!(parse(&args("replay a.txt b.txt")).is_err());
+    }
+
+    #[test]
+    fn daily_can_be_played_for_another_date() {
+        match parse(&args("daily --date 2024-02-29 --vary-range")) {
+            Ok(Command::Daily { daily, .. }) => {
+                assert_eq!(daily.date.to_string(), "2024-02-29");
+                assert!(daily.vary_range);
+            }
+            other => panic!("unexpected parse result: {:?}", other),
+        }
+        assert!(parse(&args("daily --date 2023-02-29")).is_err());
+    }
+
+    #[test]
+    fn bulls_limits_the_code_length() {
+        match parse(&args("bulls --digits 3 --solver")) {
+            Ok(Command::Bulls(options)) => assert!(options.digits == 3 && options.solver),
+            other => panic!("unexpected parse result: {:?}", other),
+        }
+        assert!(parse(&args("bulls --digits 0")).is_err());
+        assert!(parse(&args("bulls --digits 9")).is_ok());
+        assert!(parse(&args("bulls --digits 4 --solver")).is_ok());
+        assert!(parse(&args("bulls --digits 5 --solver")).is_err());
+    }
+
+    #[test]
+    fn overflow_checks_the_operation_and_its_operands() {
+        assert_eq!(
+            parse(&args("overflow + 200 100")),
+            Ok(Command::Overflow {
+                op: Op::Add,
+                operands: vec![String::from("200"), String::from("100")],
+            })
+        );
+        assert!(parse(&args("overflow neg -128")).is_ok());
+        assert!(parse(&args("overflow neg 1 2")).is_err());
+        assert!(parse(&args("overflow xor 1 2")).is_err());
+        assert!(parse(&args("overflow add 1 0x")).is_err());
+    }
+
+    #[test]
+    fn exercises_check_their_arguments() {
+        assert_eq!(
+            parse(&args("temperature 98.6F -40c")),
+            Ok(Command::Temperature {
+                temperatures: vec![Temperature::Fahrenheit(98.6), Temperature::Celsius(-40.0)],
+            })
+        );
+        assert!(parse(&args("temperature 20K")).is_err());
+        assert_eq!(
+            parse(&args("fibonacci 300")),
+            Ok(Command::Fibonacci { n: 300 })
+        );
+        assert!(parse(&args("fibonacci -1")).is_err());
+        assert!(parse(&args("fibonacci 100000000")).is_err());
+        assert_eq!(
+            parse(&args("carol --song rushes.txt")),
+            Ok(Command::Carol {
+                song: Some(PathBuf::from("rushes.txt")),
+            })
+        );
+    }
+
+    #[test]
+    fn float_inspects_values_or_explains_one_expression() {
+        assert_eq!(
+            parse(&args("float --f32 0.1 -inf")),
+            Ok(Command::Float {
+                width: Width::F32,
+                values: vec![String::from("0.1"), String::from("-inf")],
+                explain: None,
+            })
+        );
+        assert!(parse(&args("float --explain 0.1+0.2")).is_ok());
+        assert!(parse(&args("float --explain 0.1+0.2 0.3")).is_err());
+        assert!(parse(&args("float --explain 1/3")).is_err());
+        assert!(parse(&args("float ten")).is_err());
+        assert!(parse(&args("float")).is_err());
+    }
+
+    #[test]
+    fn char_takes_each_argument_as_a_text() {
+        assert_eq!(
+            parse(&args("char 😻 e\u{301}")),
+            Ok(Command::Char {
+                texts: vec![String::from("😻"), String::from("e\u{301}")],
+            })
+        );
+        assert!(parse(&args("char")).is_err());
+    }
+
+    #[test]
+    fn repl_takes_no_arguments() {
+        assert_eq!(parse(&args("repl")), Ok(Command::Repl));
+        assert!(parse(&args("repl 1+1")).is_err());
+    }
+
+    #[test]
+    fn no_arguments_shows_help() {
+        assert_eq!(parse(&[]), Ok(Command::Help));
+    }
+}
@@ -0,0 +1,10 @@
+//! The programs chapter 3 ends with ("Summary": "try building programs to do the following"),
+//! written as functions the chapter and the command line can both call.
+
+pub mod carol;
+pub mod fibonacci;
+pub mod temperature;
+
+pub use carol::CumulativeSong;
+pub use fibonacci::{fibonacci, BigUint};
+pub use temperature::Temperature;
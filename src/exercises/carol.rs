@@ -0,0 +1,225 @@
+use std::fmt;
+use std::io::{self, Write};
+
+/// A song where every verse adds one line and then repeats all the earlier ones, newest first,
+/// like "The Twelve Days of Christmas". Everything that makes it that particular song is data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CumulativeSong {
+    pub title: String,
+    /// The line every verse opens with; `{ordinal}` becomes "first", "second", ...
+    pub opening: String,
+    /// The line each verse adds, in the order they are added.
+    pub lines: Vec<String>,
+    /// Replaces the first line once it is no longer alone, as in "And a partridge in a pear tree".
+    pub first_line_later: Option<String>,
+}
+
+const ORDINALS: &[&str] = &[
+    "first",
+    "second",
+    "third",
+    "fourth",
+    "fifth",
+    "sixth",
+    "seventh",
+    "eighth",
+    "ninth",
+    "tenth",
+    "eleventh",
+    "twelfth",
+    "thirteenth",
+    "fourteenth",
+    "fifteenth",
+    "sixteenth",
+    "seventeenth",
+    "eighteenth",
+    "nineteenth",
+    "twentieth",
+];
+
+/// "first" for 1, "twelfth" for 12; past the words, "21st", "22nd", ...
+pub fn ordinal(n: usize) -> String {
+    match ORDINALS.get(n.wrapping_sub(1)) {
+        Some(word) => word.to_string(),
+        None => {
+            let suffix = match (n % 10, n % 100) {
+                (_, 11..=13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            };
+            format!("{}{}", n, suffix)
+        }
+    }
+}
+
+impl CumulativeSong {
+    pub fn twelve_days_of_christmas() -> CumulativeSong {
+        CumulativeSong {
+            title: String::from("The Twelve Days of Christmas"),
+            opening: String::from("On the {ordinal} day of Christmas my true love sent to me"),
+            lines: [
+                "A partridge in a pear tree",
+                "Two turtle doves",
+                "Three French hens",
+                "Four calling birds",
+                "Five golden rings",
+                "Six geese a-laying",
+                "Seven swans a-swimming",
+                "Eight maids a-milking",
+                "Nine ladies dancing",
+                "Ten lords a-leaping",
+                "Eleven pipers piping",
+                "Twelve drummers drumming",
+            ]
+            .iter()
+            .map(|line| line.to_string())
+            .collect(),
+            first_line_later: Some(String::from("And a partridge in a pear tree")),
+        }
+    }
+
+    /// Reads a song from text: `title:`, `opening:` and (optionally) `later:` header lines,
+    /// a blank line, then one added line per verse.
+    pub fn parse(text: &str) -> Result<CumulativeSong, String> {
+        let mut title = None;
+        let mut opening = None;
+        let mut first_line_later = None;
+
+        let mut lines = text.lines();
+        for line in &mut lines {
+            if line.trim().is_empty() {
+                break;
+            }
+            let (key, value) = line
+                .split_once(':')
+                .ok_or_else(|| format!("expected `key: value`, found `{}`", line))?;
+            let value = Some(value.trim().to_string());
+            match key.trim() {
+                "title" => title = value,
+                "opening" => opening = value,
+                "later" => first_line_later = value,
+                other => return Err(format!("unknown key `{}`", other)),
+            }
+        }
+
+        let lines: Vec<String> = lines
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect();
+        if lines.is_empty() {
+            return Err(String::from("the song has no verses"));
+        }
+
+        Ok(CumulativeSong {
+            title: title.ok_or("the song needs a `title:`")?,
+            opening: opening.ok_or("the song needs an `opening:`")?,
+            lines,
+            first_line_later,
+        })
+    }
+
+    pub fn verses(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Verse `n` (counting from 1): the opening, then lines `n` down to 1.
+    pub fn verse(&self, n: usize) -> Vec<String> {
+        assert!(
+            (1..=self.verses()).contains(&n),
+            "the song has verses 1 to {}",
+            self.verses()
+        );
+
+        let mut verse = vec![self.opening.replace("{ordinal}", &ordinal(n))];
+        for (index, line) in self.lines[..n].iter().enumerate().rev() {
+            match (&self.first_line_later, index) {
+                (Some(later), 0) if n > 1 => verse.push(later.clone()),
+                _ => verse.push(line.clone()),
+            }
+        }
+        verse
+    }
+
+    /// The title, then every verse, with a blank line between them.
+    pub fn render(&self, out: &mut dyn Write) -> io::Result<()> {
+        write!(out, "{}", self)
+    }
+}
+
+impl fmt::Display for CumulativeSong {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.title)?;
+        for n in 1..=self.verses() {
+            writeln!(f)?;
+            for line in self.verse(n) {
+                writeln!(f, "{}", line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ordinal, CumulativeSong};
+
+    #[test]
+    fn the_partridge_gets_an_and_once_it_has_company() {
+        let song = CumulativeSong::twelve_days_of_christmas();
+
+        assert_eq!(
+            song.verse(1),
+            [
+                "On the first day of Christmas my true love sent to me",
+                "A partridge in a pear tree"
+            ]
+        );
+        assert_eq!(
+            song.verse(3),
+            [
+                "On the third day of Christmas my true love sent to me",
+                "Three French hens",
+                "Two turtle doves",
+                "And a partridge in a pear tree"
+            ]
+        );
+        assert_eq!(song.verse(12).len(), 13);
+        // Title, then 12 verses of 1 + n lines, each after a blank line.
+        assert_eq!(song.to_string().lines().count(), 1 + 12 + (12 + 78));
+    }
+
+    #[test]
+    fn any_cumulative_song_can_be_given_as_text() {
+        let song = CumulativeSong::parse(
+            "title: Green Grow the Rushes\n\
+             opening: I'll sing you {ordinal}, O\n\
+             \n\
+             One is one and all alone and evermore shall be so\n\
+             Two, two, the lily-white boys, clothed all in green, O\n",
+        )
+        .unwrap();
+
+        assert_eq!(song.first_line_later, None);
+        assert_eq!(
+            song.verse(2),
+            [
+                "I'll sing you second, O",
+                "Two, two, the lily-white boys, clothed all in green, O",
+                "One is one and all alone and evermore shall be so"
+            ]
+        );
+        assert!(CumulativeSong::parse("title: No verses\nopening: x\n\n").is_err());
+        assert!(CumulativeSong::parse("opening: x\n\nline\n").is_err());
+    }
+
+    #[test]
+    fn ordinals_past_the_words_get_a_suffix() {
+        assert_eq!(ordinal(12), "twelfth");
+        assert_eq!(ordinal(21), "21st");
+        assert_eq!(ordinal(112), "112th");
+        assert_eq!(ordinal(123), "123rd");
+    }
+}
@@ -0,0 +1,151 @@
+use std::fmt;
+use std::ops::Add;
+
+/// Each limb holds nine decimal digits, so printing never needs a division.
+const LIMB: u32 = 1_000_000_000;
+
+/// An unsigned integer of any size; just enough of one to add Fibonacci numbers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigUint {
+    /// Base-10⁹ digits, least significant first, without leading zero limbs (zero is empty).
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> BigUint {
+        BigUint { limbs: Vec::new() }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// How many decimal digits the number has.
+    pub fn digits(&self) -> usize {
+        match self.limbs.last() {
+            None => 1,
+            Some(top) => (self.limbs.len() - 1) * 9 + top.to_string().len(),
+        }
+    }
+
+    /// The value, if it fits a `u128`.
+    pub fn to_u128(&self) -> Option<u128> {
+        self.limbs.iter().rev().try_fold(0u128, |value, &limb| {
+            value
+                .checked_mul(u128::from(LIMB))?
+                .checked_add(u128::from(limb))
+        })
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(mut value: u128) -> BigUint {
+        let mut limbs = Vec::new();
+        while value > 0 {
+            limbs.push((value % u128::from(LIMB)) as u32);
+            value /= u128::from(LIMB);
+        }
+        BigUint { limbs }
+    }
+}
+
+impl<'a> Add<&'a BigUint> for &'a BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let mut limbs = Vec::with_capacity(self.limbs.len().max(other.limbs.len()) + 1);
+        let mut carry = 0;
+
+        for index in 0..self.limbs.len().max(other.limbs.len()) {
+            let sum = self.limbs.get(index).copied().unwrap_or(0)
+                + other.limbs.get(index).copied().unwrap_or(0)
+                + carry;
+            limbs.push(sum % LIMB);
+            carry = sum / LIMB;
+        }
+        if carry > 0 {
+            limbs.push(carry);
+        }
+
+        BigUint { limbs }
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut limbs = self.limbs.iter().rev();
+        match limbs.next() {
+            None => f.pad("0"),
+            Some(top) => {
+                let mut digits = top.to_string();
+                for limb in limbs {
+                    digits.push_str(&format!("{:09}", limb));
+                }
+                f.pad(&digits)
+            }
+        }
+    }
+}
+
+/// Where [`fibonacci`] stops being quick: F(100 000) has about 21 000 digits.
+pub const MAX_N: u32 = 100_000;
+
+/// The `n`th Fibonacci number, counting F(0) = 0 and F(1) = 1.
+pub fn fibonacci(n: u32) -> BigUint {
+    let (mut current, mut next) = (BigUint::zero(), BigUint::from(1));
+    for _ in 0..n {
+        let sum = &current + &next;
+        current = next;
+        next = sum;
+    }
+    current
+}
+
+/// The `n`th Fibonacci number with plain `u128` arithmetic, or `None` once it overflows
+/// (past F(186)) — the reason [`fibonacci`] needs [`BigUint`].
+pub fn fibonacci_u128(n: u32) -> Option<u128> {
+    let (mut current, mut next) = (0u128, 1u128);
+    for step in 1..=n {
+        let sum = current.checked_add(next);
+        current = next;
+        next = match sum {
+            Some(sum) => sum,
+            // The last step only needs `current`, so its `next` may overflow.
+            None if step == n => 0,
+            None => return None,
+        };
+    }
+    Some(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fibonacci, fibonacci_u128, BigUint};
+
+    #[test]
+    fn starts_like_the_sequence_in_the_book() {
+        let first: Vec<String> = (0..10).map(|n| fibonacci(n).to_string()).collect();
+        assert_eq!(first, ["0", "1", "1", "2", "3", "5", "8", "13", "21", "34"]);
+    }
+
+    #[test]
+    fn agrees_with_u128_until_it_overflows() {
+        for n in 0..=186 {
+            assert_eq!(fibonacci(n).to_u128(), fibonacci_u128(n), "F({})", n);
+        }
+        assert_eq!(fibonacci_u128(187), None);
+        assert_eq!(fibonacci(187).to_u128(), None);
+    }
+
+    #[test]
+    fn carries_across_limbs() {
+        assert_eq!(fibonacci(100).to_string(), "354224848179261915075");
+        assert_eq!(
+            fibonacci(300).to_string(),
+            "222232244629420445529739893461909967206666939096499764990979600"
+        );
+        assert_eq!(fibonacci(1000).digits(), 209);
+        assert_eq!(BigUint::zero().to_string(), "0");
+        assert_eq!(BigUint::from(1_000_000_000).to_string(), "1000000000");
+    }
+}
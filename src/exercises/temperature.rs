@@ -0,0 +1,102 @@
+use std::fmt;
+use std::str::FromStr;
+
+pub fn fahrenheit_to_celsius(fahrenheit: f64) -> f64 {
+    (fahrenheit - 32.0) * 5.0 / 9.0
+}
+
+pub fn celsius_to_fahrenheit(celsius: f64) -> f64 {
+    celsius * 9.0 / 5.0 + 32.0
+}
+
+/// A temperature on one of the two scales the exercise asks about.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Temperature {
+    Celsius(f64),
+    Fahrenheit(f64),
+}
+
+impl Temperature {
+    /// The same temperature on the other scale.
+    pub fn convert(self) -> Temperature {
+        match self {
+            Temperature::Celsius(degrees) => {
+                Temperature::Fahrenheit(celsius_to_fahrenheit(degrees))
+            }
+            Temperature::Fahrenheit(degrees) => {
+                Temperature::Celsius(fahrenheit_to_celsius(degrees))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Temperature {
+    /// One decimal place is plenty, and hides the float noise of `(x - 32) * 5 / 9`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Temperature::Celsius(degrees) => write!(f, "{:.1}°C", degrees),
+            Temperature::Fahrenheit(degrees) => write!(f, "{:.1}°F", degrees),
+        }
+    }
+}
+
+impl FromStr for Temperature {
+    type Err = String;
+
+    /// Reads a number followed by its scale: `98.6F`, `-40c`, `37 °C`.
+    fn from_str(s: &str) -> Result<Temperature, String> {
+        let s = s.trim();
+        let scale = s
+            .chars()
+            .last()
+            .ok_or_else(|| String::from("expected a temperature like `98.6F` or `37C`"))?;
+        let degrees = s[..s.len() - scale.len_utf8()]
+            .trim_end()
+            .trim_end_matches('°')
+            .trim_end();
+        let degrees: f64 = degrees
+            .parse()
+            .map_err(|_| format!("`{}` doesn't start with a number", s))?;
+        if !degrees.is_finite() {
+            return Err(format!("`{}` isn't a finite temperature", s));
+        }
+
+        match scale.to_ascii_uppercase() {
+            'C' => Ok(Temperature::Celsius(degrees)),
+            'F' => Ok(Temperature::Fahrenheit(degrees)),
+            _ => Err(format!(
+                "`{}` needs a scale: C for Celsius or F for Fahrenheit",
+                s
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{celsius_to_fahrenheit, fahrenheit_to_celsius, Temperature};
+
+    #[test]
+    fn converts_the_landmarks_both_ways() {
+        assert_eq!(fahrenheit_to_celsius(212.0), 100.0);
+        assert_eq!(fahrenheit_to_celsius(32.0), 0.0);
+        assert_eq!(celsius_to_fahrenheit(-40.0), -40.0);
+        assert_eq!(
+            "98.6F"
+                .parse::<Temperature>()
+                .unwrap()
+                .convert()
+                .to_string(),
+            "37.0°C"
+        );
+    }
+
+    #[test]
+    fn reads_the_scale_from_the_last_character() {
+        assert_eq!("-40c".parse(), Ok(Temperature::Celsius(-40.0)));
+        assert_eq!("37 °C".parse(), Ok(Temperature::Celsius(37.0)));
+        assert!("37".parse::<Temperature>().is_err());
+        assert!("hotF".parse::<Temperature>().is_err());
+        assert!("infF".parse::<Temperature>().is_err());
+    }
+}
@@ -0,0 +1,945 @@
+//! What a float actually holds, as opposed to what it prints as: the IEEE 754 fields of an
+//! `f32` or `f64`, its exact decimal value, its neighbours, and why `0.1 + 0.2 != 0.3`.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::{self, Write};
+use std::num::FpCategory;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Width {
+    F32,
+    F64,
+}
+
+impl Width {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Width::F32 => "f32",
+            Width::F64 => "f64",
+        }
+    }
+
+    pub fn bits(&self) -> u32 {
+        match self {
+            Width::F32 => 32,
+            Width::F64 => 64,
+        }
+    }
+
+    pub fn exponent_bits(&self) -> u32 {
+        match self {
+            Width::F32 => 8,
+            Width::F64 => 11,
+        }
+    }
+
+    /// The stored fraction bits; the leading 1 of normal numbers is implicit.
+    pub fn mantissa_bits(&self) -> u32 {
+        match self {
+            Width::F32 => 23,
+            Width::F64 => 52,
+        }
+    }
+
+    pub fn bias(&self) -> i32 {
+        (1 << (self.exponent_bits() - 1)) - 1
+    }
+
+    /// `f32::EPSILON` or `f64::EPSILON`: the gap between 1.0 and the next float.
+    pub fn epsilon(&self) -> f64 {
+        match self {
+            Width::F32 => f64::from(f32::EPSILON),
+            Width::F64 => f64::EPSILON,
+        }
+    }
+}
+
+impl fmt::Display for Width {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A float of either width, kept as its bit pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Float {
+    width: Width,
+    bits: u64,
+}
+
+impl Float {
+    pub fn f32(value: f32) -> Float {
+        Float {
+            width: Width::F32,
+            bits: u64::from(value.to_bits()),
+        }
+    }
+
+    pub fn f64(value: f64) -> Float {
+        Float {
+            width: Width::F64,
+            bits: value.to_bits(),
+        }
+    }
+
+    /// Reads `text` the way `text.parse::<f32>()` or `text.parse::<f64>()` would, rounding to
+    /// the nearest float of `width` (an `f32` is not parsed as an `f64` and then narrowed).
+    pub fn parse(text: &str, width: Width) -> Result<Float, String> {
+        let text = text.trim();
+        let error = |_| format!("`{}` is not a number", text);
+        match width {
+            Width::F32 => text.parse().map(Float::f32).map_err(error),
+            Width::F64 => text.parse().map(Float::f64).map_err(error),
+        }
+    }
+
+    pub fn width(&self) -> Width {
+        self.width
+    }
+
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    fn sign_bit(&self) -> u64 {
+        1 << (self.width.bits() - 1)
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.bits & self.sign_bit() != 0
+    }
+
+    /// The raw, biased exponent field.
+    pub fn exponent_field(&self) -> u64 {
+        (self.bits >> self.width.mantissa_bits()) & ((1 << self.width.exponent_bits()) - 1)
+    }
+
+    pub fn mantissa_field(&self) -> u64 {
+        self.bits & ((1 << self.width.mantissa_bits()) - 1)
+    }
+
+    pub fn category(&self) -> FpCategory {
+        match self.width {
+            Width::F32 => f32::from_bits(self.bits as u32).classify(),
+            Width::F64 => f64::from_bits(self.bits).classify(),
+        }
+    }
+
+    /// The power of two the significand is scaled by; `None` for zeros, infinities and NaN.
+    pub fn exponent(&self) -> Option<i32> {
+        match self.category() {
+            FpCategory::Normal => Some(self.exponent_field() as i32 - self.width.bias()),
+            // Subnormals share the smallest normal exponent, without the implicit 1.
+            FpCategory::Subnormal => Some(1 - self.width.bias()),
+            _ => None,
+        }
+    }
+
+    /// `(m, e)` with the magnitude equal to `m × 2^e`, for finite values.
+    fn integer_significand(&self) -> Option<(u64, i32)> {
+        let shift = self.width.mantissa_bits() as i32;
+        match self.category() {
+            FpCategory::Nan | FpCategory::Infinite => None,
+            FpCategory::Zero => Some((0, 0)),
+            FpCategory::Subnormal => Some((self.mantissa_field(), self.exponent()? - shift)),
+            FpCategory::Normal => {
+                Some((self.mantissa_field() | 1 << shift, self.exponent()? - shift))
+            }
+        }
+    }
+
+    /// The significand with its implicit bit, `1.xxx` for normal numbers and `0.xxx` for
+    /// subnormals, exactly.
+    pub fn significand(&self) -> Option<Decimal> {
+        let (mantissa, _) = self.integer_significand()?;
+        Some(Decimal::from(mantissa).times_pow2(-(self.width.mantissa_bits() as i32)))
+    }
+
+    /// The value the bits stand for, digit for digit; `None` for infinities and NaN.
+    pub fn exact(&self) -> Option<Decimal> {
+        let (mantissa, exponent) = self.integer_significand()?;
+        let magnitude = Decimal::from(mantissa).times_pow2(exponent);
+        Some(if self.is_negative() {
+            -magnitude
+        } else {
+            magnitude
+        })
+    }
+
+    /// The value as an `f64`, which holds every `f32` exactly.
+    pub fn value(&self) -> f64 {
+        match self.width {
+            Width::F32 => f64::from(f32::from_bits(self.bits as u32)),
+            Width::F64 => f64::from_bits(self.bits),
+        }
+    }
+
+    fn negate(&self) -> Float {
+        Float {
+            width: self.width,
+            bits: self.bits ^ self.sign_bit(),
+        }
+    }
+
+    /// The smallest float greater than this one (IEEE 754 `nextUp`).
+    pub fn next_up(&self) -> Float {
+        let bits = match self.category() {
+            FpCategory::Nan => self.bits,
+            FpCategory::Infinite if !self.is_negative() => self.bits,
+            // Both zeros step to the smallest positive subnormal.
+            FpCategory::Zero => 1,
+            // Bit patterns of positive floats increase with their value, of negative ones the
+            // other way round.
+            _ if self.is_negative() => self.bits - 1,
+            _ => self.bits + 1,
+        };
+        Float {
+            width: self.width,
+            bits,
+        }
+    }
+
+    /// The largest float less than this one (IEEE 754 `nextDown`).
+    pub fn next_down(&self) -> Float {
+        self.negate().next_up().negate()
+    }
+
+    /// How many floats apart `self` and `other` are; `None` if either is NaN.
+    pub fn ulps_between(&self, other: &Float) -> Option<u64> {
+        let ordered = |float: &Float| -> Option<i128> {
+            if float.category() == FpCategory::Nan {
+                return None;
+            }
+            let magnitude = i128::from(float.bits & !float.sign_bit());
+            Some(if float.is_negative() {
+                -magnitude
+            } else {
+                magnitude
+            })
+        };
+        Some((ordered(self)? - ordered(other)?).unsigned_abs() as u64)
+    }
+
+    /// The bits as `sign exponent mantissa`.
+    pub fn fields(&self) -> String {
+        format!(
+            "{} {:0e$b} {:0m$b}",
+            self.bits >> (self.width.bits() - 1),
+            self.exponent_field(),
+            self.mantissa_field(),
+            e = self.width.exponent_bits() as usize,
+            m = self.width.mantissa_bits() as usize
+        )
+    }
+
+    pub fn render(&self, out: &mut dyn Write) -> io::Result<()> {
+        let width = self.width;
+        writeln!(out, "{} {}", width, self)?;
+        writeln!(out, "  bits         {}", self.fields())?;
+        writeln!(
+            out,
+            "  sign         {} ({})",
+            if self.is_negative() { '-' } else { '+' },
+            self.bits >> (width.bits() - 1)
+        )?;
+
+        let field = self.exponent_field();
+        let exponent = match (self.category(), self.exponent()) {
+            (FpCategory::Normal, Some(exponent)) => {
+                format!("{} - bias {} = {}", field, width.bias(), exponent)
+            }
+            (FpCategory::Subnormal, Some(exponent)) => format!(
+                "0: subnormal, so 1 - bias {} = {} and no implicit leading 1",
+                width.bias(),
+                exponent
+            ),
+            (FpCategory::Zero, _) => String::from("0 and mantissa 0: zero"),
+            (FpCategory::Infinite, _) => String::from("all ones and mantissa 0: infinity"),
+            _ => String::from("all ones and mantissa not 0: NaN"),
+        };
+        writeln!(out, "  exponent     {}", exponent)?;
+
+        if let (Some(significand), Some(exponent)) = (self.significand(), self.exponent()) {
+            writeln!(out, "  significand  {}", significand)?;
+            writeln!(
+                out,
+                "  value        {}{} × 2^{}",
+                if self.is_negative() { "-" } else { "" },
+                significand,
+                exponent
+            )?;
+        }
+        writeln!(out, "  class        {}", category_name(self.category()))?;
+
+        if let Some(exact) = self.exact() {
+            writeln!(out, "  exact        {}", exact)?;
+        }
+        if self.category() != FpCategory::Nan {
+            // The gap to each neighbour is one ULP; there is none next to an infinity.
+            let gap = |low: Float, high: Float, side: &str| {
+                if low.category() == FpCategory::Infinite || high.category() == FpCategory::Infinite
+                {
+                    String::new()
+                } else {
+                    format!(" ({} {})", Float::f64(high.value() - low.value()), side)
+                }
+            };
+            let (down, up) = (self.next_down(), self.next_up());
+            writeln!(out, "  next down    {}{}", down, gap(down, *self, "below"))?;
+            writeln!(out, "  next up      {}{}", up, gap(*self, up, "above"))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Float {
+    /// The shortest text that reads back as the same float, as `{:?}` prints it.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.width {
+            Width::F32 => write!(f, "{:?}", f32::from_bits(self.bits as u32)),
+            Width::F64 => write!(f, "{:?}", f64::from_bits(self.bits)),
+        }
+    }
+}
+
+fn category_name(category: FpCategory) -> &'static str {
+    match category {
+        FpCategory::Normal => "normal",
+        FpCategory::Subnormal => "subnormal",
+        FpCategory::Zero => "zero",
+        FpCategory::Infinite => "infinite",
+        FpCategory::Nan => "NaN",
+    }
+}
+
+/// An exact decimal number, with as many digits as it takes.
+///
+/// Every finite float is a finite decimal (`m × 2^e` with `e < 0` is `m × 5^-e / 10^-e`), so
+/// this is enough to print any of them without rounding, and to do arithmetic on what was
+/// written rather than on what was stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decimal {
+    negative: bool,
+    /// The unscaled value's digits, least significant first, without leading zeros.
+    digits: Vec<u8>,
+    /// How many of the digits are after the decimal point.
+    scale: usize,
+}
+
+impl Decimal {
+    fn new(negative: bool, digits: Vec<u8>, scale: usize) -> Decimal {
+        let mut decimal = Decimal {
+            negative,
+            digits,
+            scale,
+        };
+        while decimal.scale > 0 && decimal.digits.first() == Some(&0) {
+            decimal.digits.remove(0);
+            decimal.scale -= 1;
+        }
+        while decimal.digits.last() == Some(&0) {
+            decimal.digits.pop();
+        }
+        if decimal.digits.is_empty() {
+            decimal.negative = false;
+            decimal.scale = 0;
+        }
+        decimal
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    fn times_small(mut digits: Vec<u8>, factor: u8) -> Vec<u8> {
+        let mut carry = 0;
+        for digit in digits.iter_mut() {
+            let product = *digit as u32 * factor as u32 + carry;
+            *digit = (product % 10) as u8;
+            carry = product / 10;
+        }
+        while carry > 0 {
+            digits.push((carry % 10) as u8);
+            carry /= 10;
+        }
+        digits
+    }
+
+    /// `self × 2^exponent`, which stays exact for negative exponents too.
+    fn times_pow2(&self, exponent: i32) -> Decimal {
+        let mut digits = self.digits.clone();
+        let mut scale = self.scale;
+        if exponent >= 0 {
+            for _ in 0..exponent {
+                digits = Decimal::times_small(digits, 2);
+            }
+        } else {
+            // Dividing by 2 is multiplying by 5 and moving the point one place.
+            for _ in 0..-exponent {
+                digits = Decimal::times_small(digits, 5);
+            }
+            scale += (-exponent) as usize;
+        }
+        Decimal::new(self.negative, digits, scale)
+    }
+
+    /// The digits with `extra` zeros appended, i.e. at a scale of `self.scale + extra`.
+    fn rescaled(&self, extra: usize) -> Vec<u8> {
+        let mut digits = vec![0; extra];
+        digits.extend_from_slice(&self.digits);
+        digits
+    }
+
+    fn compare_digits(a: &[u8], b: &[u8]) -> Ordering {
+        a.len()
+            .cmp(&b.len())
+            .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+    }
+
+    /// Nearest float of `width` to this number, rounding the way parsing does.
+    pub fn to_float(&self, width: Width) -> Float {
+        Float::parse(&self.to_string(), width).expect("a decimal is always a valid float literal")
+    }
+}
+
+impl From<u64> for Decimal {
+    fn from(mut value: u64) -> Decimal {
+        let mut digits = Vec::new();
+        while value > 0 {
+            digits.push((value % 10) as u8);
+            value /= 10;
+        }
+        Decimal::new(false, digits, 0)
+    }
+}
+
+/// The largest exponent `Decimal` parses. f64 needs about 330 either way, plus the digits.
+const MAX_EXPONENT: i32 = 10_000;
+
+impl FromStr for Decimal {
+    type Err = String;
+
+    /// Plain decimal literals with an optional exponent: `0.1`, `-3`, `2.5e-3`.
+    fn from_str(s: &str) -> Result<Decimal, String> {
+        let error = || format!("`{}` is not a decimal number", s);
+        let text = s.trim();
+        let (negative, text) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        let (mantissa, exponent) = match text.find(['e', 'E']) {
+            Some(index) => (
+                &text[..index],
+                text[index + 1..].parse::<i32>().map_err(|_| error())?,
+            ),
+            None => (text, 0),
+        };
+        // Far beyond any float, and a bigger exponent would mean as many zeros in memory.
+        if exponent.unsigned_abs() > MAX_EXPONENT.unsigned_abs() {
+            return Err(format!("the exponent of `{}` is out of range", s));
+        }
+        let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if whole.is_empty() && fraction.is_empty()
+            || !whole
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(error());
+        }
+
+        let mut digits: Vec<u8> = whole
+            .bytes()
+            .chain(fraction.bytes())
+            .rev()
+            .map(|b| b - b'0')
+            .collect();
+        let mut scale = fraction.len() as i64 - i64::from(exponent);
+        if scale < 0 {
+            digits = [vec![0; (-scale) as usize], digits].concat();
+            scale = 0;
+        }
+        Ok(Decimal::new(negative, digits, scale as usize))
+    }
+}
+
+impl std::ops::Neg for Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Decimal {
+        Decimal::new(!self.negative, self.digits, self.scale)
+    }
+}
+
+impl<'a> std::ops::Add<&'a Decimal> for &'a Decimal {
+    type Output = Decimal;
+
+    fn add(self, other: &Decimal) -> Decimal {
+        let scale = self.scale.max(other.scale);
+        let a = self.rescaled(scale - self.scale);
+        let b = other.rescaled(scale - other.scale);
+
+        if self.negative == other.negative {
+            let mut digits = Vec::with_capacity(a.len().max(b.len()) + 1);
+            let mut carry = 0;
+            for index in 0..a.len().max(b.len()) {
+                let sum = a.get(index).unwrap_or(&0) + b.get(index).unwrap_or(&0) + carry;
+                digits.push(sum % 10);
+                carry = sum / 10;
+            }
+            if carry > 0 {
+                digits.push(carry);
+            }
+            return Decimal::new(self.negative, digits, scale);
+        }
+
+        // Opposite signs: take the smaller magnitude from the larger one, and keep its sign.
+        let (larger, smaller, negative) = match Decimal::compare_digits(&a, &b) {
+            Ordering::Less => (b, a, other.negative),
+            _ => (a, b, self.negative),
+        };
+        let mut digits = Vec::with_capacity(larger.len());
+        let mut borrow = 0;
+        for (index, &digit) in larger.iter().enumerate() {
+            let subtrahend = smaller.get(index).unwrap_or(&0) + borrow;
+            if digit >= subtrahend {
+                digits.push(digit - subtrahend);
+                borrow = 0;
+            } else {
+                digits.push(digit + 10 - subtrahend);
+                borrow = 1;
+            }
+        }
+        Decimal::new(negative, digits, scale)
+    }
+}
+
+impl<'a> std::ops::Sub<&'a Decimal> for &'a Decimal {
+    type Output = Decimal;
+
+    fn sub(self, other: &Decimal) -> Decimal {
+        self + &-other.clone()
+    }
+}
+
+impl<'a> std::ops::Mul<&'a Decimal> for &'a Decimal {
+    type Output = Decimal;
+
+    fn mul(self, other: &Decimal) -> Decimal {
+        let mut product = vec![0u32; self.digits.len() + other.digits.len()];
+        for (i, &a) in self.digits.iter().enumerate() {
+            for (j, &b) in other.digits.iter().enumerate() {
+                product[i + j] += u32::from(a) * u32::from(b);
+            }
+        }
+        let mut digits = Vec::with_capacity(product.len());
+        let mut carry = 0;
+        for column in product {
+            let total = column + carry;
+            digits.push((total % 10) as u8);
+            carry = total / 10;
+        }
+        while carry > 0 {
+            digits.push((carry % 10) as u8);
+            carry /= 10;
+        }
+        Decimal::new(
+            self.negative != other.negative,
+            digits,
+            self.scale + other.scale,
+        )
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut digits: String = self
+            .digits
+            .iter()
+            .rev()
+            .map(|&digit| char::from(b'0' + digit))
+            .collect();
+        // At least one digit before the point.
+        if digits.len() <= self.scale {
+            digits = format!("{}{}", "0".repeat(self.scale + 1 - digits.len()), digits);
+        }
+        if self.negative {
+            f.write_str("-")?;
+        }
+        if self.scale == 0 {
+            f.write_str(&digits)
+        } else {
+            let point = digits.len() - self.scale;
+            write!(f, "{}.{}", &digits[..point], &digits[point..])
+        }
+    }
+}
+
+/// What happened between `text` and the `float` it was parsed into, unless it is stored
+/// exactly (or isn't a decimal, like `inf`).
+pub fn rounding_note(text: &str, float: &Float) -> Option<String> {
+    let written: Decimal = text.parse().ok()?;
+    let width = float.width();
+    match float.exact() {
+        None => Some(format!(
+            "`{}` is too large for {}, so it is stored as {}",
+            text, width, float
+        )),
+        Some(exact) if exact == written => None,
+        Some(_) if float.category() == FpCategory::Zero => Some(format!(
+            "`{}` is too small for {}, so it is stored as {}",
+            text, width, float
+        )),
+        Some(_) => Some(format!(
+            "`{}` has no exact {}, so the nearest one (exact, above) is stored",
+            text, width
+        )),
+    }
+}
+
+/// `a op b` worked through twice: on the floats the literals were stored as, and on the
+/// literals themselves, to show where rounding crept in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub width: Width,
+    pub operator: char,
+    /// The operands as written.
+    pub operands: (String, String),
+    /// The floats the operands were rounded to.
+    pub stored: (Float, Float),
+    /// The operation on the stored values, before rounding the result.
+    pub exact_result: Decimal,
+    /// What the program computes: the exact result rounded to the nearest float.
+    pub result: Float,
+    /// The operation on the operands as written, as a person would do it.
+    pub intended: Decimal,
+    /// What the intended result is stored as when written as a literal.
+    pub intended_float: Float,
+}
+
+impl Explanation {
+    /// Whether `result == intended` holds in a program.
+    pub fn is_equal(&self) -> bool {
+        self.result.value() == self.intended_float.value()
+    }
+
+    fn operation(&self) -> &'static str {
+        match self.operator {
+            '+' => "sum",
+            '-' => "difference",
+            _ => "product",
+        }
+    }
+}
+
+/// Splits `a+b`, `a - b`, `a*b` (but not the sign in `1e-3`) and works through it.
+pub fn explain(expression: &str, width: Width) -> Result<Explanation, String> {
+    let expression: String = expression.chars().filter(|c| !c.is_whitespace()).collect();
+    let bytes = expression.as_bytes();
+    let index = (1..bytes.len())
+        .find(|&i| b"+-*/".contains(&bytes[i]) && !b"eE+-*/".contains(&bytes[i - 1]))
+        .ok_or_else(|| format!("expected `a + b`, `a - b` or `a * b`, got `{}`", expression))?;
+    let operator = char::from(bytes[index]);
+    if operator == '/' {
+        return Err(String::from(
+            "a quotient usually has no exact decimal value to compare with; try +, - or *",
+        ));
+    }
+    let (a, b) = (&expression[..index], &expression[index + 1..]);
+
+    // The floats say whether an operand is in range, before its digits are spelled out.
+    let stored = (Float::parse(a, width)?, Float::parse(b, width)?);
+    for (text, float) in [(a, &stored.0), (b, &stored.1)] {
+        if float.category() == FpCategory::Infinite || float.category() == FpCategory::Nan {
+            return Err(format!("`{}` is not a finite {}", text, width));
+        }
+    }
+    let written = |text: &str, float: &Float| match text.parse::<Decimal>() {
+        Ok(decimal) if float.category() != FpCategory::Zero || decimal.is_zero() => Ok(decimal),
+        _ if float.category() == FpCategory::Zero => Err(format!(
+            "`{}` is too small for {} and is stored as 0",
+            text, width
+        )),
+        result => result,
+    };
+    let written = (written(a, &stored.0)?, written(b, &stored.1)?);
+    let exact = (
+        stored.0.exact().expect("finite"),
+        stored.1.exact().expect("finite"),
+    );
+
+    let apply = |a: &Decimal, b: &Decimal| match operator {
+        '+' => a + b,
+        '-' => a - b,
+        _ => a * b,
+    };
+    let exact_result = apply(&exact.0, &exact.1);
+    let intended = apply(&written.0, &written.1);
+
+    // Rounding the exact result once is what IEEE 754 arithmetic does.
+    let result = exact_result.to_float(width);
+    let intended_float = intended.to_float(width);
+
+    Ok(Explanation {
+        width,
+        operator,
+        operands: (a.to_string(), b.to_string()),
+        stored,
+        exact_result,
+        result,
+        intended,
+        intended_float,
+    })
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (a, b) = &self.operands;
+        let expression = format!("{} {} {}", a, self.operator, b);
+        let exact = |float: &Float| {
+            float
+                .exact()
+                .map_or_else(|| float.to_string(), |d| d.to_string())
+        };
+
+        writeln!(f, "{} in {}:", expression, self.width)?;
+        writeln!(f, "  {} is stored as {}", a, exact(&self.stored.0))?;
+        writeln!(f, "  {} is stored as {}", b, exact(&self.stored.1))?;
+        writeln!(
+            f,
+            "  their exact {} is {}",
+            self.operation(),
+            self.exact_result
+        )?;
+        let overflowed = |float: &Float| float.category() == FpCategory::Infinite;
+        if overflowed(&self.result) {
+            writeln!(
+                f,
+                "  which is too large for {}, so it rounds to {}",
+                self.width, self.result
+            )?;
+        } else {
+            writeln!(
+                f,
+                "  which rounds to {}, printed as {}",
+                exact(&self.result),
+                self.result
+            )?;
+        }
+        if overflowed(&self.intended_float) {
+            writeln!(
+                f,
+                "  while {} is too large for {}, so it is stored as {}",
+                self.intended, self.width, self.intended_float
+            )?;
+        } else {
+            writeln!(
+                f,
+                "  while {} is stored as {}, printed as {}",
+                self.intended,
+                exact(&self.intended_float),
+                self.intended_float
+            )?;
+        }
+
+        match (overflowed(&self.result), overflowed(&self.intended_float)) {
+            (true, true) => {
+                return write!(
+                f,
+                "So {} == {} in {} only because both overflow to {}: neither is the real value.",
+                expression, self.intended, self.width, self.result
+            )
+            }
+            (true, false) | (false, true) => {
+                return write!(
+                    f,
+                    "So {} != {} in {}: one side overflows to infinity and the other doesn't.",
+                    expression, self.intended, self.width
+                )
+            }
+            (false, false) => (),
+        }
+
+        if self.is_equal() {
+            return write!(
+                f,
+                "So {} == {} in {}: the rounding errors don't show in the result.",
+                expression, self.intended, self.width
+            );
+        }
+
+        let ulps = self
+            .result
+            .ulps_between(&self.intended_float)
+            .unwrap_or(u64::MAX);
+        writeln!(
+            f,
+            "So {} != {} in {}: they are {} ULP{} apart.",
+            expression,
+            self.intended,
+            self.width,
+            ulps,
+            if ulps == 1 { "" } else { "s" }
+        )?;
+
+        let (x, y) = (self.result.value(), self.intended_float.value());
+        let tolerance = self.width.epsilon() * x.abs().max(y.abs());
+        write!(
+            f,
+            "Compare with a tolerance instead of ==: (a - b).abs() <= {}::EPSILON * a.abs().max(b.abs()) {}.",
+            self.width,
+            if (x - y).abs() <= tolerance {
+                "treats them as equal"
+            } else {
+                "is still too strict here, so scale EPSILON up"
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::FpCategory;
+
+    use super::{explain, rounding_note, Decimal, Float, Width};
+
+    #[test]
+    fn splits_a_float_into_its_fields() {
+        let tenth = Float::f64(0.1);
+        assert_eq!(
+            tenth.fields(),
+            "0 01111111011 1001100110011001100110011001100110011001100110011010"
+        );
+        assert_eq!(tenth.exponent(), Some(-4));
+        assert_eq!(
+            tenth.exact().unwrap().to_string(),
+            "0.1000000000000000055511151231257827021181583404541015625"
+        );
+
+        let minus_two = Float::f32(-2.0);
+        assert_eq!(minus_two.fields(), "1 10000000 00000000000000000000000");
+        assert_eq!(minus_two.significand().unwrap().to_string(), "1");
+        assert_eq!(minus_two.exact().unwrap().to_string(), "-2");
+    }
+
+    #[test]
+    fn classifies_every_kind_of_float() {
+        let category = |value: f64| Float::f64(value).category();
+        assert_eq!(category(1.0), FpCategory::Normal);
+        assert_eq!(category(5e-324), FpCategory::Subnormal);
+        assert_eq!(category(-0.0), FpCategory::Zero);
+        assert_eq!(category(f64::NEG_INFINITY), FpCategory::Infinite);
+        assert_eq!(category(f64::NAN), FpCategory::Nan);
+
+        assert_eq!(Float::f64(5e-324).exponent(), Some(-1022));
+        // 2^-149, the smallest f32: 45 zeros after the point, then 105 more digits.
+        let smallest = Float::f32(f32::from_bits(1)).exact().unwrap().to_string();
+        assert!(smallest.starts_with(&format!("0.{}1401298464", "0".repeat(44))));
+        assert!(smallest.ends_with("836212158203125"));
+        assert_eq!(smallest.len(), 2 + 149);
+    }
+
+    #[test]
+    fn neighbours_are_one_ulp_away() {
+        let one = Float::f64(1.0);
+        assert_eq!(one.next_up().value() - 1.0, f64::EPSILON);
+        assert_eq!(1.0 - one.next_down().value(), f64::EPSILON / 2.0);
+        assert_eq!(one.ulps_between(&one.next_up()), Some(1));
+
+        assert_eq!(Float::f64(0.0).next_up().value(), 5e-324);
+        assert_eq!(Float::f64(0.0).next_down().value(), -5e-324);
+        assert_eq!(Float::f64(-5e-324).next_up().category(), FpCategory::Zero);
+        assert_eq!(Float::f64(f64::MAX).next_up().value(), f64::INFINITY);
+        assert_eq!(Float::f64(f64::INFINITY).next_down().value(), f64::MAX);
+        assert_eq!(Float::f64(-0.0).ulps_between(&Float::f64(5e-324)), Some(1));
+        assert_eq!(
+            Float::f32(1.0).next_up().value(),
+            1.0 + f64::from(f32::EPSILON)
+        );
+    }
+
+    #[test]
+    fn decimals_are_exact() {
+        let parse = |text: &str| text.parse::<Decimal>().unwrap();
+        assert_eq!((&parse("0.1") + &parse("0.2")).to_string(), "0.3");
+        assert_eq!((&parse("1") - &parse("2.5")).to_string(), "-1.5");
+        assert_eq!((&parse("-1.5e2") * &parse("0.02")).to_string(), "-3");
+        assert_eq!(parse("12e-5").to_string(), "0.00012");
+        assert!("1.2.3".parse::<Decimal>().is_err());
+        assert!("inf".parse::<Decimal>().is_err());
+    }
+
+    #[test]
+    fn explains_why_a_tenth_plus_a_fifth_is_not_three_tenths() {
+        let explanation = explain("0.1+0.2", Width::F64).unwrap();
+        assert!(!explanation.is_equal());
+        assert_eq!(explanation.result.to_string(), "0.30000000000000004");
+        assert_eq!(explanation.intended.to_string(), "0.3");
+        assert_eq!(
+            explanation.exact_result.to_string(),
+            "0.3000000000000000166533453693773481063544750213623046875"
+        );
+        assert!(explanation.to_string().contains("they are 1 ULP apart"));
+
+        // In f32 the errors happen to round the same way.
+        assert!(explain("0.1 + 0.2", Width::F32).unwrap().is_equal());
+        assert!(explain("0.5+0.25", Width::F64).unwrap().is_equal());
+        assert!(explain("1e-3-1", Width::F64).is_ok());
+        assert!(explain("1/3", Width::F64).is_err());
+        assert!(explain("0.1", Width::F64).is_err());
+    }
+
+    #[test]
+    fn explains_overflow_instead_of_calling_it_exact() {
+        let explanation = explain("1e308*10", Width::F64).unwrap();
+        assert!(explanation.is_equal());
+        let text = explanation.to_string();
+        assert!(text.contains("which is too large for f64, so it rounds to inf"));
+        assert!(text.contains("only because both overflow to inf"));
+        assert!(!text.contains("rounding errors don't show"));
+    }
+
+    #[test]
+    fn rejects_operands_out_of_range_before_spelling_them_out() {
+        assert_eq!(
+            explain("1e999999999+1", Width::F64).unwrap_err(),
+            "`1e999999999` is not a finite f64"
+        );
+        assert_eq!(
+            explain("1e-99999999+1", Width::F64).unwrap_err(),
+            "`1e-99999999` is too small for f64 and is stored as 0"
+        );
+        assert!(explain("1e40+1", Width::F32).is_err());
+        assert!("1e99999".parse::<Decimal>().is_err());
+        assert!("0e5000".parse::<Decimal>().unwrap().is_zero());
+        assert_eq!(
+            explain("1e-2147483648+1", Width::F64).unwrap_err(),
+            "`1e-2147483648` is too small for f64 and is stored as 0"
+        );
+        assert_eq!(
+            "1e-2147483648".parse::<Decimal>(),
+            Err(String::from(
+                "the exponent of `1e-2147483648` is out of range"
+            ))
+        );
+    }
+
+    #[test]
+    fn notes_when_the_input_was_rounded() {
+        let note = |text: &str, width| rounding_note(text, &Float::parse(text, width).unwrap());
+        assert_eq!(
+            note("16777217", Width::F32).unwrap(),
+            "`16777217` has no exact f32, so the nearest one (exact, above) is stored"
+        );
+        assert_eq!(note("16777217", Width::F64), None);
+        assert_eq!(note("0.5", Width::F32), None);
+        assert!(note("1e40", Width::F32).unwrap().contains("too large"));
+        assert!(note("1e-400", Width::F64).unwrap().contains("too small"));
+        assert_eq!(note("inf", Width::F64), None);
+        // Too far out to spell out, so there is nothing to compare it with.
+        assert_eq!(note("1e-2147483648", Width::F64), None);
+    }
+}
@@ -0,0 +1,21 @@
+//! The chapter 2 guessing game, pulled apart from stdin/stdout so it can be tested and extended.
+
+pub mod blitz;
+pub mod bulls_and_cows;
+pub mod daily;
+pub mod difficulty;
+pub mod engine;
+pub mod guess;
+pub mod hints;
+pub mod leaderboard;
+pub mod reverse;
+pub mod solver;
+pub mod transcript;
+
+pub use bulls_and_cows::BullsAndCows;
+pub use difficulty::{Difficulty, GameConfig};
+pub use engine::{GameResult, GuessingGame, Outcome};
+pub use guess::{Guess, GuessError};
+pub use hints::Hint;
+pub use leaderboard::{GameRecord, Leaderboard};
+pub use transcript::Transcript;
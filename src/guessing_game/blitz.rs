@@ -0,0 +1,234 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Where a [`Timer`] gets the time from, so tests can script it.
+pub trait Clock {
+    /// Time since the game started.
+    fn elapsed(&mut self) -> Duration;
+}
+
+/// The real clock, started when the game is.
+pub struct Stopwatch(Instant);
+
+impl Stopwatch {
+    pub fn start() -> Stopwatch {
+        Stopwatch(Instant::now())
+    }
+}
+
+impl Clock for Stopwatch {
+    fn elapsed(&mut self) -> Duration {
+        self.0.elapsed()
+    }
+}
+
+/// How much time a blitz game allows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeLimit {
+    /// The whole game has to fit in this budget.
+    Total(Duration),
+    /// Each guess has to come within this long of the previous one (or of the start).
+    PerGuess(Duration),
+}
+
+/// Parses `90`, `90s`, `1.5s`, `2m` or `500ms`; a bare number is seconds.
+pub fn parse_duration(text: &str) -> Result<Duration, String> {
+    let (number, unit) = match text.find(|c: char| !(c.is_ascii_digit() || c == '.')) {
+        Some(index) => text.split_at(index),
+        None => (text, "s"),
+    };
+    let scale = match unit {
+        "ms" => 0.001,
+        "s" => 1.0,
+        "m" => 60.0,
+        _ => {
+            return Err(format!(
+                "`{}` is not a duration like 90s, 2m or 500ms",
+                text
+            ))
+        }
+    };
+
+    match number.parse::<f64>() {
+        Ok(value) if value > 0.0 => Duration::try_from_secs_f64(value * scale)
+            .map_err(|_| format!("`{}` is not a duration like 90s, 2m or 500ms", text)),
+        _ => Err(format!(
+            "`{}` is not a duration like 90s, 2m or 500ms",
+            text
+        )),
+    }
+}
+
+/// Times every guess of a game and enforces its [`TimeLimit`], if any.
+///
+/// `read_line` can't be interrupted, so the limit is checked when an answer arrives: a player
+/// who ran out of time finds out as soon as they press Enter.
+pub struct Timer {
+    limit: Option<TimeLimit>,
+    clock: Box<dyn Clock>,
+    /// When the latest line arrived.
+    now: Duration,
+    /// When the previous guess was counted.
+    last_guess: Duration,
+    think_times: Vec<Duration>,
+}
+
+impl Timer {
+    pub fn new(limit: Option<TimeLimit>, clock: Box<dyn Clock>) -> Timer {
+        Timer {
+            limit,
+            clock,
+            now: Duration::ZERO,
+            last_guess: Duration::ZERO,
+            think_times: Vec::new(),
+        }
+    }
+
+    pub fn limit(&self) -> Option<TimeLimit> {
+        self.limit
+    }
+
+    /// Called when a line arrives. Returns why the game is over if it came too late.
+    pub fn check(&mut self) -> Option<String> {
+        self.now = self.clock.elapsed();
+
+        match self.limit? {
+            TimeLimit::Total(budget) if self.now > budget => Some(format!(
+                "Time's up! The game had to be over within {}.",
+                seconds(budget)
+            )),
+            TimeLimit::PerGuess(limit) if self.now - self.last_guess > limit => Some(format!(
+                "Too slow! That guess took {}; each guess has to come within {}.",
+                seconds(self.now - self.last_guess),
+                seconds(limit)
+            )),
+            _ => None,
+        }
+    }
+
+    /// Records the line that just arrived as a guess. Time spent on rejected input counts
+    /// towards the next guess.
+    pub fn lap(&mut self) {
+        self.think_times.push(self.now - self.last_guess);
+        self.last_guess = self.now;
+    }
+
+    /// What's left of a total budget, as of the latest line.
+    pub fn remaining(&self) -> Option<Duration> {
+        match self.limit? {
+            TimeLimit::Total(budget) => Some(budget.saturating_sub(self.now)),
+            TimeLimit::PerGuess(_) => None,
+        }
+    }
+
+    pub fn stats(&self) -> LatencyStats {
+        let slowest = self
+            .think_times
+            .iter()
+            .enumerate()
+            .max_by_key(|&(_, time)| time)
+            .map(|(index, &time)| (index as u32 + 1, time));
+        let average = match self.think_times.len() {
+            0 => Duration::ZERO,
+            n => self.think_times.iter().sum::<Duration>() / n as u32,
+        };
+
+        LatencyStats {
+            total: self.now,
+            slowest,
+            average,
+        }
+    }
+}
+
+/// How long a game and its guesses took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatencyStats {
+    pub total: Duration,
+    /// Which guess took longest (counting from 1) and how long it took.
+    pub slowest: Option<(u32, Duration)>,
+    pub average: Duration,
+}
+
+impl fmt::Display for LatencyStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Total time: {}", seconds(self.total))?;
+        if let Some((guess, time)) = self.slowest {
+            write!(f, ", slowest guess: #{} ({})", guess, seconds(time))?;
+        }
+        write!(f, ", average think time: {}", seconds(self.average))
+    }
+}
+
+fn seconds(duration: Duration) -> String {
+    format!("{:.1}s", duration.as_secs_f64())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{parse_duration, Clock, TimeLimit, Timer};
+
+    /// Hands out the given times, one per call.
+    pub struct Scripted(pub Vec<u64>);
+
+    impl Clock for Scripted {
+        fn elapsed(&mut self) -> Duration {
+            Duration::from_secs(self.0.remove(0))
+        }
+    }
+
+    #[test]
+    fn parses_durations_with_units() {
+        assert_eq!(parse_duration("90"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert!(parse_duration("0").is_err());
+        assert!(parse_duration("soon").is_err());
+        assert!(parse_duration("5h").is_err());
+        assert!(parse_duration("99999999999999999999999").is_err());
+        assert!(parse_duration("99999999999999999999999m").is_err());
+    }
+
+    #[test]
+    fn collects_think_times_per_guess() {
+        let mut timer = Timer::new(None, Box::new(Scripted(vec![2, 9, 10])));
+        for _ in 0..3 {
+            assert_eq!(timer.check(), None);
+            timer.lap();
+        }
+
+        let stats = timer.stats();
+        assert_eq!(stats.total, Duration::from_secs(10));
+        assert_eq!(stats.slowest, Some((2, Duration::from_secs(7))));
+        assert_eq!(
+            stats.to_string(),
+            "Total time: 10.0s, slowest guess: #2 (7.0s), average think time: 3.3s"
+        );
+    }
+
+    #[test]
+    fn a_total_budget_runs_out() {
+        let limit = TimeLimit::Total(Duration::from_secs(30));
+        let mut timer = Timer::new(Some(limit), Box::new(Scripted(vec![20, 31])));
+
+        assert_eq!(timer.check(), None);
+        assert_eq!(timer.remaining(), Some(Duration::from_secs(10)));
+        timer.lap();
+        assert!(timer.check().unwrap().starts_with("Time's up!"));
+    }
+
+    #[test]
+    fn the_countdown_restarts_with_every_guess() {
+        let limit = TimeLimit::PerGuess(Duration::from_secs(10));
+        let mut timer = Timer::new(Some(limit), Box::new(Scripted(vec![8, 16, 27])));
+
+        assert_eq!(timer.check(), None);
+        timer.lap();
+        assert_eq!(timer.check(), None);
+        timer.lap();
+        assert!(timer.check().unwrap().starts_with("Too slow!"));
+    }
+}
@@ -0,0 +1,532 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::time::Duration;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use super::engine::{play_rounds, Attempts, GameResult, Rounds};
+use super::guess::{self, GuessError};
+
+/// The longest code allowed. Every digit is different.
+pub const MAX_DIGITS: usize = 9;
+
+/// [`Knuth`] scores every code against every candidate, which gets too slow past this.
+pub const MAX_SOLVER_DIGITS: usize = 4;
+
+/// A code of distinct decimal digits. Leading zeros are allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Code {
+    digits: [u8; MAX_DIGITS],
+    len: usize,
+    /// One bit per digit used, so counting shared digits is a single `&`.
+    mask: u16,
+}
+
+impl Code {
+    /// `None` unless `digits` are 1 to [`MAX_DIGITS`] different digits from 0 to 9.
+    pub fn from_digits(digits: &[u8]) -> Option<Code> {
+        if digits.is_empty() || digits.len() > MAX_DIGITS {
+            return None;
+        }
+
+        let mut code = Code {
+            digits: [0; MAX_DIGITS],
+            len: digits.len(),
+            mask: 0,
+        };
+        for (slot, &digit) in code.digits.iter_mut().zip(digits) {
+            if digit > 9 || code.mask & (1 << digit) != 0 {
+                return None;
+            }
+            *slot = digit;
+            code.mask |= 1 << digit;
+        }
+
+        Some(code)
+    }
+
+    /// Reads a guess the way [`Guess::parse`](super::Guess::parse) does, then checks the
+    /// code's own rules: exactly `length` digits, none of them repeated.
+    pub fn parse(input: &str, length: usize) -> Result<Code, CodeError> {
+        let input = input.trim();
+
+        if input.is_empty() {
+            return Err(GuessError::Empty.into());
+        } else if guess::is_negative_number(input) {
+            return Err(GuessError::Negative(input.to_string()).into());
+        } else if !input.chars().all(|c| c.is_ascii_digit()) {
+            return Err(GuessError::NotANumber(input.to_string()).into());
+        }
+
+        if input.len() != length {
+            return Err(CodeError::WrongLength {
+                input: input.to_string(),
+                length,
+            });
+        }
+
+        let digits: Vec<u8> = input.bytes().map(|b| b - b'0').collect();
+        Code::from_digits(&digits).ok_or_else(|| {
+            let digit = input
+                .chars()
+                .find(|&c| input.matches(c).count() > 1)
+                .unwrap_or('?');
+            CodeError::RepeatedDigit {
+                input: input.to_string(),
+                digit,
+            }
+        })
+    }
+
+    pub fn random<R: Rng + ?Sized>(rng: &mut R, length: usize) -> Code {
+        let mut digits: Vec<u8> = (0..=9).collect();
+        digits.shuffle(rng);
+        Code::from_digits(&digits[..length]).expect("shuffled digits are distinct")
+    }
+
+    /// Every code of `length` digits, in increasing order.
+    pub fn all(length: usize) -> Vec<Code> {
+        fn extend(prefix: &mut Vec<u8>, length: usize, codes: &mut Vec<Code>) {
+            if prefix.len() == length {
+                codes.extend(Code::from_digits(prefix));
+                return;
+            }
+            for digit in 0..=9 {
+                if !prefix.contains(&digit) {
+                    prefix.push(digit);
+                    extend(prefix, length, codes);
+                    prefix.pop();
+                }
+            }
+        }
+
+        let mut codes = Vec::new();
+        extend(&mut Vec::with_capacity(length), length, &mut codes);
+        codes
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn digits(&self) -> &[u8] {
+        &self.digits[..self.len]
+    }
+
+    /// How `guess` scores against this code.
+    pub fn score(&self, guess: &Code) -> Score {
+        let bulls = self
+            .digits()
+            .iter()
+            .zip(guess.digits())
+            .filter(|(a, b)| a == b)
+            .count();
+        let shared = (self.mask & guess.mask).count_ones() as usize;
+
+        Score {
+            bulls,
+            cows: shared - bulls,
+        }
+    }
+}
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for digit in self.digits() {
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+/// Why a line couldn't be read as a code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodeError {
+    /// Not a number at all, reported as it is for the number guessing game.
+    Guess(GuessError),
+    /// A code with the wrong number of digits.
+    WrongLength { input: String, length: usize },
+    /// A code that uses a digit twice.
+    RepeatedDigit { input: String, digit: char },
+}
+
+impl From<GuessError> for CodeError {
+    fn from(error: GuessError) -> CodeError {
+        CodeError::Guess(error)
+    }
+}
+
+impl fmt::Display for CodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CodeError::Guess(error) => error.fmt(f),
+            CodeError::WrongLength { input, length } => write!(
+                f,
+                "`{}` has {} digits; the code has {}.",
+                input,
+                input.chars().count(),
+                length
+            ),
+            CodeError::RepeatedDigit { input, digit } => write!(
+                f,
+                "`{}` uses {} more than once; every digit of the code is different.",
+                input, digit
+            ),
+        }
+    }
+}
+
+impl Error for CodeError {}
+
+/// Bulls are right digits in the right place; cows are right digits in the wrong place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Score {
+    pub bulls: usize,
+    pub cows: usize,
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let plural = |n: usize| if n == 1 { "" } else { "s" };
+        write!(
+            f,
+            "{} bull{}, {} cow{}",
+            self.bulls,
+            plural(self.bulls),
+            self.cows,
+            plural(self.cows)
+        )
+    }
+}
+
+/// Guess the code; every guess is scored in bulls and cows.
+pub struct BullsAndCows {
+    secret: Code,
+    attempts: Attempts,
+    history: Vec<(Code, Score)>,
+}
+
+impl BullsAndCows {
+    pub fn new<R: Rng + ?Sized>(
+        rng: &mut R,
+        length: usize,
+        max_attempts: Option<u32>,
+    ) -> BullsAndCows {
+        BullsAndCows::with_secret(Code::random(rng, length), max_attempts)
+    }
+
+    pub fn with_secret(secret: Code, max_attempts: Option<u32>) -> BullsAndCows {
+        BullsAndCows {
+            secret,
+            attempts: Attempts::new(max_attempts),
+            history: Vec::new(),
+        }
+    }
+
+    pub fn secret(&self) -> Code {
+        self.secret
+    }
+
+    /// How many digits the code has.
+    pub fn length(&self) -> usize {
+        self.secret.len()
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts.used()
+    }
+
+    pub fn remaining_attempts(&self) -> Option<u32> {
+        self.attempts.remaining()
+    }
+
+    /// Every guess so far with its score.
+    pub fn history(&self) -> &[(Code, Score)] {
+        &self.history
+    }
+
+    /// Scores one guess. Every call counts as an attempt.
+    pub fn guess(&mut self, guess: Code) -> Score {
+        self.attempts.count();
+        let score = self.secret.score(&guess);
+        self.history.push((guess, score));
+        score
+    }
+
+    /// Reads guesses line by line from `input` until the code is cracked or the input ends.
+    pub fn play<R: BufRead, W: Write>(
+        &mut self,
+        input: R,
+        mut output: W,
+    ) -> io::Result<GameResult> {
+        let result = play_rounds(self, input, &mut output, &mut |_, _| ())?;
+        if !matches!(result, GameResult::Abandoned { .. }) {
+            writeln!(output, "The code was: {}", self.secret)?;
+        }
+        Ok(result)
+    }
+
+    /// Every guess with its score, then the code length, attempts and time taken.
+    pub fn render_summary(&self, out: &mut dyn Write, duration: Duration) -> io::Result<()> {
+        writeln!(out, "Guesses")?;
+        for (number, (code, score)) in self.history.iter().enumerate() {
+            writeln!(out, "  {:>3}  {}  {}", number + 1, code, score)?;
+        }
+
+        writeln!(out)?;
+        writeln!(
+            out,
+            "  {:<12} {:>4} attempts  {:>7.1}s",
+            format!("{}-digit code", self.length()),
+            self.attempts(),
+            duration.as_secs_f64()
+        )
+    }
+}
+
+impl Rounds for BullsAndCows {
+    fn respond(&mut self, line: &str, output: &mut dyn Write) -> io::Result<Option<GameResult>> {
+        let guess = match Code::parse(line, self.length()) {
+            Ok(guess) => guess,
+            Err(error) => {
+                writeln!(output, "{}", error)?;
+                return Ok(None);
+            }
+        };
+
+        let score = self.guess(guess);
+        if score.bulls == self.length() {
+            writeln!(
+                output,
+                "You cracked the code after {} attempts!",
+                self.attempts()
+            )?;
+            return Ok(Some(GameResult::Won {
+                attempts: self.attempts(),
+            }));
+        }
+
+        writeln!(output, "{}", score)?;
+
+        if self.attempts.report(output)? {
+            return Ok(Some(GameResult::CodeLost {
+                attempts: self.attempts(),
+                code: self.secret,
+            }));
+        }
+
+        Ok(None)
+    }
+
+    fn attempts(&self) -> u32 {
+        self.attempts.used()
+    }
+}
+
+/// Knuth's minimax strategy: guess the code that leaves the fewest candidates in the worst case.
+pub struct Knuth {
+    all: Vec<Code>,
+    /// The codes that agree with every score so far, in increasing order.
+    candidates: Vec<Code>,
+}
+
+impl Knuth {
+    pub fn new(length: usize) -> Knuth {
+        let all = Code::all(length);
+        Knuth {
+            candidates: all.clone(),
+            all,
+        }
+    }
+
+    pub fn candidates(&self) -> &[Code] {
+        &self.candidates
+    }
+
+    pub fn next_guess(&self) -> Code {
+        // Before anything is known every code is as good as any other: relabelling digits and
+        // positions turns one into another. Skipping the search saves the most expensive round.
+        if self.candidates.len() <= 1 || self.candidates.len() == self.all.len() {
+            return self.candidates.first().copied().unwrap_or(self.all[0]);
+        }
+
+        let length = self.all[0].len();
+        let mut best = None;
+        for guess in &self.all {
+            let mut buckets = [0usize; (MAX_DIGITS + 1) * (MAX_DIGITS + 1)];
+            for candidate in &self.candidates {
+                let score = candidate.score(guess);
+                buckets[score.bulls * (length + 1) + score.cows] += 1;
+            }
+            let worst = buckets.iter().copied().max().unwrap_or(0);
+
+            // Ties go to a guess that could still be the code, then to the smallest code.
+            let could_win = self.candidates.binary_search(guess).is_ok();
+            let key = (worst, !could_win);
+            if best.is_none_or(|(best_key, _)| key < best_key) {
+                best = Some((key, *guess));
+            }
+        }
+
+        best.map(|(_, guess)| guess).unwrap_or(self.candidates[0])
+    }
+
+    /// Drops every candidate that wouldn't have given `score` for `guess`.
+    pub fn learn(&mut self, guess: &Code, score: Score) {
+        self.candidates
+            .retain(|candidate| candidate.score(guess) == score);
+    }
+}
+
+/// Lets `knuth` play `game` to the end.
+pub fn solve(game: &mut BullsAndCows, knuth: &mut Knuth) -> GameResult {
+    loop {
+        let guess = knuth.next_guess();
+        let score = game.guess(guess);
+
+        if score.bulls == game.length() {
+            return GameResult::Won {
+                attempts: game.attempts(),
+            };
+        }
+        if game.remaining_attempts() == Some(0) {
+            return GameResult::CodeLost {
+                attempts: game.attempts(),
+                code: game.secret(),
+            };
+        }
+
+        knuth.learn(&guess, score);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::{solve, BullsAndCows, Code, CodeError, Knuth, Score};
+    use crate::guessing_game::{GameResult, GuessError};
+
+    fn code(digits: &str) -> Code {
+        Code::parse(digits, digits.len()).unwrap()
+    }
+
+    #[test]
+    fn scores_bulls_and_cows() {
+        assert_eq!(
+            code("1234").score(&code("1243")),
+            Score { bulls: 2, cows: 2 }
+        );
+        assert_eq!(
+            code("1234").score(&code("5678")),
+            Score { bulls: 0, cows: 0 }
+        );
+        assert_eq!(
+            code("0123").score(&code("3012")),
+            Score { bulls: 0, cows: 4 }
+        );
+    }
+
+    #[test]
+    fn rejects_codes_that_break_the_rules() {
+        assert_eq!(
+            Code::parse("\n", 4),
+            Err(CodeError::Guess(GuessError::Empty))
+        );
+        assert_eq!(
+            Code::parse("12a4", 4),
+            Err(CodeError::Guess(GuessError::NotANumber(String::from(
+                "12a4"
+            ))))
+        );
+        assert_eq!(
+            Code::parse("-123", 4),
+            Err(CodeError::Guess(GuessError::Negative(String::from("-123"))))
+        );
+        assert_eq!(
+            Code::parse("123", 4),
+            Err(CodeError::WrongLength {
+                input: String::from("123"),
+                length: 4
+            })
+        );
+        assert_eq!(
+            Code::parse("1231", 4),
+            Err(CodeError::RepeatedDigit {
+                input: String::from("1231"),
+                digit: '1'
+            })
+        );
+    }
+
+    #[test]
+    fn lists_every_code_once() {
+        let codes = Code::all(3);
+        assert_eq!(codes.len(), 10 * 9 * 8);
+        assert!(codes.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn plays_through_the_shared_loop() {
+        let mut game = BullsAndCows::with_secret(code("0427"), Some(5));
+        let mut output = Vec::new();
+
+        let result = game
+            .play(Cursor::new("1234\n11\n0472\n0427\n"), &mut output)
+            .unwrap();
+
+        assert_eq!(result, GameResult::Won { attempts: 3 });
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("0 bulls, 2 cows\nAttempts left: 4\n"));
+        assert!(output.contains("`11` has 2 digits; the code has 4."));
+        assert!(output.ends_with("The code was: 0427\n"));
+    }
+
+    #[test]
+    fn a_lost_game_keeps_the_code_s_leading_zero() {
+        let mut game = BullsAndCows::with_secret(code("0427"), Some(1));
+        let mut output = Vec::new();
+
+        let result = game.play(Cursor::new("1234\n"), &mut output).unwrap();
+
+        assert_eq!(
+            result,
+            GameResult::CodeLost {
+                attempts: 1,
+                code: code("0427")
+            }
+        );
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .ends_with("The code was: 0427\n"));
+    }
+
+    #[test]
+    fn knuth_cracks_three_digit_codes_in_at_most_six_guesses() {
+        // Every code passes, but checking all 720 takes a while in a debug build.
+        for secret in Code::all(3).into_iter().step_by(9) {
+            let mut game = BullsAndCows::with_secret(secret, None);
+            match solve(&mut game, &mut Knuth::new(3)) {
+                GameResult::Won { attempts } => {
+                    assert!(attempts <= 6, "{} took {}", secret, attempts)
+                }
+                other => panic!("Knuth did not win: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn knuth_cracks_a_four_digit_code() {
+        let mut game = BullsAndCows::with_secret(code("9382"), None);
+        assert!(matches!(
+            solve(&mut game, &mut Knuth::new(4)),
+            GameResult::Won { attempts } if attempts <= 7
+        ));
+    }
+}
@@ -0,0 +1,253 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::difficulty::{Difficulty, GameConfig};
+use super::engine::{split_mix, GameResult, GuessingGame, Outcome};
+
+/// A calendar date (proleptic Gregorian), written `YYYY-MM-DD`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    /// Today in UTC, so everyone gets the same puzzle whatever their time zone.
+    pub fn today() -> Date {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|since| since.as_secs())
+            .unwrap_or(0);
+        Date::from_days((seconds / 86_400) as i64)
+    }
+
+    /// Days since 1970-01-01.
+    pub fn days(&self) -> i64 {
+        // Howard Hinnant's `days_from_civil`: shift the year to start in March so the leap day
+        // is the last day of the year, then count whole 400-year eras.
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// The inverse of [`days`](Date::days).
+    pub fn from_days(days: i64) -> Date {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Date {
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Date, String> {
+        let invalid = || format!("`{}` is not a date like 2024-02-29", s);
+
+        let mut parts = s.splitn(3, '-');
+        let mut next = || parts.next().ok_or_else(invalid);
+        let (year, month, day) = (next()?, next()?, next()?);
+
+        let date = Date {
+            year: year.parse().map_err(|_| invalid())?,
+            month: month.parse().map_err(|_| invalid())?,
+            day: day.parse().map_err(|_| invalid())?,
+        };
+        if !(1..=12).contains(&date.month)
+            || !(1..=days_in_month(date.year, date.month)).contains(&date.day)
+        {
+            return Err(invalid());
+        }
+
+        Ok(date)
+    }
+}
+
+/// The upper ends a daily range is picked from when the range varies.
+const DAILY_MAXIMUMS: &[u32] = &[50, 100, 200, 500, 1000];
+
+/// The puzzle of the day: the same secret for everyone who plays on the same date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Daily {
+    pub date: Date,
+    /// Whether the range also changes from day to day instead of always being 1-100.
+    pub vary_range: bool,
+}
+
+impl Daily {
+    /// Seeds the secret number through [`GuessingGame::with_seed`]. This has to stay the same
+    /// across releases (and platforms), so it doesn't rely on `Hash`, whose output may change.
+    pub fn seed(&self) -> u64 {
+        split_mix(self.date.days() as u64)
+    }
+
+    pub fn config(&self, max_attempts: Option<u32>) -> GameConfig {
+        let difficulty = if self.vary_range {
+            let max = DAILY_MAXIMUMS[(self.seed() >> 32) as usize % DAILY_MAXIMUMS.len()];
+            Difficulty::from_range(1, max)
+        } else {
+            Difficulty::Normal
+        };
+
+        GameConfig {
+            difficulty,
+            max_attempts,
+        }
+    }
+}
+
+/// A result to paste in the team chat: the attempts and the higher/lower answers, but not the
+/// guesses themselves, so it spoils nothing for people who haven't played yet.
+pub fn share(daily: &Daily, game: &GuessingGame, result: GameResult) -> String {
+    let range = game.range();
+    let score = match (result, game.remaining_attempts()) {
+        (GameResult::Won { attempts }, Some(left)) => format!("{}/{}", attempts, attempts + left),
+        (GameResult::Won { attempts }, None) => attempts.to_string(),
+        (_, Some(_)) => format!("X/{}", game.attempts()),
+        (_, None) => String::from("X"),
+    };
+    let pattern: String = game
+        .history()
+        .iter()
+        .map(|(_, outcome)| match outcome {
+            Outcome::TooSmall => "⬆️",
+            Outcome::TooBig => "⬇️",
+            Outcome::Correct { .. } => "✅",
+        })
+        .collect();
+
+    format!(
+        "Guess the number, daily {} ({}-{}): {}\n{}",
+        daily.date,
+        range.start(),
+        range.end(),
+        score,
+        pattern
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{share, Daily, Date};
+    use crate::guessing_game::{GameConfig, GameResult, GuessingGame};
+
+    fn date(text: &str) -> Date {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn converts_between_dates_and_days_since_the_epoch() {
+        assert_eq!(date("1970-01-01").days(), 0);
+        assert_eq!(date("2000-03-01").days(), 11_017);
+        assert_eq!(date("1969-12-31").days(), -1);
+
+        for days in [-800_000, -1, 0, 59, 11_016, 20_000, 2_932_896] {
+            assert_eq!(Date::from_days(days).days(), days);
+        }
+        assert_eq!(Date::from_days(19_782).to_string(), "2024-02-29");
+    }
+
+    #[test]
+    fn rejects_dates_that_do_not_exist() {
+        assert!("2023-02-29".parse::<Date>().is_err());
+        assert!("2024-13-01".parse::<Date>().is_err());
+        assert!("yesterday".parse::<Date>().is_err());
+        assert!("2024-02-29".parse::<Date>().is_ok());
+    }
+
+    #[test]
+    fn the_same_day_gives_everyone_the_same_secret() {
+        let secret = |day: &str| {
+            let daily = Daily {
+                date: date(day),
+                vary_range: true,
+            };
+            GuessingGame::with_seed(daily.seed(), &daily.config(None)).secret_number()
+        };
+
+        assert_eq!(secret("2024-05-01"), secret("2024-05-01"));
+        let secrets: Vec<u32> = (1..=9)
+            .map(|d| secret(&format!("2024-05-0{}", d)))
+            .collect();
+        assert!(secrets.windows(2).any(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn a_day_s_secret_never_changes() {
+        // Pinned: a new release (or a new `rand`) must not change a past day's answer.
+        let daily = |day: &str, vary_range| Daily {
+            date: date(day),
+            vary_range,
+        };
+        let secret = |daily: Daily| {
+            GuessingGame::with_seed(daily.seed(), &daily.config(None)).secret_number()
+        };
+        assert_eq!(secret(daily("2024-05-01", false)), 17);
+        assert_eq!(secret(daily("2024-02-29", false)), 15);
+        let varied = daily("2024-05-01", true);
+        assert_eq!(varied.config(None).difficulty.range(), 1..=200);
+        assert_eq!(secret(varied), 17);
+    }
+
+    #[test]
+    fn shared_results_show_directions_but_not_guesses() {
+        let daily = Daily {
+            date: date("2024-05-01"),
+            vary_range: false,
+        };
+        let config = GameConfig {
+            max_attempts: Some(6),
+            ..daily.config(None)
+        };
+        let mut game = GuessingGame::with_secret(42, &config);
+        game.guess(50);
+        game.guess(25);
+        game.guess(42);
+
+        let shared = share(&daily, &game, GameResult::Won { attempts: 3 });
+        assert_eq!(
+            shared,
+            "Guess the number, daily 2024-05-01 (1-100): 3/6\n⬇️⬆️✅"
+        );
+        assert!(!shared.contains("42"));
+    }
+}
@@ -0,0 +1,104 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// How wide the range the secret number is drawn from is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Difficulty {
+    /// 1–10
+    Easy,
+    /// 1–100, the range from the book.
+    #[default]
+    Normal,
+    /// 1–10000
+    Hard,
+    Custom {
+        min: u32,
+        max: u32,
+    },
+}
+
+impl Difficulty {
+    /// The preset with exactly this range, or a custom difficulty.
+    pub fn from_range(min: u32, max: u32) -> Difficulty {
+        [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard]
+            .iter()
+            .copied()
+            .find(|preset| preset.range() == (min..=max))
+            .unwrap_or(Difficulty::Custom { min, max })
+    }
+
+    pub fn range(&self) -> RangeInclusive<u32> {
+        match *self {
+            Difficulty::Easy => 1..=10,
+            Difficulty::Normal => 1..=100,
+            Difficulty::Hard => 1..=10_000,
+            Difficulty::Custom { min, max } => min..=max,
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Difficulty::Easy => write!(f, "easy"),
+            Difficulty::Normal => write!(f, "normal"),
+            Difficulty::Hard => write!(f, "hard"),
+            Difficulty::Custom { min, max } => write!(f, "{}-{}", min, max),
+        }
+    }
+}
+
+/// Parses `easy`, `normal`, `hard`, or a custom `MIN-MAX` range such as `1-50`.
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Difficulty, String> {
+        match s {
+            "easy" => Ok(Difficulty::Easy),
+            "normal" => Ok(Difficulty::Normal),
+            "hard" => Ok(Difficulty::Hard),
+            _ => {
+                let invalid = || {
+                    format!(
+                        "`{}` is not a difficulty (use easy, normal, hard or MIN-MAX)",
+                        s
+                    )
+                };
+                let (min, max) = s.split_once('-').ok_or_else(invalid)?;
+                let min: u32 = min.trim().parse().map_err(|_| invalid())?;
+                let max: u32 = max.trim().parse().map_err(|_| invalid())?;
+
+                if min > max {
+                    return Err(format!("`{}` is an empty range: {} > {}", s, min, max));
+                }
+
+                Ok(Difficulty::Custom { min, max })
+            }
+        }
+    }
+}
+
+/// Everything that can be tuned about a game before it starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GameConfig {
+    pub difficulty: Difficulty,
+    /// The game is lost once this many guesses have been wrong; `None` plays until the number is found.
+    pub max_attempts: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Difficulty;
+
+    #[test]
+    fn parses_presets_and_custom_ranges() {
+        assert_eq!("hard".parse::<Difficulty>().unwrap().range(), 1..=10_000);
+        assert_eq!(
+            "5-50".parse::<Difficulty>(),
+            Ok(Difficulty::Custom { min: 5, max: 50 })
+        );
+        assert!("50-5".parse::<Difficulty>().is_err());
+        assert!("extreme".parse::<Difficulty>().is_err());
+    }
+}
@@ -0,0 +1,513 @@
+use std::cmp::Ordering;
+use std::io::{self, BufRead, Write};
+use std::ops::RangeInclusive;
+
+use rand::Rng;
+
+use super::blitz::Timer;
+use super::bulls_and_cows::Code;
+use super::difficulty::GameConfig;
+use super::guess::Guess;
+use super::hints::{Hint, Turn};
+use super::transcript::Transcript;
+
+/// The feedback for a single guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    TooSmall,
+    TooBig,
+    Correct { attempts: u32 },
+}
+
+impl Outcome {
+    /// How the guess compared to the secret number: the `Ordering` the game matched on.
+    pub fn ordering(&self) -> Ordering {
+        match self {
+            Outcome::TooSmall => Ordering::Less,
+            Outcome::TooBig => Ordering::Greater,
+            Outcome::Correct { .. } => Ordering::Equal,
+        }
+    }
+}
+
+/// How a game driven by [`GuessingGame::play`] ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameResult {
+    Won {
+        attempts: u32,
+    },
+    /// Every allowed attempt was used up without finding the number.
+    Lost {
+        attempts: u32,
+        secret_number: u32,
+    },
+    /// A [`BullsAndCows`](super::BullsAndCows) game ran out of attempts. The code is kept whole,
+    /// since read as a number it would lose its leading zeros.
+    CodeLost {
+        attempts: u32,
+        code: Code,
+    },
+    /// A blitz game's time limit ran out before the number was guessed.
+    OutOfTime {
+        attempts: u32,
+        secret_number: u32,
+    },
+    /// The input ran out (e.g. Ctrl-D) before the number was guessed.
+    Abandoned {
+        attempts: u32,
+    },
+}
+
+/// Counts guesses against an optional limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Attempts {
+    used: u32,
+    max: Option<u32>,
+}
+
+impl Attempts {
+    pub fn new(max: Option<u32>) -> Attempts {
+        Attempts { used: 0, max }
+    }
+
+    /// Counts one more attempt and returns how many have been made.
+    pub fn count(&mut self) -> u32 {
+        self.used += 1;
+        self.used
+    }
+
+    pub fn used(&self) -> u32 {
+        self.used
+    }
+
+    /// How many guesses are left, or `None` when attempts are unlimited.
+    pub fn remaining(&self) -> Option<u32> {
+        self.max.map(|max| max.saturating_sub(self.used))
+    }
+
+    /// Tells the player how many attempts are left after a wrong guess; `true` once none are.
+    pub fn report(&self, output: &mut dyn Write) -> io::Result<bool> {
+        match self.remaining() {
+            Some(0) => {
+                writeln!(output, "You're out of attempts!")?;
+                Ok(true)
+            }
+            Some(remaining) => writeln!(output, "Attempts left: {}", remaining).map(|_| false),
+            None => Ok(false),
+        }
+    }
+}
+
+/// A game played one line of input at a time; [`play_rounds`] supplies the loop around it.
+pub trait Rounds {
+    /// Handles one line of input, writing the feedback to `output`. Input that isn't a valid
+    /// guess is explained and doesn't count as an attempt. Returns the result once the game is over.
+    fn respond(&mut self, line: &str, output: &mut dyn Write) -> io::Result<Option<GameResult>>;
+
+    fn attempts(&self) -> u32;
+}
+
+/// Prompts for guesses and hands each line to `game` until it's over or `input` runs out.
+/// `on_line` sees every line along with the feedback it got.
+pub fn play_rounds<R: BufRead, W: Write>(
+    game: &mut dyn Rounds,
+    mut input: R,
+    mut output: W,
+    on_line: &mut dyn FnMut(&str, &str),
+) -> io::Result<GameResult> {
+    loop {
+        // Using a ! means that you’re calling a macro instead of a normal function.
+        writeln!(output, "Please input your guess.")?;
+
+        // In Rust, variables are immutable by default
+        let mut guess = String::new(); // String is UTF-8 encoded
+
+        // read_line returns how many bytes it read: 0 means the input is exhausted,
+        // so we stop instead of asking forever.
+        if input.read_line(&mut guess)? == 0 {
+            return Ok(GameResult::Abandoned {
+                attempts: game.attempts(),
+            });
+        }
+
+        // The feedback is collected first so `on_line` sees exactly what was shown.
+        let mut feedback = Vec::new();
+        let result = game.respond(&guess, &mut feedback)?;
+        output.write_all(&feedback)?;
+        on_line(&guess, &String::from_utf8_lossy(&feedback));
+
+        if let Some(result) = result {
+            return Ok(result);
+        }
+    }
+}
+
+pub struct GuessingGame {
+    secret_number: u32,
+    range: RangeInclusive<u32>,
+    attempts: Attempts,
+    history: Vec<(u32, Outcome)>,
+    hints: Vec<Box<dyn Hint>>,
+    transcript: Option<Transcript>,
+    timer: Option<Timer>,
+}
+
+/// SplitMix64: spreads nearby seeds (consecutive days, say) over the whole `u64` range. It is
+/// spelled out here so its output can never change.
+pub fn split_mix(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+impl GuessingGame {
+    /// Picks the secret number with `rng`; pass a seeded `StdRng` for a reproducible game.
+    pub fn new<R: Rng + ?Sized>(rng: &mut R) -> GuessingGame {
+        GuessingGame::with_config(rng, &GameConfig::default())
+    }
+
+    pub fn with_config<R: Rng + ?Sized>(rng: &mut R, config: &GameConfig) -> GuessingGame {
+        GuessingGame::with_secret(rng.gen_range(config.difficulty.range()), config)
+    }
+
+    /// Picks the secret number from `seed` without an RNG, so a seed stands for the same game
+    /// in every release: `rand` promises that for neither `StdRng` nor `gen_range`, and daily
+    /// puzzles and transcripts rely on it.
+    pub fn with_seed(seed: u64, config: &GameConfig) -> GuessingGame {
+        let range = config.difficulty.range();
+        let width = u64::from(range.end() - range.start()) + 1;
+        let offset = (split_mix(seed) % width) as u32;
+        GuessingGame::with_secret(range.start() + offset, config)
+    }
+
+    pub fn with_secret(secret_number: u32, config: &GameConfig) -> GuessingGame {
+        GuessingGame {
+            secret_number,
+            range: config.difficulty.range(),
+            attempts: Attempts::new(config.max_attempts),
+            history: Vec::new(),
+            hints: Vec::new(),
+            transcript: None,
+            timer: None,
+        }
+    }
+
+    /// The numbers a guess has to be in; anything else is rejected by [`Guess::parse`].
+    pub fn range(&self) -> &RangeInclusive<u32> {
+        &self.range
+    }
+
+    pub fn secret_number(&self) -> u32 {
+        self.secret_number
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.attempts.used()
+    }
+
+    /// How many guesses are left, or `None` when attempts are unlimited.
+    pub fn remaining_attempts(&self) -> Option<u32> {
+        self.attempts.remaining()
+    }
+
+    /// Adds a hint that [`play`](GuessingGame::play) shows after every wrong guess.
+    pub fn add_hint(&mut self, hint: Box<dyn Hint>) {
+        self.hints.push(hint);
+    }
+
+    /// Scores one guess. Every call counts as an attempt.
+    pub fn guess(&mut self, guess: u32) -> Outcome {
+        let attempts = self.attempts.count();
+
+        let outcome = match guess.cmp(&self.secret_number) {
+            Ordering::Less => Outcome::TooSmall,
+            Ordering::Greater => Outcome::TooBig,
+            Ordering::Equal => Outcome::Correct { attempts },
+        };
+        self.history.push((guess, outcome));
+        outcome
+    }
+
+    /// Every guess so far with its outcome.
+    pub fn history(&self) -> &[(u32, Outcome)] {
+        &self.history
+    }
+
+    /// Asks every hint about `guess`; `previous` is the guess made before it.
+    fn hints(&mut self, guess: u32, previous: Option<u32>) -> Vec<String> {
+        let turn = Turn {
+            guess,
+            previous,
+            secret_number: self.secret_number,
+            range: &self.range,
+        };
+
+        self.hints
+            .iter_mut()
+            .filter_map(|hint| hint.hint(&turn))
+            .collect()
+    }
+
+    /// Starts recording every line [`play`](GuessingGame::play) reads into `transcript`.
+    pub fn record(&mut self, transcript: Transcript) {
+        self.transcript = Some(transcript);
+    }
+
+    /// The transcript started with [`record`](GuessingGame::record), with the result of the game.
+    pub fn take_transcript(&mut self) -> Option<Transcript> {
+        self.transcript.take()
+    }
+
+    /// Times every guess from now on and ends the game once `timer`'s limit is exceeded.
+    pub fn time(&mut self, timer: Timer) {
+        self.timer = Some(timer);
+    }
+
+    pub fn timer(&self) -> Option<&Timer> {
+        self.timer.as_ref()
+    }
+
+    /// Reads guesses line by line from `input` until the number is guessed or the input ends.
+    pub fn play<R: BufRead, W: Write>(
+        &mut self,
+        input: R,
+        mut output: W,
+    ) -> io::Result<GameResult> {
+        let mut transcript = self.transcript.take();
+        let result = play_rounds(self, input, &mut output, &mut |line, feedback| {
+            if let Some(transcript) = &mut transcript {
+                transcript.push(line, feedback);
+            }
+        })?;
+
+        if let Some(transcript) = &mut transcript {
+            transcript.finish(result);
+        }
+        self.transcript = transcript;
+
+        // An abandoned game may be picked up again (a daily puzzle, say), so it keeps its secret.
+        if !matches!(result, GameResult::Abandoned { .. }) {
+            writeln!(output, "The secret number is: {}", self.secret_number)?;
+        }
+
+        Ok(result)
+    }
+}
+
+impl Rounds for GuessingGame {
+    fn respond(&mut self, guess: &str, output: &mut dyn Write) -> io::Result<Option<GameResult>> {
+        if let Some(reason) = self.timer.as_mut().and_then(Timer::check) {
+            writeln!(output, "{}", reason)?;
+            return Ok(Some(GameResult::OutOfTime {
+                attempts: self.attempts(),
+                secret_number: self.secret_number,
+            }));
+        }
+
+        // Can "shadow" the previous value of guess with a new one.
+        // Used in situations in which you want to convert a value from one type to another type.
+        // Switching from an expect call to a match expression is how you generally
+        // move from crashing on an error to handling the error.
+        let guess = match Guess::parse(guess, &self.range) {
+            Ok(guess) => guess.value(),
+            Err(error) => {
+                // Bad input doesn't count as an attempt, but the player is told what was wrong.
+                writeln!(output, "{}", error)?;
+                return Ok(None);
+            }
+        };
+
+        if let Some(timer) = &mut self.timer {
+            timer.lap();
+        }
+
+        let previous = self.history.last().map(|&(guess, _)| guess);
+        match self.guess(guess) {
+            Outcome::TooSmall => writeln!(output, "Too small!")?,
+            Outcome::TooBig => writeln!(output, "Too big!")?,
+            Outcome::Correct { attempts } => {
+                writeln!(
+                    output,
+                    "You guessed the number after {} attempts!",
+                    attempts
+                )?;
+                return Ok(Some(GameResult::Won { attempts }));
+            }
+        }
+
+        writeln!(output, "You guessed: {}", guess)?;
+
+        for hint in self.hints(guess, previous) {
+            writeln!(output, "Hint: {}", hint)?;
+        }
+
+        if self.attempts.report(output)? {
+            return Ok(Some(GameResult::Lost {
+                attempts: self.attempts(),
+                secret_number: self.secret_number,
+            }));
+        }
+
+        if let Some(left) = self.timer.as_ref().and_then(Timer::remaining) {
+            writeln!(output, "Time left: {:.1}s", left.as_secs_f64())?;
+        }
+
+        Ok(None)
+    }
+
+    fn attempts(&self) -> u32 {
+        self.attempts.used()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::time::Duration;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::{GameResult, GuessingGame, Outcome};
+    use crate::guessing_game::blitz::{Clock, TimeLimit, Timer};
+    use crate::guessing_game::hints::{HotCold, WarmerColder};
+    use crate::guessing_game::{Difficulty, GameConfig};
+
+    #[test]
+    fn counts_every_guess_as_an_attempt() {
+        let mut game = GuessingGame::with_secret(42, &GameConfig::default());
+
+        assert_eq!(game.guess(10), Outcome::TooSmall);
+        assert_eq!(game.guess(90), Outcome::TooBig);
+        assert_eq!(game.guess(42), Outcome::Correct { attempts: 3 });
+    }
+
+    #[test]
+    fn same_seed_picks_the_same_secret() {
+        let first = GuessingGame::new(&mut StdRng::seed_from_u64(7));
+        let second = GuessingGame::new(&mut StdRng::seed_from_u64(7));
+
+        assert_eq!(first.secret_number(), second.secret_number());
+        assert!((1..=100).contains(&first.secret_number()));
+    }
+
+    #[test]
+    fn unparsable_lines_do_not_count_as_attempts() {
+        let mut game = GuessingGame::with_secret(50, &GameConfig::default());
+        let mut output = Vec::new();
+
+        let result = game
+            .play(Cursor::new("25\nfifty\n75\n50\n"), &mut output)
+            .unwrap();
+
+        assert_eq!(result, GameResult::Won { attempts: 3 });
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("You guessed the number after 3 attempts!"));
+    }
+
+    #[test]
+    fn explains_why_a_guess_was_rejected() {
+        let mut game = GuessingGame::with_secret(50, &GameConfig::default());
+        let mut output = Vec::new();
+
+        let result = game
+            .play(Cursor::new("-5\n500\n50\n"), &mut output)
+            .unwrap();
+
+        assert_eq!(result, GameResult::Won { attempts: 1 });
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("`-5` is negative"));
+        assert!(output.contains("500 is out of range; the secret number is between 1 and 100."));
+    }
+
+    #[test]
+    fn ends_cleanly_when_input_runs_out() {
+        let mut game = GuessingGame::with_secret(50, &GameConfig::default());
+
+        let mut output = Vec::new();
+        let result = game.play(Cursor::new("10\n"), &mut output).unwrap();
+
+        assert_eq!(result, GameResult::Abandoned { attempts: 1 });
+        assert!(!String::from_utf8(output).unwrap().contains("50"));
+    }
+
+    #[test]
+    fn draws_the_secret_from_the_difficulty_range() {
+        let config = GameConfig {
+            difficulty: Difficulty::Easy,
+            max_attempts: None,
+        };
+
+        for seed in 0..20 {
+            let game = GuessingGame::with_config(&mut StdRng::seed_from_u64(seed), &config);
+            assert!((1..=10).contains(&game.secret_number()));
+        }
+    }
+
+    #[test]
+    fn running_out_of_attempts_loses_and_reveals_the_secret() {
+        let config = GameConfig {
+            difficulty: Difficulty::Normal,
+            max_attempts: Some(2),
+        };
+        let mut game = GuessingGame::with_secret(50, &config);
+        let mut output = Vec::new();
+
+        let result = game.play(Cursor::new("10\n90\n50\n"), &mut output).unwrap();
+
+        assert_eq!(
+            result,
+            GameResult::Lost {
+                attempts: 2,
+                secret_number: 50
+            }
+        );
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.ends_with("You're out of attempts!\nThe secret number is: 50\n"));
+    }
+
+    #[test]
+    fn shows_every_hint_after_a_wrong_guess() {
+        let mut game = GuessingGame::with_secret(42, &GameConfig::default());
+        game.add_hint(Box::new(HotCold));
+        game.add_hint(Box::new(WarmerColder));
+        let mut output = Vec::new();
+
+        game.play(Cursor::new("90\n45\n42\n"), &mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("You guessed: 90\nHint: Cold\n"));
+        assert!(output.contains("You guessed: 45\nHint: Hot\nHint: Warmer\n"));
+    }
+
+    #[test]
+    fn a_late_answer_ends_a_timed_game() {
+        struct Slow(u64);
+
+        impl Clock for Slow {
+            fn elapsed(&mut self) -> Duration {
+                self.0 += 20;
+                Duration::from_secs(self.0)
+            }
+        }
+
+        let mut game = GuessingGame::with_secret(42, &GameConfig::default());
+        let limit = TimeLimit::Total(Duration::from_secs(30));
+        game.time(Timer::new(Some(limit), Box::new(Slow(0))));
+        let mut output = Vec::new();
+
+        let result = game.play(Cursor::new("50\n42\n"), &mut output).unwrap();
+
+        assert_eq!(
+            result,
+            GameResult::OutOfTime {
+                attempts: 1,
+                secret_number: 42
+            }
+        );
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("You guessed: 50\nTime left: 10.0s\n"));
+        assert!(output.contains("Time's up!"));
+    }
+}
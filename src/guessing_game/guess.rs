@@ -0,0 +1,128 @@
+use std::error::Error;
+use std::fmt;
+use std::num::IntErrorKind;
+use std::ops::RangeInclusive;
+
+/// A guess that is known to be a number inside the game's range.
+///
+/// This is the `Guess` type from chapter 9 ("Creating Custom Types for Validation"),
+/// except that invalid input is reported with a [`GuessError`] instead of a panic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Guess(u32);
+
+impl Guess {
+    pub fn parse(input: &str, range: &RangeInclusive<u32>) -> Result<Guess, GuessError> {
+        let input = input.trim();
+
+        let value: u32 = match input.parse() {
+            Ok(value) => value,
+            Err(error) => {
+                return Err(match error.kind() {
+                    IntErrorKind::Empty => GuessError::Empty,
+                    IntErrorKind::PosOverflow => GuessError::Overflow(input.to_string()),
+                    _ if is_negative_number(input) => GuessError::Negative(input.to_string()),
+                    _ => GuessError::NotANumber(input.to_string()),
+                })
+            }
+        };
+
+        if !range.contains(&value) {
+            return Err(GuessError::OutOfRange {
+                value,
+                min: *range.start(),
+                max: *range.end(),
+            });
+        }
+
+        Ok(Guess(value))
+    }
+
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+pub(super) fn is_negative_number(input: &str) -> bool {
+    match input.strip_prefix('-') {
+        Some(digits) => !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()),
+        None => false,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GuessError {
+    Empty,
+    NotANumber(String),
+    Negative(String),
+    Overflow(String),
+    OutOfRange { value: u32, min: u32, max: u32 },
+}
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GuessError::Empty => write!(f, "Please type a number before pressing Enter."),
+            GuessError::NotANumber(input) => write!(f, "`{}` is not a number.", input),
+            GuessError::Negative(input) => {
+                write!(
+                    f,
+                    "`{}` is negative; guesses have to be positive numbers.",
+                    input
+                )
+            }
+            GuessError::Overflow(input) => write!(f, "`{}` is too large to be a guess.", input),
+            GuessError::OutOfRange { value, min, max } => write!(
+                f,
+                "{} is out of range; the secret number is between {} and {}.",
+                value, min, max
+            ),
+        }
+    }
+}
+
+impl Error for GuessError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Guess, GuessError};
+
+    const RANGE: std::ops::RangeInclusive<u32> = 1..=100;
+
+    #[test]
+    fn accepts_numbers_in_range_with_surrounding_whitespace() {
+        assert_eq!(Guess::parse(" 42\n", &RANGE).map(|g| g.value()), Ok(42));
+    }
+
+    #[test]
+    fn tells_apart_each_kind_of_bad_input() {
+        assert_eq!(Guess::parse("\n", &RANGE), Err(GuessError::Empty));
+        assert_eq!(
+            Guess::parse("abc", &RANGE),
+            Err(GuessError::NotANumber(String::from("abc")))
+        );
+        assert_eq!(
+            Guess::parse("-5", &RANGE),
+            Err(GuessError::Negative(String::from("-5")))
+        );
+        assert_eq!(
+            Guess::parse("99999999999", &RANGE),
+            Err(GuessError::Overflow(String::from("99999999999")))
+        );
+        assert_eq!(
+            Guess::parse("500", &RANGE),
+            Err(GuessError::OutOfRange {
+                value: 500,
+                min: 1,
+                max: 100
+            })
+        );
+    }
+
+    #[test]
+    fn zero_is_out_of_range() {
+        assert!(matches!(
+            Guess::parse("0", &RANGE),
+            Err(GuessError::OutOfRange { value: 0, .. })
+        ));
+    }
+}
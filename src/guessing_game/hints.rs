@@ -0,0 +1,248 @@
+use std::ops::RangeInclusive;
+
+/// What a hint gets to look at after a wrong guess.
+pub struct Turn<'a> {
+    pub guess: u32,
+    /// The guess before this one, if any.
+    pub previous: Option<u32>,
+    pub secret_number: u32,
+    pub range: &'a RangeInclusive<u32>,
+}
+
+impl Turn<'_> {
+    fn distance(&self, guess: u32) -> u32 {
+        guess.abs_diff(self.secret_number)
+    }
+}
+
+/// Extra feedback shown after "Too small!"/"Too big!". New kinds of hints only need to
+/// implement this trait; the game loop just asks every hint it was given.
+pub trait Hint {
+    fn name(&self) -> &'static str;
+
+    /// A line to show the player after a wrong guess, or `None` to stay quiet this turn.
+    fn hint(&mut self, turn: &Turn) -> Option<String>;
+}
+
+/// How far off the guess is, in bands relative to the size of the range.
+pub struct HotCold;
+
+impl Hint for HotCold {
+    fn name(&self) -> &'static str {
+        "hot-cold"
+    }
+
+    fn hint(&mut self, turn: &Turn) -> Option<String> {
+        let width = f64::from(turn.range.end() - turn.range.start()) + 1.0;
+        let off = f64::from(turn.distance(turn.guess)) / width;
+
+        let band = if off <= 0.02 {
+            "Scorching!"
+        } else if off <= 0.05 {
+            "Hot"
+        } else if off <= 0.15 {
+            "Warm"
+        } else if off <= 0.35 {
+            "Cool"
+        } else {
+            "Cold"
+        };
+        Some(band.to_string())
+    }
+}
+
+/// Whether this guess is closer to the secret than the one before.
+pub struct WarmerColder;
+
+impl Hint for WarmerColder {
+    fn name(&self) -> &'static str {
+        "warmer"
+    }
+
+    fn hint(&mut self, turn: &Turn) -> Option<String> {
+        let previous = turn.previous?;
+
+        let hint = match turn.distance(turn.guess).cmp(&turn.distance(previous)) {
+            std::cmp::Ordering::Less => "Warmer",
+            std::cmp::Ordering::Greater => "Colder",
+            std::cmp::Ordering::Equal => "Same distance as last time",
+        };
+        Some(hint.to_string())
+    }
+}
+
+/// One fact about the secret per wrong guess: its parity, then divisibility by 3, 5 and 7.
+#[derive(Default)]
+pub struct Divisibility {
+    told: usize,
+}
+
+impl Hint for Divisibility {
+    fn name(&self) -> &'static str {
+        "parity"
+    }
+
+    fn hint(&mut self, turn: &Turn) -> Option<String> {
+        let secret = turn.secret_number;
+
+        let hint = match self.told {
+            0 if secret.is_multiple_of(2) => String::from("The number is even"),
+            0 => String::from("The number is odd"),
+            1..=3 => {
+                let divisor = [3, 5, 7][self.told - 1];
+                if secret.is_multiple_of(divisor) {
+                    format!("The number is divisible by {}", divisor)
+                } else {
+                    format!("The number is not divisible by {}", divisor)
+                }
+            }
+            _ => return None,
+        };
+        self.told += 1;
+        Some(hint)
+    }
+}
+
+/// Reveals one digit of the secret per wrong guess, starting from the last one,
+/// until the budget runs out.
+pub struct DigitReveal {
+    budget: u32,
+    revealed: u32,
+}
+
+impl DigitReveal {
+    pub fn new(budget: u32) -> DigitReveal {
+        DigitReveal {
+            budget,
+            revealed: 0,
+        }
+    }
+}
+
+impl Hint for DigitReveal {
+    fn name(&self) -> &'static str {
+        "reveal"
+    }
+
+    fn hint(&mut self, turn: &Turn) -> Option<String> {
+        let digits = turn.secret_number.to_string();
+        if self.revealed >= self.budget || self.revealed as usize >= digits.len() {
+            return None;
+        }
+
+        let position = self.revealed as usize;
+        let digit = digits.as_bytes()[digits.len() - 1 - position] as char;
+        self.revealed += 1;
+
+        let place = match position {
+            0 => String::from("last digit"),
+            1 => String::from("second-to-last digit"),
+            n => format!("digit {} places from the end", n + 1),
+        };
+        Some(format!(
+            "The {} is {} ({} left)",
+            place,
+            digit,
+            plural(self.budget - self.revealed, "reveal")
+        ))
+    }
+}
+
+fn plural(count: u32, noun: &str) -> String {
+    format!("{} {}{}", count, noun, if count == 1 { "" } else { "s" })
+}
+
+/// The hint names [`by_name`] understands.
+pub const HINTS: &[&str] = &["hot-cold", "warmer", "parity", "reveal[:N]"];
+
+/// Builds a hint from its name; `reveal:N` sets the reveal budget (1 by default).
+pub fn by_name(spec: &str) -> Result<Box<dyn Hint>, String> {
+    let (name, argument) = match spec.split_once(':') {
+        Some((name, argument)) => (name, Some(argument)),
+        None => (spec, None),
+    };
+
+    match (name, argument) {
+        ("hot-cold", None) => Ok(Box::new(HotCold)),
+        ("warmer", None) => Ok(Box::new(WarmerColder)),
+        ("parity", None) => Ok(Box::new(Divisibility::default())),
+        ("reveal", None) => Ok(Box::new(DigitReveal::new(1))),
+        ("reveal", Some(budget)) => match budget.parse() {
+            Ok(0) => Err(String::from("`reveal:0` would never reveal anything")),
+            Ok(budget) => Ok(Box::new(DigitReveal::new(budget))),
+            Err(_) => Err(format!("`{}` is not a number of reveals", budget)),
+        },
+        _ => Err(format!(
+            "unknown hint `{}` (available: {})",
+            spec,
+            HINTS.join(", ")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{by_name, DigitReveal, Divisibility, Hint, HotCold, Turn, WarmerColder};
+
+    fn turn(guess: u32, previous: Option<u32>) -> Turn<'static> {
+        Turn {
+            guess,
+            previous,
+            secret_number: 42,
+            range: &(1..=100),
+        }
+    }
+
+    #[test]
+    fn hot_cold_bands_depend_on_the_distance() {
+        assert_eq!(HotCold.hint(&turn(43, None)).unwrap(), "Scorching!");
+        assert_eq!(HotCold.hint(&turn(52, None)).unwrap(), "Warm");
+        assert_eq!(HotCold.hint(&turn(100, None)).unwrap(), "Cold");
+    }
+
+    #[test]
+    fn warmer_colder_needs_a_previous_guess() {
+        assert_eq!(WarmerColder.hint(&turn(50, None)), None);
+        assert_eq!(WarmerColder.hint(&turn(45, Some(50))).unwrap(), "Warmer");
+        assert_eq!(WarmerColder.hint(&turn(10, Some(50))).unwrap(), "Colder");
+    }
+
+    #[test]
+    fn divisibility_tells_one_fact_per_turn_then_stops() {
+        let mut hint = Divisibility::default();
+        let facts: Vec<Option<String>> = (0..5).map(|_| hint.hint(&turn(1, None))).collect();
+
+        assert_eq!(
+            facts,
+            vec![
+                Some(String::from("The number is even")),
+                Some(String::from("The number is divisible by 3")),
+                Some(String::from("The number is not divisible by 5")),
+                Some(String::from("The number is divisible by 7")),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn digit_reveal_stops_when_the_budget_is_spent() {
+        let mut hint = DigitReveal::new(2);
+        assert_eq!(
+            hint.hint(&turn(1, None)).unwrap(),
+            "The last digit is 2 (1 reveal left)"
+        );
+        assert_eq!(
+            hint.hint(&turn(1, None)).unwrap(),
+            "The second-to-last digit is 4 (0 reveals left)"
+        );
+        assert_eq!(hint.hint(&turn(1, None)), None);
+    }
+
+    #[test]
+    fn hints_are_built_from_their_names() {
+        assert_eq!(by_name("reveal:3").unwrap().name(), "reveal");
+        assert!(by_name("reveal:lots").is_err());
+        assert!(by_name("reveal:0").is_err());
+        assert!(by_name("psychic").is_err());
+    }
+}
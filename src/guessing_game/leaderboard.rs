@@ -0,0 +1,346 @@
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, ErrorKind, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::daily::Date;
+use super::difficulty::Difficulty;
+
+/// Where `play` keeps its results unless told otherwise.
+pub const DEFAULT_PATH: &str = "guessing_game_scores.tsv";
+
+/// `fields[index]` as a number of whatever type it is stored as, without wrapping.
+fn field<T: FromStr>(fields: &[&str], index: usize) -> Result<T, String> {
+    fields[index]
+        .parse()
+        .map_err(|_| format!("`{}` is not a number in range", fields[index]))
+}
+
+/// One finished game (won or lost) as stored in the scores file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameRecord {
+    pub player: String,
+    pub min: u32,
+    pub max: u32,
+    pub attempts: u32,
+    pub duration: Duration,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub won: bool,
+    /// The day whose daily challenge this was, if it was one.
+    pub daily: Option<Date>,
+}
+
+impl GameRecord {
+    pub fn difficulty(&self) -> Difficulty {
+        Difficulty::from_range(self.min, self.max)
+    }
+
+    /// One tab-separated line: timestamp, player, min, max, attempts, duration in ms, won/lost,
+    /// and for a daily challenge its date as an eighth field.
+    fn to_line(&self) -> String {
+        let mut line = format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.timestamp,
+            self.player,
+            self.min,
+            self.max,
+            self.attempts,
+            self.duration.as_millis(),
+            if self.won { "won" } else { "lost" }
+        );
+        if let Some(date) = self.daily {
+            line.push_str(&format!("\t{}", date));
+        }
+        line
+    }
+
+    fn from_line(line: &str) -> Result<GameRecord, String> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        if !(7..=8).contains(&fields.len()) {
+            return Err(format!("expected 7 or 8 fields, found {}", fields.len()));
+        }
+
+        let (min, max) = (field(&fields, 2)?, field(&fields, 3)?);
+        if min > max {
+            return Err(format!("the range {}-{} is backwards", min, max));
+        }
+
+        Ok(GameRecord {
+            timestamp: field(&fields, 0)?,
+            player: fields[1].to_string(),
+            min,
+            max,
+            attempts: field(&fields, 4)?,
+            duration: Duration::from_millis(field(&fields, 5)?),
+            won: match fields[6] {
+                "won" => true,
+                "lost" => false,
+                other => return Err(format!("`{}` is neither won nor lost", other)),
+            },
+            daily: fields.get(7).map(|date| date.parse()).transpose()?,
+        })
+    }
+
+    /// Fewer attempts is better; a faster game breaks the tie.
+    fn beats(&self, other: &GameRecord) -> bool {
+        (self.attempts, self.duration) < (other.attempts, other.duration)
+    }
+}
+
+/// A player's current and longest run of consecutive wins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Streak {
+    pub current: u32,
+    pub longest: u32,
+}
+
+#[derive(Debug, Default)]
+pub struct Leaderboard {
+    records: Vec<GameRecord>,
+}
+
+impl Leaderboard {
+    pub fn new(records: Vec<GameRecord>) -> Leaderboard {
+        Leaderboard { records }
+    }
+
+    /// Reads every record from `path`. A missing file is an empty leaderboard.
+    pub fn load(path: &Path) -> io::Result<Leaderboard> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(error),
+        };
+
+        let mut records = Vec::new();
+        for (number, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record = GameRecord::from_line(line).map_err(|message| {
+                io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("{} line {}: {}", path.display(), number + 1, message),
+                )
+            })?;
+            records.push(record);
+        }
+
+        Ok(Leaderboard { records })
+    }
+
+    /// Adds `record` to the end of the file at `path`, creating it if needed.
+    pub fn append(path: &Path, record: &GameRecord) -> io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", record.to_line())
+    }
+
+    pub fn records(&self) -> &[GameRecord] {
+        &self.records
+    }
+
+    /// The best won game for each difficulty, easiest difficulty first.
+    pub fn best_by_difficulty(&self) -> Vec<&GameRecord> {
+        best_per_difficulty(self.records.iter())
+    }
+
+    /// The best won game `player` has on every difficulty they have played.
+    pub fn personal_bests(&self, player: &str) -> Vec<&GameRecord> {
+        best_per_difficulty(self.records.iter().filter(|r| r.player == player))
+    }
+
+    /// Every player, in alphabetical order.
+    pub fn players(&self) -> Vec<&str> {
+        let mut players: Vec<&str> = self.records.iter().map(|r| r.player.as_str()).collect();
+        players.sort_unstable();
+        players.dedup();
+        players
+    }
+
+    /// Consecutive wins for `player`, in the order the games were played.
+    pub fn streak(&self, player: &str) -> Streak {
+        let mut streak = Streak::default();
+        for record in self.records.iter().filter(|r| r.player == player) {
+            if record.won {
+                streak.current += 1;
+                streak.longest = streak.longest.max(streak.current);
+            } else {
+                streak.current = 0;
+            }
+        }
+        streak
+    }
+
+    pub fn render(&self, out: &mut dyn Write, player: Option<&str>) -> io::Result<()> {
+        if self.records.is_empty() {
+            return writeln!(out, "No games recorded yet. Play one with `play`!");
+        }
+
+        writeln!(out, "Best per difficulty")?;
+        for record in self.best_by_difficulty() {
+            writeln!(
+                out,
+                "  {:<12} {:<16} {:>4} attempts  {:>7.1}s",
+                record.difficulty().to_string(),
+                record.player,
+                record.attempts,
+                record.duration.as_secs_f64()
+            )?;
+        }
+
+        writeln!(out)?;
+        writeln!(out, "Players")?;
+        writeln!(
+            out,
+            "  {:<16} {:>5} {:>5} {:>7} {:>12}",
+            "player", "games", "wins", "streak", "best streak"
+        )?;
+        for name in self.players() {
+            let games = self.records.iter().filter(|r| r.player == name);
+            let wins = games.clone().filter(|r| r.won).count();
+            let streak = self.streak(name);
+            writeln!(
+                out,
+                "  {:<16} {:>5} {:>5} {:>7} {:>12}",
+                name,
+                games.count(),
+                wins,
+                streak.current,
+                streak.longest
+            )?;
+        }
+
+        let players = match player {
+            Some(player) => vec![player],
+            None => self.players(),
+        };
+        writeln!(out)?;
+        writeln!(out, "Personal bests")?;
+        for name in players {
+            for record in self.personal_bests(name) {
+                writeln!(
+                    out,
+                    "  {:<16} {:<12} {:>4} attempts  {:>7.1}s",
+                    name,
+                    record.difficulty().to_string(),
+                    record.attempts,
+                    record.duration.as_secs_f64()
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn best_per_difficulty<'a>(records: impl Iterator<Item = &'a GameRecord>) -> Vec<&'a GameRecord> {
+    // Keyed by (width, min) so easier difficulties come first.
+    let mut best: BTreeMap<(u32, u32), &GameRecord> = BTreeMap::new();
+    for record in records.filter(|record| record.won) {
+        let key = (record.max - record.min, record.min);
+        match best.get(&key) {
+            Some(current) if !record.beats(current) => (),
+            _ => {
+                best.insert(key, record);
+            }
+        }
+    }
+    best.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{GameRecord, Leaderboard, Streak};
+
+    fn record(player: &str, max: u32, attempts: u32, won: bool) -> GameRecord {
+        GameRecord {
+            player: player.to_string(),
+            min: 1,
+            max,
+            attempts,
+            duration: Duration::from_millis(1500),
+            timestamp: 1_700_000_000,
+            won,
+            daily: None,
+        }
+    }
+
+    #[test]
+    fn records_survive_a_round_trip_through_the_file_format() {
+        let original = record("ferris", 100, 7, true);
+        assert_eq!(GameRecord::from_line(&original.to_line()), Ok(original));
+    }
+
+    #[test]
+    fn daily_results_carry_their_date() {
+        let daily = GameRecord {
+            daily: Some("2024-02-29".parse().unwrap()),
+            ..record("ferris", 100, 7, true)
+        };
+        let line = daily.to_line();
+        assert!(line.ends_with("\twon\t2024-02-29"));
+        assert_eq!(GameRecord::from_line(&line), Ok(daily));
+        assert!(
+            GameRecord::from_line("1700000000\tferris\t1\t100\t7\t1500\twon\tyesterday").is_err()
+        );
+    }
+
+    #[test]
+    fn corrupt_lines_are_rejected_instead_of_wrapped() {
+        let line = |min: &str, max: &str, attempts: &str| {
+            format!(
+                "1700000000\tferris\t{}\t{}\t{}\t1500\twon",
+                min, max, attempts
+            )
+        };
+        assert!(GameRecord::from_line(&line("1", "100", "7")).is_ok());
+        assert!(GameRecord::from_line(&line("1", "4294967396", "7")).is_err());
+        assert!(GameRecord::from_line(&line("1", "100", "4294967297")).is_err());
+        assert!(GameRecord::from_line(&line("-1", "100", "7")).is_err());
+        assert_eq!(
+            GameRecord::from_line(&line("100", "1", "7")),
+            Err(String::from("the range 100-1 is backwards"))
+        );
+    }
+
+    #[test]
+    fn best_by_difficulty_ignores_losses_and_keeps_the_fewest_attempts() {
+        let board = Leaderboard::new(vec![
+            record("alice", 100, 7, true),
+            record("bob", 100, 5, true),
+            record("carol", 100, 2, false),
+            record("alice", 10, 3, true),
+        ]);
+
+        let best: Vec<(&str, u32, u32)> = board
+            .best_by_difficulty()
+            .iter()
+            .map(|r| (r.player.as_str(), r.max, r.attempts))
+            .collect();
+        assert_eq!(best, vec![("alice", 10, 3), ("bob", 100, 5)]);
+    }
+
+    #[test]
+    fn streaks_reset_on_a_loss() {
+        let board = Leaderboard::new(vec![
+            record("alice", 100, 7, true),
+            record("alice", 100, 6, true),
+            record("alice", 100, 9, true),
+            record("alice", 100, 10, false),
+            record("alice", 100, 4, true),
+        ]);
+
+        assert_eq!(
+            board.streak("alice"),
+            Streak {
+                current: 1,
+                longest: 3
+            }
+        );
+    }
+}
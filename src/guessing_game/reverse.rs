@@ -0,0 +1,242 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::ops::RangeInclusive;
+
+use super::solver::Strategy;
+
+/// The roles of chapter 2, swapped: the player thinks of a number and the program guesses it.
+pub struct ReverseGame {
+    strategy: Box<dyn Strategy>,
+    min: u32,
+    max: u32,
+    low: u32,
+    high: u32,
+    /// The guesses that set the current bounds, to explain a contradiction.
+    low_set_by: Option<u32>,
+    high_set_by: Option<u32>,
+    attempts: u32,
+}
+
+/// The player's answers can't all be true.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Contradiction {
+    pub guess: u32,
+    /// How the guess compares to the player's number, according to the player.
+    pub answer: Ordering,
+    /// The earlier guess this answer conflicts with; `None` when it conflicts with the range itself.
+    pub earlier: Option<u32>,
+    pub min: u32,
+    pub max: u32,
+}
+
+impl fmt::Display for Contradiction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.answer, self.earlier) {
+            (Ordering::Less, Some(earlier)) => write!(
+                f,
+                "You said your number is higher than {}, but earlier you said it is lower than {}.",
+                self.guess, earlier
+            ),
+            (Ordering::Less, None) => write!(
+                f,
+                "You said your number is higher than {}, but {} is the largest number allowed.",
+                self.guess, self.max
+            ),
+            (_, Some(earlier)) => write!(
+                f,
+                "You said your number is lower than {}, but earlier you said it is higher than {}.",
+                self.guess, earlier
+            ),
+            (_, None) => write!(
+                f,
+                "You said your number is lower than {}, but {} is the smallest number allowed.",
+                self.guess, self.min
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReverseResult {
+    Guessed { number: u32, attempts: u32 },
+    Cheated(Contradiction),
+    Abandoned { attempts: u32 },
+}
+
+/// Reads `higher`/`lower`/`correct` (or just `h`/`l`/`c`) as the guess's `Ordering` against
+/// the player's number, the same way the chapter compares `guess.cmp(&secret_number)`.
+pub fn parse_answer(input: &str) -> Option<Ordering> {
+    match input.trim().to_lowercase().as_str() {
+        "h" | "higher" | "+" => Some(Ordering::Less),
+        "l" | "lower" | "-" => Some(Ordering::Greater),
+        "c" | "correct" | "=" => Some(Ordering::Equal),
+        _ => None,
+    }
+}
+
+impl ReverseGame {
+    pub fn new(range: RangeInclusive<u32>, strategy: Box<dyn Strategy>) -> ReverseGame {
+        let (min, max) = range.into_inner();
+        ReverseGame {
+            strategy,
+            min,
+            max,
+            low: min,
+            high: max,
+            low_set_by: None,
+            high_set_by: None,
+            attempts: 0,
+        }
+    }
+
+    pub fn propose(&mut self) -> u32 {
+        self.attempts += 1;
+        self.strategy.next_guess(self.low, self.high)
+    }
+
+    /// Narrows the range with the player's answer to `guess`.
+    /// Returns `Ok(true)` once the number is found.
+    pub fn answer(&mut self, guess: u32, answer: Ordering) -> Result<bool, Contradiction> {
+        let contradiction = |earlier| Contradiction {
+            guess,
+            answer,
+            earlier,
+            min: self.min,
+            max: self.max,
+        };
+
+        match answer {
+            Ordering::Equal => Ok(true),
+            Ordering::Less => {
+                if guess >= self.high {
+                    return Err(contradiction(self.high_set_by));
+                }
+                self.low = guess + 1;
+                self.low_set_by = Some(guess);
+                Ok(false)
+            }
+            Ordering::Greater => {
+                if guess <= self.low {
+                    return Err(contradiction(self.low_set_by));
+                }
+                self.high = guess - 1;
+                self.high_set_by = Some(guess);
+                Ok(false)
+            }
+        }
+    }
+
+    pub fn play<R: BufRead, W: Write>(
+        &mut self,
+        mut input: R,
+        mut output: W,
+    ) -> io::Result<ReverseResult> {
+        writeln!(
+            output,
+            "Think of a number between {} and {}. I'll try to guess it!",
+            self.min, self.max
+        )?;
+        writeln!(
+            output,
+            "Answer each guess with (h)igher, (l)ower or (c)orrect."
+        )?;
+
+        loop {
+            let guess = self.propose();
+
+            let answer = loop {
+                writeln!(output, "Is it {}?", guess)?;
+
+                let mut line = String::new();
+                if input.read_line(&mut line)? == 0 {
+                    return Ok(ReverseResult::Abandoned {
+                        attempts: self.attempts,
+                    });
+                }
+
+                match parse_answer(&line) {
+                    Some(answer) => break answer,
+                    None => writeln!(output, "Please answer higher, lower or correct.")?,
+                }
+            };
+
+            match self.answer(guess, answer) {
+                Ok(true) => {
+                    writeln!(
+                        output,
+                        "Got it! Your number is {}; that took me {} guesses.",
+                        guess, self.attempts
+                    )?;
+                    return Ok(ReverseResult::Guessed {
+                        number: guess,
+                        attempts: self.attempts,
+                    });
+                }
+                Ok(false) => (),
+                Err(contradiction) => {
+                    writeln!(output, "{} No number fits all your answers!", contradiction)?;
+                    return Ok(ReverseResult::Cheated(contradiction));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+    use std::io::Cursor;
+
+    use super::{Contradiction, ReverseGame, ReverseResult};
+    use crate::guessing_game::solver::BinarySearch;
+
+    fn game() -> ReverseGame {
+        ReverseGame::new(1..=100, Box::new(BinarySearch))
+    }
+
+    #[test]
+    fn finds_the_number_with_honest_answers() {
+        // Thinking of 70: 50 -> higher, 75 -> lower, 62 -> higher, 68 -> higher, 71 -> lower, 69 -> higher, 70.
+        let answers = "h\nl\nh\nh\nl\nh\nc\n";
+        let result = game().play(Cursor::new(answers), Vec::new()).unwrap();
+
+        assert_eq!(
+            result,
+            ReverseResult::Guessed {
+                number: 70,
+                attempts: 7
+            }
+        );
+    }
+
+    #[test]
+    fn higher_at_the_upper_bound_is_a_contradiction() {
+        let mut game = game();
+        let contradiction = game.answer(100, Ordering::Less).unwrap_err();
+
+        assert_eq!(contradiction.earlier, None);
+        assert_eq!(
+            contradiction.to_string(),
+            "You said your number is higher than 100, but 100 is the largest number allowed."
+        );
+    }
+
+    #[test]
+    fn points_out_the_earlier_answer_that_conflicts() {
+        // 50 -> lower, 25 -> higher, 37 -> higher, 43 -> higher, 46 -> higher, 48 -> higher, 49 -> higher: impossible.
+        let answers = "l\nh\nh\nh\nh\nh\nh\n";
+        let result = game().play(Cursor::new(answers), Vec::new()).unwrap();
+
+        assert_eq!(
+            result,
+            ReverseResult::Cheated(Contradiction {
+                guess: 49,
+                answer: Ordering::Less,
+                earlier: Some(50),
+                min: 1,
+                max: 100,
+            })
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::difficulty::GameConfig;
+use super::engine::{GameResult, GuessingGame};
+
+/// A way of picking the next guess for the bot.
+pub trait Strategy {
+    fn name(&self) -> &'static str;
+
+    /// The next guess, knowing the secret number is somewhere in `low..=high`.
+    fn next_guess(&mut self, low: u32, high: u32) -> u32;
+}
+
+/// Always guesses the middle of what's left, halving the range every time.
+pub struct BinarySearch;
+
+impl Strategy for BinarySearch {
+    fn name(&self) -> &'static str {
+        "binary"
+    }
+
+    fn next_guess(&mut self, low: u32, high: u32) -> u32 {
+        low + (high - low) / 2
+    }
+}
+
+/// Guesses a random number among those that are still possible.
+pub struct RandomProbe<R: Rng> {
+    rng: R,
+}
+
+impl<R: Rng> RandomProbe<R> {
+    pub fn new(rng: R) -> RandomProbe<R> {
+        RandomProbe { rng }
+    }
+}
+
+impl<R: Rng> Strategy for RandomProbe<R> {
+    fn name(&self) -> &'static str {
+        "random"
+    }
+
+    fn next_guess(&mut self, low: u32, high: u32) -> u32 {
+        self.rng.gen_range(low..=high)
+    }
+}
+
+/// Counts up from the bottom of the range, one number at a time. Intentionally naive.
+pub struct LinearScan;
+
+impl Strategy for LinearScan {
+    fn name(&self) -> &'static str {
+        "linear"
+    }
+
+    fn next_guess(&mut self, low: u32, _high: u32) -> u32 {
+        low
+    }
+}
+
+/// The strategy names [`by_name`] understands, in the order they are reported.
+pub const STRATEGIES: &[&str] = &["binary", "random", "linear"];
+
+/// Builds the strategy called `name`; `seed` feeds the ones that need randomness.
+pub fn by_name(name: &str, seed: u64) -> Option<Box<dyn Strategy>> {
+    match name {
+        "binary" => Some(Box::new(BinarySearch)),
+        "random" => Some(Box::new(RandomProbe::new(StdRng::seed_from_u64(seed)))),
+        "linear" => Some(Box::new(LinearScan)),
+        _ => None,
+    }
+}
+
+/// Lets `strategy` play `game` to the end, narrowing the range with each guess's feedback.
+pub fn solve(game: &mut GuessingGame, strategy: &mut dyn Strategy) -> GameResult {
+    let (mut low, mut high) = (*game.range().start(), *game.range().end());
+
+    loop {
+        let guess = strategy.next_guess(low, high);
+        let outcome = game.guess(guess);
+
+        match outcome.ordering() {
+            Ordering::Less => low = guess + 1,
+            Ordering::Greater => high = guess - 1,
+            Ordering::Equal => {
+                return GameResult::Won {
+                    attempts: game.attempts(),
+                }
+            }
+        }
+
+        if game.remaining_attempts() == Some(0) {
+            return GameResult::Lost {
+                attempts: game.attempts(),
+                secret_number: game.secret_number(),
+            };
+        }
+    }
+}
+
+/// Summary of how many attempts a strategy needed over many games.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub games: usize,
+    /// Games that hit the attempt limit; they are left out of the figures below.
+    pub losses: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub worst: u32,
+}
+
+impl Stats {
+    pub fn from_attempts(mut attempts: Vec<u32>, losses: usize) -> Stats {
+        attempts.sort_unstable();
+
+        let wins = attempts.len();
+        let mean = if wins == 0 {
+            0.0
+        } else {
+            attempts.iter().map(|&a| f64::from(a)).sum::<f64>() / wins as f64
+        };
+        let median = match wins {
+            0 => 0.0,
+            n if n % 2 == 1 => f64::from(attempts[n / 2]),
+            n => f64::from(attempts[n / 2 - 1] + attempts[n / 2]) / 2.0,
+        };
+
+        Stats {
+            games: wins + losses,
+            losses,
+            mean,
+            median,
+            worst: attempts.last().copied().unwrap_or(0),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:>7} {:>8.2} {:>8.1} {:>6} {:>7}",
+            self.games, self.mean, self.median, self.worst, self.losses
+        )
+    }
+}
+
+/// Plays `games` games with secrets drawn from `rng` and collects the attempts `strategy` needed.
+pub fn simulate<R: Rng + ?Sized>(
+    strategy: &mut dyn Strategy,
+    config: &GameConfig,
+    games: usize,
+    rng: &mut R,
+) -> Stats {
+    let mut attempts = Vec::with_capacity(games);
+    let mut losses = 0;
+
+    for _ in 0..games {
+        let mut game = GuessingGame::with_config(rng, config);
+        match solve(&mut game, strategy) {
+            GameResult::Won { attempts: taken } => attempts.push(taken),
+            _ => losses += 1,
+        }
+    }
+
+    Stats::from_attempts(attempts, losses)
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::{simulate, solve, BinarySearch, LinearScan, RandomProbe, Stats};
+    use crate::guessing_game::{Difficulty, GameConfig, GameResult, GuessingGame};
+
+    #[test]
+    fn binary_search_never_needs_more_than_seven_guesses_for_one_to_a_hundred() {
+        let config = GameConfig::default();
+
+        for secret in 1..=100 {
+            let mut game = GuessingGame::with_secret(secret, &config);
+            match solve(&mut game, &mut BinarySearch) {
+                GameResult::Won { attempts } => {
+                    assert!(attempts <= 7, "{} took {}", secret, attempts)
+                }
+                other => panic!("binary search did not win: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn linear_scan_needs_as_many_guesses_as_the_secret() {
+        let mut game = GuessingGame::with_secret(37, &GameConfig::default());
+        assert_eq!(
+            solve(&mut game, &mut LinearScan),
+            GameResult::Won { attempts: 37 }
+        );
+    }
+
+    #[test]
+    fn strategies_lose_when_they_run_out_of_attempts() {
+        let config = GameConfig {
+            difficulty: Difficulty::Normal,
+            max_attempts: Some(5),
+        };
+
+        let stats = simulate(&mut LinearScan, &config, 50, &mut StdRng::seed_from_u64(1));
+        assert_eq!(stats.games, 50);
+        assert!(stats.losses > 0);
+        assert!(stats.worst <= 5);
+    }
+
+    #[test]
+    fn random_probe_always_finds_the_number() {
+        let mut probe = RandomProbe::new(StdRng::seed_from_u64(3));
+        let stats = simulate(
+            &mut probe,
+            &GameConfig::default(),
+            200,
+            &mut StdRng::seed_from_u64(4),
+        );
+        assert_eq!(stats.losses, 0);
+    }
+
+    #[test]
+    fn median_of_an_even_number_of_games_averages_the_middle_two() {
+        let stats = Stats::from_attempts(vec![7, 1, 3, 5], 0);
+        assert_eq!(stats.median, 4.0);
+        assert_eq!(stats.mean, 4.0);
+        assert_eq!(stats.worst, 7);
+    }
+}
@@ -0,0 +1,435 @@
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use super::difficulty::GameConfig;
+use super::engine::{GameResult, GuessingGame, Rounds};
+use super::hints;
+
+/// One line the player typed, when they typed it and what the game answered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    /// Time since the session started.
+    pub elapsed: Duration,
+    pub input: String,
+    pub feedback: String,
+}
+
+/// Everything needed to reproduce a game: the seed and settings it was started with, every
+/// line of input and the feedback it got, and how the game ended.
+#[derive(Debug, Clone)]
+pub struct Transcript {
+    pub seed: u64,
+    pub config: GameConfig,
+    /// Hint names as understood by [`hints::by_name`].
+    pub hints: Vec<String>,
+    /// Seconds since the Unix epoch when the session started.
+    pub started: u64,
+    pub entries: Vec<Entry>,
+    /// `None` while the game is still running.
+    pub result: Option<GameResult>,
+    clock: Instant,
+}
+
+impl Transcript {
+    pub fn new(seed: u64, config: GameConfig, hints: Vec<String>, started: u64) -> Transcript {
+        Transcript {
+            seed,
+            config,
+            hints,
+            started,
+            entries: Vec::new(),
+            result: None,
+            clock: Instant::now(),
+        }
+    }
+
+    /// A fresh game set up exactly like the recorded one.
+    pub fn game(&self) -> Result<GuessingGame, String> {
+        let mut game = GuessingGame::with_seed(self.seed, &self.config);
+        for name in &self.hints {
+            game.add_hint(hints::by_name(name)?);
+        }
+        Ok(game)
+    }
+
+    pub fn push(&mut self, input: &str, feedback: &str) {
+        self.entries.push(Entry {
+            elapsed: self.clock.elapsed(),
+            input: input.trim_end_matches(&['\r', '\n'][..]).to_string(),
+            feedback: feedback.to_string(),
+        });
+    }
+
+    pub fn finish(&mut self, result: GameResult) {
+        self.result = Some(result);
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_text())
+    }
+
+    pub fn load(path: &Path) -> io::Result<Transcript> {
+        let contents = fs::read_to_string(path)?;
+
+        Transcript::from_text(&contents).map_err(|(number, message)| {
+            let place = match number {
+                Some(number) => format!("{} line {}", path.display(), number),
+                None => path.display().to_string(),
+            };
+            io::Error::new(ErrorKind::InvalidData, format!("{}: {}", place, message))
+        })
+    }
+
+    /// One tab-separated `key value...` line per setting, guess and the outcome. Inputs and
+    /// feedback are escaped so tabs and newlines in them don't break the format.
+    fn to_text(&self) -> String {
+        let mut text = String::from("# guessing game transcript\n");
+        text += &format!("seed\t{}\n", self.seed);
+        text += &format!("difficulty\t{}\n", self.config.difficulty);
+        text += &format!(
+            "max-attempts\t{}\n",
+            self.config
+                .max_attempts
+                .map_or_else(|| String::from("-"), |max| max.to_string())
+        );
+        text += &format!("hints\t{}\n", self.hints.join(","));
+        text += &format!("started\t{}\n", self.started);
+
+        for entry in &self.entries {
+            text += &format!(
+                "guess\t{}\t{}\t{}\n",
+                entry.elapsed.as_millis(),
+                escape(&entry.input),
+                escape(&entry.feedback)
+            );
+        }
+
+        match self.result {
+            Some(GameResult::Won { attempts }) => text += &format!("outcome\twon\t{}\n", attempts),
+            Some(GameResult::Lost {
+                attempts,
+                secret_number,
+            }) => text += &format!("outcome\tlost\t{}\t{}\n", attempts, secret_number),
+            Some(GameResult::CodeLost { attempts, code }) => {
+                text += &format!("outcome\tlost\t{}\t{}\n", attempts, code)
+            }
+            Some(GameResult::OutOfTime {
+                attempts,
+                secret_number,
+            }) => text += &format!("outcome\tout-of-time\t{}\t{}\n", attempts, secret_number),
+            Some(GameResult::Abandoned { attempts }) => {
+                text += &format!("outcome\tabandoned\t{}\n", attempts)
+            }
+            None => (),
+        }
+
+        text
+    }
+
+    /// Errors carry the 1-based line number they were found on, if it was on a line.
+    fn from_text(text: &str) -> Result<Transcript, (Option<usize>, String)> {
+        let mut transcript = Transcript::new(0, GameConfig::default(), Vec::new(), 0);
+        let mut seeded = false;
+
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            transcript
+                .parse_line(line)
+                .map_err(|message| (Some(index + 1), message))?;
+            seeded |= line.starts_with("seed\t");
+        }
+
+        // Without its seed the game can't be set up again, and seed 0 would be a different one.
+        if !seeded {
+            return Err((None, String::from("there is no `seed` line")));
+        }
+        Ok(transcript)
+    }
+
+    fn parse_line(&mut self, line: &str) -> Result<(), String> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        let field = |index: usize| -> Result<&str, String> {
+            fields
+                .get(index)
+                .copied()
+                .ok_or_else(|| format!("`{}` is missing field {}", fields[0], index))
+        };
+        let number = |index: usize| -> Result<u64, String> {
+            let value = field(index)?;
+            value
+                .parse()
+                .map_err(|_| format!("`{}` is not a number", value))
+        };
+        // Attempts and guesses are `u32`s: a larger value is an error, not something to wrap.
+        let number_u32 = |index: usize| -> Result<u32, String> {
+            let value = field(index)?;
+            value
+                .parse()
+                .map_err(|_| format!("`{}` is not a number from 0 to {}", value, u32::MAX))
+        };
+
+        match fields[0] {
+            "seed" => self.seed = number(1)?,
+            "difficulty" => self.config.difficulty = field(1)?.parse()?,
+            "max-attempts" => {
+                self.config.max_attempts = match field(1)? {
+                    "-" => None,
+                    _ => match number_u32(1)? {
+                        0 => return Err(String::from("a game needs at least 1 attempt")),
+                        max => Some(max),
+                    },
+                }
+            }
+            "hints" => {
+                self.hints = Vec::new();
+                for name in field(1)?.split(',').filter(|name| !name.is_empty()) {
+                    hints::by_name(name)?;
+                    self.hints.push(name.to_string());
+                }
+            }
+            "started" => self.started = number(1)?,
+            "guess" => self.entries.push(Entry {
+                elapsed: Duration::from_millis(number(1)?),
+                input: unescape(field(2)?),
+                feedback: unescape(field(3)?),
+            }),
+            "outcome" => {
+                let attempts = number_u32(2)?;
+                self.result = Some(match field(1)? {
+                    "won" => GameResult::Won { attempts },
+                    "lost" => GameResult::Lost {
+                        attempts,
+                        secret_number: number_u32(3)?,
+                    },
+                    "out-of-time" => GameResult::OutOfTime {
+                        attempts,
+                        secret_number: number_u32(3)?,
+                    },
+                    "abandoned" => GameResult::Abandoned { attempts },
+                    other => return Err(format!("`{}` is not an outcome", other)),
+                });
+            }
+            other => return Err(format!("unknown entry `{}`", other)),
+        }
+
+        Ok(())
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+
+    unescaped
+}
+
+/// A recorded guess whose feedback came out differently the second time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    /// 1-based position among the recorded guesses (not a line of the file: the settings come
+    /// first).
+    pub guess: usize,
+    pub input: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// What re-running a transcript produced.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replay {
+    /// The feedback for every recorded line, as the engine gives it now.
+    pub feedback: Vec<String>,
+    pub mismatches: Vec<Mismatch>,
+    pub result: GameResult,
+}
+
+/// Feeds every recorded line to a fresh game set up like the recorded one.
+pub fn replay(transcript: &Transcript) -> Result<Replay, String> {
+    let mut game = transcript.game()?;
+    let mut replay = Replay {
+        feedback: Vec::new(),
+        mismatches: Vec::new(),
+        result: GameResult::Abandoned { attempts: 0 },
+    };
+    let mut finished = None;
+
+    for (index, entry) in transcript.entries.iter().enumerate() {
+        let actual = match finished {
+            Some(_) => String::from("(the game was already over)\n"),
+            None => {
+                let mut feedback = Vec::new();
+                finished = game
+                    .respond(&entry.input, &mut feedback)
+                    .map_err(|error| error.to_string())?;
+                String::from_utf8_lossy(&feedback).into_owned()
+            }
+        };
+
+        if actual != entry.feedback {
+            replay.mismatches.push(Mismatch {
+                guess: index + 1,
+                input: entry.input.clone(),
+                expected: entry.feedback.clone(),
+                actual: actual.clone(),
+            });
+        }
+        replay.feedback.push(actual);
+    }
+
+    replay.result = finished.unwrap_or(GameResult::Abandoned {
+        attempts: game.attempts(),
+    });
+    Ok(replay)
+}
+
+impl Replay {
+    /// Same feedback for every line and the same outcome as recorded.
+    pub fn matches(&self, transcript: &Transcript) -> bool {
+        self.mismatches.is_empty() && transcript.result == Some(self.result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::{escape, replay, unescape, Transcript};
+    use crate::guessing_game::{Difficulty, GameConfig, GameResult};
+
+    fn recorded(input: &str) -> Transcript {
+        let config = GameConfig {
+            difficulty: Difficulty::Easy,
+            max_attempts: Some(3),
+        };
+        let transcript = Transcript::new(7, config, vec![String::from("warmer")], 1_600_000_000);
+        let mut game = transcript.game().unwrap();
+        game.record(transcript);
+        game.play(Cursor::new(input), Vec::new()).unwrap();
+        game.take_transcript().unwrap()
+    }
+
+    #[test]
+    fn records_every_line_with_its_feedback_and_the_outcome() {
+        let transcript = recorded("five\n11\n");
+
+        assert_eq!(transcript.entries.len(), 2);
+        assert_eq!(transcript.entries[0].input, "five");
+        assert_eq!(transcript.entries[0].feedback, "`five` is not a number.\n");
+        assert_eq!(
+            transcript.result,
+            Some(GameResult::Abandoned { attempts: 0 })
+        );
+    }
+
+    #[test]
+    fn survives_a_round_trip_through_text() {
+        let transcript = recorded("1\n\t2\n10\n");
+        let loaded = Transcript::from_text(&transcript.to_text()).unwrap();
+
+        assert_eq!(loaded.seed, 7);
+        assert_eq!(loaded.config, transcript.config);
+        assert_eq!(loaded.hints, transcript.hints);
+        assert_eq!(loaded.started, transcript.started);
+        // Times are stored in whole milliseconds, so only the text is compared exactly.
+        let text = |transcript: &Transcript| -> Vec<(String, String)> {
+            transcript
+                .entries
+                .iter()
+                .map(|entry| (entry.input.clone(), entry.feedback.clone()))
+                .collect()
+        };
+        assert_eq!(text(&loaded), text(&transcript));
+        assert_eq!(loaded.result, transcript.result);
+    }
+
+    #[test]
+    fn replaying_a_transcript_gives_the_same_feedback() {
+        let transcript = recorded("3\n8\n6\n");
+        let replayed = replay(&transcript).unwrap();
+
+        assert!(replayed.matches(&transcript));
+    }
+
+    #[test]
+    fn replay_points_at_the_first_guess_that_differs() {
+        let mut transcript = recorded("3\n8\n6\n");
+        transcript.entries[1].feedback = String::from("Too big!\n");
+
+        let replayed = replay(&transcript).unwrap();
+
+        assert!(!replayed.matches(&transcript));
+        assert_eq!(replayed.mismatches.len(), 1);
+        assert_eq!(replayed.mismatches[0].guess, 2);
+    }
+
+    #[test]
+    fn reports_the_line_of_a_broken_entry() {
+        assert_eq!(
+            Transcript::from_text("seed\t1\nguess\tsoon\t5\t\n")
+                .unwrap_err()
+                .0,
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn out_of_range_numbers_are_errors_not_wrapped() {
+        let error = |text: &str| Transcript::from_text(text).unwrap_err();
+        assert_eq!(
+            error("seed\t1\nmax-attempts\t4294967297\n"),
+            (
+                Some(2),
+                format!("`4294967297` is not a number from 0 to {}", u32::MAX)
+            )
+        );
+        assert_eq!(error("seed\t1\noutcome\twon\t4294967296\n").0, Some(2));
+        assert_eq!(error("seed\t1\noutcome\tlost\t3\t-1\n").0, Some(2));
+    }
+
+    #[test]
+    fn settings_a_game_cannot_have_are_errors() {
+        let error = |text: &str| Transcript::from_text(text).unwrap_err();
+        assert_eq!(
+            error("difficulty\teasy\nguess\t5\t1\tToo small!\\n\n"),
+            (None, String::from("there is no `seed` line"))
+        );
+        assert_eq!(
+            error("seed\t1\nmax-attempts\t0\n"),
+            (Some(2), String::from("a game needs at least 1 attempt"))
+        );
+        assert!(Transcript::from_text("seed\t1\nmax-attempts\t-\n").is_ok());
+    }
+
+    #[test]
+    fn escaping_round_trips() {
+        let text = "a\tb\\n\nc";
+        assert_eq!(unescape(&escape(text)), text);
+        assert!(!escape(text).contains(&['\t', '\n'][..]));
+    }
+}
@@ -0,0 +1,353 @@
+//! What a value looks like in memory: a generalization of chapter 3's `print_type_of`.
+//!
+//! Use the [`inspect!`](crate::inspect!) macro on any value. Types that implement [`Inspect`]
+//! also show their bytes; for the rest (`String`, references, your own structs) the report
+//! stops at the layout:
+//!
+//! ```
+//! use the_rust_programming_language::inspect;
+//!
+//! let report = inspect!(500i32);
+//! assert_eq!(report.type_name, "i32");
+//! assert_eq!((report.size, report.align), (4, 4));
+//! assert!(report.is_copy && !report.needs_drop);
+//!
+//! let report = inspect!(String::from("hello"));
+//! assert!(!report.is_copy && report.needs_drop);
+//! assert_eq!(report.bytes, None);
+//! ```
+
+use std::any;
+use std::fmt;
+use std::io::{self, Write};
+use std::marker::PhantomData;
+use std::mem;
+
+/// Types whose bytes can be shown.
+///
+/// Reading padding is undefined behaviour, so instead of copying raw memory every type lists
+/// its own bytes, field by field, and leaves the gaps between fields as `None`.
+pub trait Inspect {
+    /// `size_of::<Self>()` bytes in memory order; `None` marks padding.
+    fn bytes(&self) -> Vec<Option<u8>>;
+}
+
+macro_rules! inspect_with_ne_bytes {
+    ($($t:ty),*) => {
+        $(
+            impl Inspect for $t {
+                fn bytes(&self) -> Vec<Option<u8>> {
+                    self.to_ne_bytes().iter().copied().map(Some).collect()
+                }
+            }
+        )*
+    };
+}
+
+inspect_with_ne_bytes!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl Inspect for char {
+    fn bytes(&self) -> Vec<Option<u8>> {
+        u32::from(*self).bytes()
+    }
+}
+
+impl Inspect for bool {
+    fn bytes(&self) -> Vec<Option<u8>> {
+        vec![Some(u8::from(*self))]
+    }
+}
+
+impl Inspect for () {
+    fn bytes(&self) -> Vec<Option<u8>> {
+        Vec::new()
+    }
+}
+
+impl<T: Inspect, const N: usize> Inspect for [T; N] {
+    fn bytes(&self) -> Vec<Option<u8>> {
+        // Elements are `size_of::<T>()` apart, padding included, so they just follow each other.
+        self.iter().flat_map(Inspect::bytes).collect()
+    }
+}
+
+/// Where `field` starts inside `whole`, in bytes.
+fn offset_of<W, F>(whole: &W, field: &F) -> usize {
+    field as *const F as usize - whole as *const W as usize
+}
+
+macro_rules! inspect_tuple {
+    ($($name:ident . $index:tt),+) => {
+        impl<$($name: Inspect),+> Inspect for ($($name,)+) {
+            fn bytes(&self) -> Vec<Option<u8>> {
+                // The compiler may reorder tuple fields, so each one goes where it actually is.
+                let mut bytes = vec![None; mem::size_of::<Self>()];
+                $(
+                    let offset = offset_of(self, &self.$index);
+                    for (i, byte) in self.$index.bytes().into_iter().enumerate() {
+                        bytes[offset + i] = byte;
+                    }
+                )+
+                bytes
+            }
+        }
+    };
+}
+
+inspect_tuple!(A.0);
+inspect_tuple!(A.0, B.1);
+inspect_tuple!(A.0, B.1, C.2);
+inspect_tuple!(A.0, B.1, C.2, D.3);
+inspect_tuple!(A.0, B.1, C.2, D.3, E.4);
+inspect_tuple!(A.0, B.1, C.2, D.3, E.4, F.5);
+
+/// Everything [`inspect!`](crate::inspect!) reports about a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inspection {
+    pub type_name: &'static str,
+    pub size: usize,
+    pub align: usize,
+    pub is_copy: bool,
+    pub needs_drop: bool,
+    /// See [`Inspect::bytes`]; `None` when the type doesn't implement it.
+    pub bytes: Option<Vec<Option<u8>>>,
+}
+
+impl Inspection {
+    /// Prefer [`inspect!`](crate::inspect!), which works out `is_copy` on its own.
+    pub fn new<T: Inspect>(value: &T, is_copy: bool) -> Inspection {
+        Inspection::layout(value, is_copy, Some(value.bytes()))
+    }
+
+    /// The report for any `T`, with whatever bytes could be found out.
+    pub fn layout<T>(_: &T, is_copy: bool, bytes: Option<Vec<Option<u8>>>) -> Inspection {
+        Inspection {
+            type_name: any::type_name::<T>(),
+            size: mem::size_of::<T>(),
+            align: mem::align_of::<T>(),
+            is_copy,
+            needs_drop: mem::needs_drop::<T>(),
+            bytes,
+        }
+    }
+
+    /// The bytes as hex pairs, with `__` for padding, or `?` when they aren't known.
+    pub fn hex(&self) -> String {
+        match &self.bytes {
+            Some(bytes) => bytes
+                .iter()
+                .map(|byte| match byte {
+                    Some(byte) => format!("{:02x}", byte),
+                    None => String::from("__"),
+                })
+                .collect::<Vec<String>>()
+                .join(" "),
+            None => String::from("?"),
+        }
+    }
+}
+
+impl fmt::Display for Inspection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: size {}, align {}, {}, {}, bytes [{}]",
+            self.type_name,
+            self.size,
+            self.align,
+            if self.is_copy { "Copy" } else { "not Copy" },
+            if self.needs_drop {
+                "needs drop"
+            } else {
+                "no drop"
+            },
+            self.hex()
+        )
+    }
+}
+
+/// Tells `Copy` types apart without specialization: for a concrete `T`, method lookup finds
+/// the inherent `is_copy` (which needs `T: Copy`) before falling back to [`NotCopy`]'s.
+/// This only works where the type is known, which is why [`inspect!`](crate::inspect!) is a macro.
+#[doc(hidden)]
+pub struct CopyProbe<T>(PhantomData<T>);
+
+#[doc(hidden)]
+pub fn copy_probe<T>(_: &T) -> CopyProbe<T> {
+    CopyProbe(PhantomData)
+}
+
+impl<T: Copy> CopyProbe<T> {
+    pub fn is_copy(&self) -> bool {
+        true
+    }
+}
+
+#[doc(hidden)]
+pub trait NotCopy {
+    fn is_copy(&self) -> bool {
+        false
+    }
+}
+
+impl<T> NotCopy for CopyProbe<T> {}
+
+/// The same trick for the bytes: the inherent `bytes` needs `T: Inspect`, and [`NoBytes`]
+/// answers `None` for every other type.
+#[doc(hidden)]
+pub struct BytesProbe<'a, T>(&'a T);
+
+#[doc(hidden)]
+pub fn bytes_probe<T>(value: &T) -> BytesProbe<'_, T> {
+    BytesProbe(value)
+}
+
+impl<'a, T: Inspect> BytesProbe<'a, T> {
+    pub fn bytes(&self) -> Option<Vec<Option<u8>>> {
+        Some(self.0.bytes())
+    }
+}
+
+#[doc(hidden)]
+pub trait NoBytes {
+    fn bytes(&self) -> Option<Vec<Option<u8>>> {
+        None
+    }
+}
+
+impl<'a, T> NoBytes for BytesProbe<'a, T> {}
+
+/// Inspects a value: its type name, size, alignment, whether it is `Copy` and needs drop,
+/// and its bytes if its type implements [`Inspect`]. See the [module docs](crate::inspect).
+#[macro_export]
+macro_rules! inspect {
+    ($value:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::inspect::{NoBytes as _, NotCopy as _};
+        let value = &$value;
+        $crate::inspect::Inspection::layout(
+            value,
+            $crate::inspect::copy_probe(value).is_copy(),
+            $crate::inspect::bytes_probe(value).bytes(),
+        )
+    }};
+}
+
+/// Every type chapter 3 introduces, with the example values the chapter uses where it has one.
+pub fn chapter_3_types() -> Vec<(&'static str, Inspection)> {
+    vec![
+        ("42", inspect!(42i8)),
+        ("42", inspect!(42i16)),
+        ("42", inspect!(42i32)),
+        ("42", inspect!(42i64)),
+        ("42", inspect!(42i128)),
+        ("42", inspect!(42isize)),
+        ("42", inspect!(42u8)),
+        ("42", inspect!(42u16)),
+        ("42", inspect!(42u32)),
+        ("42", inspect!(42u64)),
+        ("42", inspect!(42u128)),
+        ("42", inspect!(42usize)),
+        ("3.0", inspect!(3.0f32)),
+        ("2.0", inspect!(2.0f64)),
+        ("'😻'", inspect!('😻')),
+        ("true", inspect!(true)),
+        ("()", inspect!(())),
+        ("(500, 6.4, 1)", inspect!((500i32, 6.4f64, 1u8))),
+        ("[3; 5]", inspect!([3i32; 5])),
+    ]
+}
+
+/// A table of [`chapter_3_types`].
+pub fn render_table(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(
+        out,
+        "{:<16} {:<14} {:>4} {:>5} {:>5} {:>5}  bytes (native endian, __ = padding)",
+        "type", "value", "size", "align", "Copy", "drop"
+    )?;
+
+    let yes_no = |flag: bool| if flag { "yes" } else { "no" };
+    for (value, inspection) in chapter_3_types() {
+        writeln!(
+            out,
+            "{:<16} {:<14} {:>4} {:>5} {:>5} {:>5}  {}",
+            inspection.type_name,
+            value,
+            inspection.size,
+            inspection.align,
+            yes_no(inspection.is_copy),
+            yes_no(inspection.needs_drop),
+            inspection.hex()
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Inspect;
+
+    #[test]
+    fn scalars_report_their_layout() {
+        let report = inspect!(1u16);
+        assert_eq!(report.type_name, "u16");
+        assert_eq!((report.size, report.align), (2, 2));
+        assert_eq!(report.bytes, Some(1u16.to_ne_bytes().map(Some).to_vec()));
+        assert_eq!(
+            inspect!('A').bytes,
+            Some(65u32.to_ne_bytes().map(Some).to_vec())
+        );
+    }
+
+    #[test]
+    fn tells_copy_types_from_the_rest() {
+        assert!(inspect!(5i32).is_copy);
+        assert!(inspect!((1u8, 'x')).is_copy);
+
+        // `String` doesn't implement `Inspect`, so the probe is used directly.
+        use super::NotCopy;
+        assert!(!super::copy_probe(&[String::new()]).is_copy());
+        assert!(super::copy_probe(&[1u8]).is_copy());
+    }
+
+    #[test]
+    fn tuples_show_padding_where_the_compiler_put_it() {
+        let tuple = (500i32, 6.4f64, 1u8);
+        let bytes = tuple.bytes();
+
+        assert_eq!(bytes.len(), std::mem::size_of::<(i32, f64, u8)>());
+        assert_eq!(bytes.iter().filter(|byte| byte.is_none()).count(), 16 - 13);
+    }
+
+    #[test]
+    fn arrays_repeat_their_elements() {
+        assert_eq!([1u8, 2, 3].bytes(), vec![Some(1), Some(2), Some(3)]);
+        assert_eq!(inspect!([7u8; 0]).bytes, Some(Vec::new()));
+    }
+
+    #[test]
+    fn other_types_report_their_layout_only() {
+        let report = inspect!(String::from("hello"));
+        assert_eq!(report.type_name, "alloc::string::String");
+        assert_eq!(report.size, 3 * std::mem::size_of::<usize>());
+        assert!(!report.is_copy && report.needs_drop);
+        assert_eq!((&report.bytes, report.hex()), (&None, String::from("?")));
+
+        #[derive(Clone, Copy)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+        let report = inspect!(Point { x: 1, y: 2 });
+        assert!(report.type_name.ends_with("Point"));
+        assert_eq!((report.size, report.align), (8, 4));
+        assert!(report.is_copy && !report.needs_drop);
+        assert_eq!(report.bytes, None);
+
+        let report = inspect!("hi");
+        assert_eq!(report.type_name, "&str");
+        assert!(report.is_copy);
+        assert!(inspect!(vec![1u8]).needs_drop);
+    }
+}
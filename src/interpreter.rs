@@ -0,0 +1,520 @@
+//! A tiny Rust-like expression language with chapter 3's rules: `let` bindings that shadow
+//! (and may change type while doing so), `mut` bindings that may not, blocks whose value is
+//! their last expression without a semicolon, and `if` as an expression whose branches must
+//! have the same type.
+//!
+//! Like `rustc`, it checks types before running anything, so `if condition { 5 } else { "six" }`
+//! is rejected whatever `condition` turns out to be.
+//!
+//! ```
+//! use the_rust_programming_language::interpreter::{Session, Type};
+//!
+//! let mut session = Session::new();
+//! session.run(r#"let spaces = "    "; let spaces = spaces.len();"#).unwrap();
+//! let value = session.run("spaces").unwrap().value.unwrap();
+//! assert_eq!((value.to_string(), value.ty()), (String::from("4"), Type::Usize));
+//! ```
+
+mod check;
+mod eval;
+mod lexer;
+mod parser;
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+pub use eval::Value;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Type {
+    I32,
+    Usize,
+    /// An integer literal whose type isn't decided yet; becomes `i32` unless something says
+    /// otherwise, as in Rust.
+    Integer,
+    F64,
+    Bool,
+    Str,
+    Unit,
+}
+
+impl Type {
+    pub fn is_integer(&self) -> bool {
+        matches!(self, Type::I32 | Type::Usize | Type::Integer)
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.is_integer() || *self == Type::F64
+    }
+
+    /// The type two operands share, if they can share one: an integer literal takes the type
+    /// of the other side.
+    pub fn unify(self, other: Type) -> Option<Type> {
+        match (self, other) {
+            (a, b) if a == b => Some(a),
+            (Type::Integer, b) if b.is_integer() => Some(b),
+            (a, Type::Integer) if a.is_integer() => Some(a),
+            _ => None,
+        }
+    }
+
+    /// The type a binding gets: an undecided integer becomes `i32`.
+    pub fn resolved(self) -> Type {
+        match self {
+            Type::Integer => Type::I32,
+            ty => ty,
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Type::I32 => "i32",
+            Type::Usize => "usize",
+            Type::Integer => "{integer}",
+            Type::F64 => "f64",
+            Type::Bool => "bool",
+            Type::Str => "&str",
+            Type::Unit => "()",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input isn't a program at all.
+    Syntax(String),
+    /// The program doesn't type-check; nothing was run.
+    Type(String),
+    /// The program panicked while running, e.g. on overflow.
+    Runtime(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Syntax(message) => write!(f, "syntax error: {}", message),
+            Error::Type(message) => write!(f, "type error: {}", message),
+            Error::Runtime(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn mismatched(expected: Type, found: Type) -> Error {
+    Error::Type(format!(
+        "mismatched types: expected `{}`, found `{}`",
+        expected, found
+    ))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Binding<T> {
+    pub name: String,
+    pub value: T,
+    pub mutable: bool,
+}
+
+/// Nested scopes of bindings. A `let` adds a binding rather than replacing one, which is all
+/// shadowing is: lookups find the newest binding with the name, and leaving a block drops
+/// every binding made in it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scopes<T> {
+    scopes: Vec<Vec<Binding<T>>>,
+}
+
+impl<T> Scopes<T> {
+    pub fn new() -> Scopes<T> {
+        Scopes {
+            scopes: vec![Vec::new()],
+        }
+    }
+
+    pub fn push(&mut self) {
+        self.scopes.push(Vec::new());
+    }
+
+    pub fn pop(&mut self) {
+        self.scopes.pop();
+    }
+
+    pub fn define(&mut self, name: &str, value: T, mutable: bool) {
+        self.scopes
+            .last_mut()
+            .expect("there is always a scope")
+            .push(Binding {
+                name: name.to_string(),
+                value,
+                mutable,
+            });
+    }
+
+    pub fn lookup(&self, name: &str) -> Option<&Binding<T>> {
+        self.scopes
+            .iter()
+            .rev()
+            .flat_map(|scope| scope.iter().rev())
+            .find(|binding| binding.name == name)
+    }
+
+    pub fn lookup_mut(&mut self, name: &str) -> Option<&mut Binding<T>> {
+        self.scopes
+            .iter_mut()
+            .rev()
+            .flat_map(|scope| scope.iter_mut().rev())
+            .find(|binding| binding.name == name)
+    }
+
+    /// Every binding of the outermost scope, shadowed ones included, oldest first.
+    pub fn globals(&self) -> &[Binding<T>] {
+        &self.scopes[0]
+    }
+}
+
+impl<T> Default for Scopes<T> {
+    fn default() -> Scopes<T> {
+        Scopes::new()
+    }
+}
+
+/// What running one input did.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outcome {
+    /// The top-level bindings the input added, in order.
+    pub bindings: Vec<Binding<Value>>,
+    /// The value of the final expression, if the input ended with one.
+    pub value: Option<Value>,
+}
+
+/// Top-level bindings that live from one input to the next, as in a REPL.
+#[derive(Debug, Clone, Default)]
+pub struct Session {
+    types: Scopes<Type>,
+    values: Scopes<Value>,
+}
+
+impl Session {
+    pub fn new() -> Session {
+        Session::default()
+    }
+
+    /// Parses, type-checks and runs `source`. Nothing it binds is kept if any step fails.
+    pub fn run(&mut self, source: &str) -> Result<Outcome, Error> {
+        let program = parser::parse(&lexer::tokenize(source)?)?;
+
+        let mut types = self.types.clone();
+        check::statements(&program, &mut types)?;
+
+        let mut values = self.values.clone();
+        let before = values.globals().len();
+        let value = eval::statements(&program, &mut values, Type::I32)?;
+        let value = match program.tail {
+            // Nothing is left to decide an integer's type, so it is an `i32`.
+            Some(_) => Some(eval::settle(value.clone(), value.ty().resolved())?),
+            None => None,
+        };
+
+        let bindings = values.globals()[before..].to_vec();
+        self.types = types;
+        self.values = values;
+        Ok(Outcome { bindings, value })
+    }
+
+    pub fn globals(&self) -> &[Binding<Value>] {
+        self.values.globals()
+    }
+
+    /// `binding`, and the earlier global it shadows, if any.
+    fn describe(&self, binding: &Binding<Value>, index: usize) -> String {
+        let mut text = format!(
+            "{}{}: {} = {}",
+            if binding.mutable { "mut " } else { "" },
+            binding.name,
+            binding.value.ty(),
+            binding.value
+        );
+        let shadowed = self.globals()[..index]
+            .iter()
+            .rev()
+            .find(|earlier| earlier.name == binding.name);
+        if let Some(earlier) = shadowed {
+            text.push_str(&format!(
+                " (shadows {}: {})",
+                earlier.name,
+                earlier.value.ty()
+            ));
+        }
+        text
+    }
+}
+
+/// How many `{` are still open in `source`, ignoring braces in strings and comments.
+fn open_braces(source: &str) -> i64 {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut chars = source.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '"' => in_string = !in_string,
+            '/' if !in_string && chars.peek() == Some(&'/') => {
+                // Skip to the end of the line.
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '{' if !in_string => depth += 1,
+            '}' if !in_string => depth -= 1,
+            _ => (),
+        }
+    }
+
+    depth
+}
+
+/// Reads inputs line by line (continuing while a `{` is open) and prints what each one binds
+/// and evaluates to. `:env` lists the bindings, `:quit` or end of input stops.
+pub fn repl<R: BufRead, W: Write>(mut input: R, mut output: W) -> io::Result<()> {
+    writeln!(
+        output,
+        "Rust-like expressions: let, mut, blocks, if/else. :env lists bindings, :quit exits."
+    )?;
+    let mut session = Session::new();
+
+    loop {
+        write!(output, ">> ")?;
+        output.flush()?;
+
+        let mut source = String::new();
+        loop {
+            if input.read_line(&mut source)? == 0 {
+                return Ok(());
+            }
+            if open_braces(&source) <= 0 {
+                break;
+            }
+            write!(output, ".. ")?;
+            output.flush()?;
+        }
+
+        match source.trim() {
+            "" => continue,
+            ":quit" | ":q" => return Ok(()),
+            ":env" => {
+                for (index, binding) in session.globals().iter().enumerate() {
+                    writeln!(output, "{}", session.describe(binding, index))?;
+                }
+                continue;
+            }
+            _ => (),
+        }
+
+        let before = session.globals().len();
+        match session.run(&source) {
+            Ok(outcome) => {
+                for index in before..session.globals().len() {
+                    let binding = &session.globals()[index];
+                    writeln!(output, "{}", session.describe(binding, index))?;
+                }
+                if let Some(value) = outcome.value {
+                    writeln!(output, "{}: {}", value, value.ty())?;
+                }
+            }
+            Err(error) => writeln!(output, "{}", error)?,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::{parser, repl, Error, Session, Type};
+
+    fn run(source: &str) -> Result<String, Error> {
+        let value = Session::new()
+            .run(source)?
+            .value
+            .expect("a final expression");
+        Ok(format!("{}: {}", value, value.ty()))
+    }
+
+    #[test]
+    fn shadowing_can_change_the_type() {
+        assert_eq!(
+            run("let x = 5; let x = x + 1; let x = x * 2; x"),
+            Ok(String::from("12: i32"))
+        );
+        assert_eq!(
+            run(r#"let spaces = "    "; let spaces = spaces.len(); spaces"#),
+            Ok(String::from("4: usize"))
+        );
+        // Assigning to a `mut` binding can't change its type.
+        assert_eq!(
+            run(r#"let mut spaces = "   "; spaces = spaces.len(); spaces"#),
+            Err(Error::Type(String::from(
+                "mismatched types: expected `&str`, found `usize`"
+            )))
+        );
+        assert!(run("let x = 5; x = 6; x").is_err());
+    }
+
+    #[test]
+    fn a_block_is_worth_its_last_expression() {
+        assert_eq!(
+            run("let y = { let x = 3; x + 1 }; y"),
+            Ok(String::from("4: i32"))
+        );
+        assert_eq!(
+            run("let y = { let x = 3; x + 1; }; y"),
+            Ok(String::from("(): ()"))
+        );
+        // Bindings made inside a block end with it, and stop shadowing.
+        assert_eq!(
+            run("let x = 5; let y = { let x = \"inner\"; x.len() }; x + 1"),
+            Ok(String::from("6: i32"))
+        );
+        assert!(run("{ let z = 1; } z").is_err());
+    }
+
+    #[test]
+    fn if_branches_must_have_the_same_type() {
+        assert_eq!(
+            run("let condition = true; let number = if condition { 5 } else { 6 }; number"),
+            Ok(String::from("5: i32"))
+        );
+        // Rejected before running, although the `else` branch would never be taken.
+        assert_eq!(
+            run(r#"let condition = true; if condition { 5 } else { "six" }"#),
+            Err(Error::Type(String::from(
+                "`if` and `else` have incompatible types: expected `{integer}`, found `&str`"
+            )))
+        );
+        assert_eq!(
+            run("if 1 { 2 } else { 3 }"),
+            Err(Error::Type(String::from(
+                "mismatched types: expected `bool`, found `{integer}`"
+            )))
+        );
+        assert!(run("if true { 2 }").is_err());
+        assert_eq!(
+            run("let n = 6; if n % 4 == 0 { 4 } else if n % 3 == 0 { 3 } else { 1 }"),
+            Ok(String::from("3: i32"))
+        );
+    }
+
+    #[test]
+    fn integers_overflow_like_a_debug_build() {
+        assert_eq!(
+            run("let x = 2147483647; x + 1"),
+            Err(Error::Runtime(String::from("attempt to add with overflow")))
+        );
+        assert_eq!(
+            run("let x: usize = 0; x - 1"),
+            Err(Error::Runtime(String::from(
+                "attempt to subtract with overflow"
+            )))
+        );
+        assert_eq!(
+            run("3_000_000_000usize + 1"),
+            Ok(String::from("3000000001: usize"))
+        );
+        // An unsuffixed literal is computed as the type it ends up as.
+        assert_eq!(
+            run("2147483647 + 1"),
+            Err(Error::Runtime(String::from("attempt to add with overflow")))
+        );
+        assert_eq!(
+            run("-(-2147483648)"),
+            Err(Error::Runtime(String::from(
+                "attempt to negate with overflow"
+            )))
+        );
+        assert_eq!(run("-2147483648"), Ok(String::from("-2147483648: i32")));
+        assert_eq!(
+            run("2147483647 * 2 > 0"),
+            Err(Error::Runtime(String::from(
+                "attempt to multiply with overflow"
+            )))
+        );
+        assert_eq!(
+            run("let x: usize = 3_000_000_000 + 1; x"),
+            Ok(String::from("3000000001: usize"))
+        );
+        assert_eq!(
+            run(r#""ab".len() + 3_000_000_000 * 2"#),
+            Ok(String::from("6000000002: usize"))
+        );
+        assert_eq!(
+            run("2147483648"),
+            Err(Error::Runtime(String::from(
+                "`2147483648` does not fit in `i32`"
+            )))
+        );
+        assert!(run("1 / 0").is_err());
+        assert!(run("let x: i32 = 1; let y: usize = 2; x + y").is_err());
+        assert_eq!(run("7.0 / 2.0"), Ok(String::from("3.5: f64")));
+    }
+
+    #[test]
+    fn syntax_errors_stop_before_anything_runs() {
+        let mut session = Session::new();
+        assert!(matches!(
+            session.run("let x = 1; let y = (x + ;"),
+            Err(Error::Syntax(_))
+        ));
+        assert!(matches!(session.run("x"), Err(Error::Type(_))));
+        assert_eq!(
+            session.run("\"open"),
+            Err(Error::Syntax(String::from("unterminated string literal")))
+        );
+    }
+
+    #[test]
+    fn runs_anything_nested_up_to_the_limit() {
+        let depth = parser::MAX_DEPTH;
+        let blocks = format!("{}1{}", "{ ".repeat(depth), " }".repeat(depth));
+        assert_eq!(run(&blocks), Ok(String::from("1: i32")));
+        let branches = format!(
+            "{}1{}",
+            "if true { ".repeat(depth / 2),
+            " } else { 2 }".repeat(depth / 2)
+        );
+        assert_eq!(run(&branches), Ok(String::from("1: i32")));
+        let negations = format!("{}1{}", "-(".repeat(depth / 2), ")".repeat(depth / 2));
+        assert_eq!(run(&negations), Ok(String::from("1: i32")));
+        assert!(matches!(
+            run(&format!("{{{}}}", blocks)),
+            Err(Error::Syntax(_))
+        ));
+    }
+
+    #[test]
+    fn a_failed_input_binds_nothing() {
+        let mut session = Session::new();
+        session.run("let x = 1;").unwrap();
+        assert!(session.run("let x = \"two\"; let y = x + 1;").is_err());
+        let value = session.run("x").unwrap().value.unwrap();
+        assert_eq!(value.ty(), Type::I32);
+    }
+
+    #[test]
+    fn the_repl_reports_bindings_and_shadowing() {
+        let input = "let spaces = \"    \";\nlet spaces = spaces.len();\nlet y = {\nlet x = 3;\nx + 1\n};\nif spaces > 3 { \"wide\" } else { \"narrow\" }\n:env\n";
+        let mut output = Vec::new();
+        repl(Cursor::new(input), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("spaces: &str = \"    \"\n"));
+        assert!(output.contains("spaces: usize = 4 (shadows spaces: &str)\n"));
+        assert!(output.contains("y: i32 = 4\n"));
+        assert!(output.contains("\"wide\": &str\n"));
+    }
+}
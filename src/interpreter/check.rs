@@ -0,0 +1,235 @@
+//! The type checker, which runs over the whole input before any of it is evaluated.
+
+use super::parser::{BinaryOp, Block, Expr, Statement, UnaryOp};
+use super::{mismatched, Error, Scopes, Type};
+
+/// Checks `block` in the current scope (the top level of a session) and returns its type.
+pub fn statements(block: &Block, scopes: &mut Scopes<Type>) -> Result<Type, Error> {
+    for statement in &block.statements {
+        match statement {
+            Statement::Let {
+                name,
+                mutable,
+                ty,
+                value,
+            } => {
+                let found = expr(value, scopes)?;
+                let ty = match ty {
+                    Some(declared) => declared
+                        .unify(found)
+                        .ok_or_else(|| mismatched(*declared, found))?,
+                    None => found.resolved(),
+                };
+                scopes.define(name, ty, *mutable);
+            }
+            Statement::Assign { name, value } => {
+                let found = expr(value, scopes)?;
+                let binding = scopes.lookup(name).ok_or_else(|| not_found(name))?;
+                if !binding.mutable {
+                    return Err(Error::Type(format!(
+                        "cannot assign twice to immutable variable `{}`",
+                        name
+                    )));
+                }
+                if binding.value.unify(found).is_none() {
+                    return Err(mismatched(binding.value, found));
+                }
+            }
+            Statement::Expr(value) => {
+                expr(value, scopes)?;
+            }
+            Statement::BlockLike(value) => {
+                let found = expr(value, scopes)?;
+                if found != Type::Unit {
+                    return Err(mismatched(Type::Unit, found));
+                }
+            }
+        }
+    }
+
+    match &block.tail {
+        Some(tail) => expr(tail, scopes),
+        None => Ok(Type::Unit),
+    }
+}
+
+/// Checks `block` in a scope of its own.
+fn block(block: &Block, scopes: &mut Scopes<Type>) -> Result<Type, Error> {
+    scopes.push();
+    let ty = statements(block, scopes);
+    scopes.pop();
+    ty
+}
+
+fn not_found(name: &str) -> Error {
+    Error::Type(format!("cannot find value `{}` in this scope", name))
+}
+
+fn expr(expr_: &Expr, scopes: &mut Scopes<Type>) -> Result<Type, Error> {
+    match expr_ {
+        Expr::Int(_, ty) => Ok(*ty),
+        Expr::Float(_) => Ok(Type::F64),
+        Expr::Bool(_) => Ok(Type::Bool),
+        Expr::Str(_) => Ok(Type::Str),
+        Expr::Unit => Ok(Type::Unit),
+        Expr::Var(name) => scopes
+            .lookup(name)
+            .map(|binding| binding.value)
+            .ok_or_else(|| not_found(name)),
+        Expr::Unary(op, operand) => {
+            let ty = expr(operand, scopes)?;
+            let allowed = match op {
+                UnaryOp::Neg => matches!(ty, Type::I32 | Type::Integer | Type::F64),
+                UnaryOp::Not => ty == Type::Bool,
+            };
+            if allowed {
+                Ok(ty)
+            } else {
+                let symbol = if *op == UnaryOp::Neg { "-" } else { "!" };
+                Err(Error::Type(format!(
+                    "cannot apply unary operator `{}` to type `{}`",
+                    symbol, ty
+                )))
+            }
+        }
+        Expr::Binary(op, left, right) => {
+            let left = expr(left, scopes)?;
+            let right = expr(right, scopes)?;
+            binary(*op, left, right)
+        }
+        Expr::Method(receiver, method) => {
+            let ty = expr(receiver, scopes)?;
+            match (ty, method.as_str()) {
+                (Type::Str, "len") => Ok(Type::Usize),
+                _ => Err(Error::Type(format!(
+                    "no method named `{}` found for `{}`",
+                    method, ty
+                ))),
+            }
+        }
+        Expr::If(condition, then, otherwise) => {
+            let condition = expr(condition, scopes)?;
+            if condition != Type::Bool {
+                return Err(mismatched(Type::Bool, condition));
+            }
+            let then = block(then, scopes)?;
+            match otherwise {
+                None if then == Type::Unit => Ok(Type::Unit),
+                None => Err(Error::Type(format!(
+                    "`if` may be missing an `else` clause: expected `()`, found `{}`",
+                    then
+                ))),
+                Some(otherwise) => {
+                    let otherwise = expr(otherwise, scopes)?;
+                    then.unify(otherwise).ok_or_else(|| {
+                        Error::Type(format!(
+                            "`if` and `else` have incompatible types: expected `{}`, found `{}`",
+                            then, otherwise
+                        ))
+                    })
+                }
+            }
+        }
+        Expr::Block(inner) => block(inner, scopes),
+    }
+}
+
+fn binary(op: BinaryOp, left: Type, right: Type) -> Result<Type, Error> {
+    let shared = left.unify(right);
+    match op {
+        BinaryOp::And | BinaryOp::Or => match (left, right) {
+            (Type::Bool, Type::Bool) => Ok(Type::Bool),
+            (Type::Bool, other) | (other, _) => Err(mismatched(Type::Bool, other)),
+        },
+        BinaryOp::Eq | BinaryOp::Ne | BinaryOp::Lt | BinaryOp::Le | BinaryOp::Gt | BinaryOp::Ge => {
+            shared
+                .map(|_| Type::Bool)
+                .ok_or_else(|| mismatched(left, right))
+        }
+        _ => match shared {
+            Some(ty) if ty.is_numeric() => Ok(ty),
+            // Two different integer types: Rust won't convert one into the other.
+            None if left.is_integer() && right.is_integer() => Err(mismatched(left, right)),
+            _ => Err(Error::Type(match op {
+                BinaryOp::Add => format!("cannot add `{}` to `{}`", right, left),
+                BinaryOp::Sub => format!("cannot subtract `{}` from `{}`", right, left),
+                BinaryOp::Mul => format!("cannot multiply `{}` by `{}`", left, right),
+                BinaryOp::Div => format!("cannot divide `{}` by `{}`", left, right),
+                _ => format!(
+                    "cannot calculate the remainder of `{}` divided by `{}`",
+                    left, right
+                ),
+            })),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::statements;
+    use crate::interpreter::lexer::tokenize;
+    use crate::interpreter::parser::parse;
+    use crate::interpreter::{Error, Scopes, Type};
+
+    fn check(source: &str) -> Result<Type, Error> {
+        let program = parse(&tokenize(source)?)?;
+        statements(&program, &mut Scopes::new())
+    }
+
+    fn type_error(source: &str) -> String {
+        match check(source) {
+            Err(Error::Type(message)) => message,
+            other => panic!("expected a type error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn shadowing_may_change_the_type_for_the_rest_of_the_scope() {
+        assert_eq!(
+            check(r#"let x = "five"; let x = x.len(); x"#),
+            Ok(Type::Usize)
+        );
+        assert_eq!(
+            check(r#"let x = "five"; { let x = 5; x + 1 }; x"#),
+            Ok(Type::Str)
+        );
+        assert_eq!(
+            type_error(r#"let x = "five"; { let x = 5; }; x + 1"#),
+            "cannot add `{integer}` to `&str`"
+        );
+    }
+
+    #[test]
+    fn bindings_end_with_their_block() {
+        assert_eq!(
+            type_error("{ let inner = 1; } inner"),
+            "cannot find value `inner` in this scope"
+        );
+        assert_eq!(check("let y = { let x = 3; x + 1 }; y"), Ok(Type::I32));
+    }
+
+    #[test]
+    fn branches_and_operands_must_agree() {
+        assert_eq!(
+            type_error("if true { 1 } else { 2.0 }"),
+            "`if` and `else` have incompatible types: expected `{integer}`, found `f64`"
+        );
+        assert_eq!(
+            type_error("if true { 1 }"),
+            "`if` may be missing an `else` clause: expected `()`, found `{integer}`"
+        );
+        assert_eq!(check("if true { 1 } else { 2usize }"), Ok(Type::Usize));
+        assert_eq!(
+            type_error(r#""a".len() + 1i32"#),
+            "mismatched types: expected `usize`, found `i32`"
+        );
+        assert_eq!(
+            type_error("-true"),
+            "cannot apply unary operator `-` to type `bool`"
+        );
+        assert_eq!(
+            type_error("let x = 1; x = 2;"),
+            "cannot assign twice to immutable variable `x`"
+        );
+    }
+}
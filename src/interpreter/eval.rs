@@ -0,0 +1,232 @@
+//! The evaluator. It trusts the type checker, so a type mix-up here is a bug, not an error.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use super::parser::{BinaryOp, Block, Expr, Statement, UnaryOp};
+use super::{Error, Scopes, Type};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// Integers of every type, kept wide and range-checked against `ty` after each step.
+    Int(i128, Type),
+    F64(f64),
+    Bool(bool),
+    Str(String),
+    Unit,
+}
+
+impl Value {
+    pub fn ty(&self) -> Type {
+        match self {
+            Value::Int(_, ty) => *ty,
+            Value::F64(_) => Type::F64,
+            Value::Bool(_) => Type::Bool,
+            Value::Str(_) => Type::Str,
+            Value::Unit => Type::Unit,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Int(value, _) => write!(f, "{}", value),
+            Value::F64(value) => write!(f, "{:?}", value),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Str(text) => write!(f, "{:?}", text),
+            Value::Unit => f.write_str("()"),
+        }
+    }
+}
+
+fn range(ty: Type) -> (i128, i128) {
+    match ty {
+        Type::I32 => (i32::MIN.into(), i32::MAX.into()),
+        Type::Usize => (0, usize::MAX as i128),
+        _ => (i128::MIN, i128::MAX),
+    }
+}
+
+/// `value` as a `ty`, once the checker has decided what an integer literal is.
+pub fn settle(value: Value, ty: Type) -> Result<Value, Error> {
+    match value {
+        Value::Int(n, Type::Integer) if ty.is_integer() => {
+            let (min, max) = range(ty);
+            if n < min || n > max {
+                return Err(Error::Runtime(format!("`{}` does not fit in `{}`", n, ty)));
+            }
+            Ok(Value::Int(n, ty))
+        }
+        value => Ok(value),
+    }
+}
+
+/// Runs `block` in the current scope and returns its value. `want` is the type an undecided
+/// integer it produces will end up as, so arithmetic on one overflows where Rust's would.
+pub fn statements(block: &Block, scopes: &mut Scopes<Value>, want: Type) -> Result<Value, Error> {
+    for statement in &block.statements {
+        match statement {
+            Statement::Let {
+                name,
+                mutable,
+                ty,
+                value,
+            } => {
+                let value = expr(value, scopes, ty.unwrap_or(Type::I32))?;
+                let ty = ty.unwrap_or_else(|| value.ty().resolved());
+                let value = settle(value, ty)?;
+                scopes.define(name, value, *mutable);
+            }
+            Statement::Assign { name, value } => {
+                let want = scopes.lookup(name).expect("checked").value.ty();
+                let value = expr(value, scopes, want)?;
+                let binding = scopes.lookup_mut(name).expect("checked");
+                binding.value = settle(value, binding.value.ty())?;
+            }
+            Statement::Expr(value) | Statement::BlockLike(value) => {
+                expr(value, scopes, Type::I32)?;
+            }
+        }
+    }
+
+    match &block.tail {
+        Some(tail) => expr(tail, scopes, want),
+        None => Ok(Value::Unit),
+    }
+}
+
+fn block(block: &Block, scopes: &mut Scopes<Value>, want: Type) -> Result<Value, Error> {
+    scopes.push();
+    let value = statements(block, scopes, want);
+    scopes.pop();
+    value
+}
+
+fn expr(expr_: &Expr, scopes: &mut Scopes<Value>, want: Type) -> Result<Value, Error> {
+    Ok(match expr_ {
+        Expr::Int(value, ty) => settle(Value::Int(*value, Type::Integer), *ty)?,
+        Expr::Float(value) => Value::F64(*value),
+        Expr::Bool(value) => Value::Bool(*value),
+        Expr::Str(text) => Value::Str(text.clone()),
+        Expr::Unit => Value::Unit,
+        Expr::Var(name) => scopes.lookup(name).expect("checked").value.clone(),
+        Expr::Unary(op, operand) => match (op, expr(operand, scopes, want)?) {
+            (UnaryOp::Neg, Value::Int(n, ty)) => integer(ty, want, Some(-n), "negate")?,
+            (UnaryOp::Neg, Value::F64(x)) => Value::F64(-x),
+            (UnaryOp::Not, Value::Bool(b)) => Value::Bool(!b),
+            (_, value) => unreachable!("checked: {:?}", value),
+        },
+        Expr::Binary(BinaryOp::And, left, right) => {
+            // Short-circuits, like Rust's.
+            Value::Bool(
+                truthy(expr(left, scopes, Type::I32)?) && truthy(expr(right, scopes, Type::I32)?),
+            )
+        }
+        Expr::Binary(BinaryOp::Or, left, right) => Value::Bool(
+            truthy(expr(left, scopes, Type::I32)?) || truthy(expr(right, scopes, Type::I32)?),
+        ),
+        Expr::Binary(op, left, right) => {
+            // Compared operands have nothing to become but each other's type, or `i32`.
+            let want = if op.is_comparison() { Type::I32 } else { want };
+            let left = expr(left, scopes, want)?;
+            // A typed left side decides what an undecided right side is.
+            let want = match left.ty() {
+                Type::Integer => want,
+                ty => ty,
+            };
+            let right = expr(right, scopes, want)?;
+            binary(*op, left, right, want)?
+        }
+        Expr::Method(receiver, _) => match expr(receiver, scopes, want)? {
+            Value::Str(text) => Value::Int(text.len() as i128, Type::Usize),
+            value => unreachable!("checked: {:?}", value),
+        },
+        Expr::If(condition, then, otherwise) => {
+            if truthy(expr(condition, scopes, Type::I32)?) {
+                block(then, scopes, want)?
+            } else {
+                match otherwise {
+                    Some(otherwise) => expr(otherwise, scopes, want)?,
+                    None => Value::Unit,
+                }
+            }
+        }
+        Expr::Block(inner) => block(inner, scopes, want)?,
+    })
+}
+
+fn truthy(value: Value) -> bool {
+    match value {
+        Value::Bool(b) => b,
+        value => unreachable!("checked: {:?}", value),
+    }
+}
+
+/// An integer result, or the panic a debug build would raise for `action`. An undecided
+/// integer is checked against the type it will become, `want`.
+fn integer(ty: Type, want: Type, result: Option<i128>, action: &str) -> Result<Value, Error> {
+    let (min, max) = match ty {
+        Type::Integer if want.is_integer() => range(want.resolved()),
+        ty => range(ty),
+    };
+    match result {
+        Some(n) if n >= min && n <= max => Ok(Value::Int(n, ty)),
+        _ => Err(Error::Runtime(format!(
+            "attempt to {} with overflow",
+            action
+        ))),
+    }
+}
+
+fn binary(op: BinaryOp, left: Value, right: Value, want: Type) -> Result<Value, Error> {
+    if let (Value::Int(a, left_ty), Value::Int(b, right_ty)) = (&left, &right) {
+        let (a, b) = (*a, *b);
+        let ty = left_ty.unify(*right_ty).expect("checked");
+        let zero_divisor = || {
+            Error::Runtime(String::from(match op {
+                BinaryOp::Div => "attempt to divide by zero",
+                _ => "attempt to calculate the remainder with a divisor of zero",
+            }))
+        };
+        return match op {
+            BinaryOp::Add => integer(ty, want, a.checked_add(b), "add"),
+            BinaryOp::Sub => integer(ty, want, a.checked_sub(b), "subtract"),
+            BinaryOp::Mul => integer(ty, want, a.checked_mul(b), "multiply"),
+            BinaryOp::Div if b == 0 => Err(zero_divisor()),
+            BinaryOp::Rem if b == 0 => Err(zero_divisor()),
+            BinaryOp::Div => integer(ty, want, a.checked_div(b), "divide"),
+            BinaryOp::Rem => integer(ty, want, a.checked_rem(b), "calculate the remainder"),
+            _ => Ok(compare(op, a.cmp(&b))),
+        };
+    }
+
+    Ok(match (op, left, right) {
+        (BinaryOp::Add, Value::F64(a), Value::F64(b)) => Value::F64(a + b),
+        (BinaryOp::Sub, Value::F64(a), Value::F64(b)) => Value::F64(a - b),
+        (BinaryOp::Mul, Value::F64(a), Value::F64(b)) => Value::F64(a * b),
+        (BinaryOp::Div, Value::F64(a), Value::F64(b)) => Value::F64(a / b),
+        (BinaryOp::Rem, Value::F64(a), Value::F64(b)) => Value::F64(a % b),
+        (op, Value::F64(a), Value::F64(b)) => match a.partial_cmp(&b) {
+            Some(ordering) => compare(op, ordering),
+            // Every comparison with NaN is false, except `!=`.
+            None => Value::Bool(op == BinaryOp::Ne),
+        },
+        (op, Value::Bool(a), Value::Bool(b)) => compare(op, a.cmp(&b)),
+        (op, Value::Str(a), Value::Str(b)) => compare(op, a.cmp(&b)),
+        (op, Value::Unit, Value::Unit) => compare(op, Ordering::Equal),
+        (op, left, right) => unreachable!("checked: {:?} {:?} {:?}", left, op, right),
+    })
+}
+
+fn compare(op: BinaryOp, ordering: Ordering) -> Value {
+    Value::Bool(match op {
+        BinaryOp::Eq => ordering == Ordering::Equal,
+        BinaryOp::Ne => ordering != Ordering::Equal,
+        BinaryOp::Lt => ordering == Ordering::Less,
+        BinaryOp::Le => ordering != Ordering::Greater,
+        BinaryOp::Gt => ordering == Ordering::Greater,
+        BinaryOp::Ge => ordering != Ordering::Less,
+        op => unreachable!("not a comparison: {:?}", op),
+    })
+}
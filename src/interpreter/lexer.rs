@@ -0,0 +1,193 @@
+use std::fmt;
+
+use super::{Error, Type};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    /// An integer literal, with its type when it has a suffix (`5usize`).
+    Int(i128, Option<Type>),
+    Float(f64),
+    Str(String),
+    Ident(String),
+    Let,
+    Mut,
+    If,
+    Else,
+    True,
+    False,
+    /// Punctuation and operators, as written.
+    Symbol(&'static str),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Token::Int(value, None) => write!(f, "`{}`", value),
+            Token::Int(value, Some(ty)) => write!(f, "`{}{}`", value, ty),
+            Token::Float(value) => write!(f, "`{:?}`", value),
+            Token::Str(text) => write!(f, "{:?}", text),
+            Token::Ident(name) => write!(f, "`{}`", name),
+            Token::Let => f.write_str("`let`"),
+            Token::Mut => f.write_str("`mut`"),
+            Token::If => f.write_str("`if`"),
+            Token::Else => f.write_str("`else`"),
+            Token::True => f.write_str("`true`"),
+            Token::False => f.write_str("`false`"),
+            Token::Symbol(symbol) => write!(f, "`{}`", symbol),
+        }
+    }
+}
+
+/// Longest first, so `==` isn't read as two `=`.
+const SYMBOLS: &[&str] = &[
+    "==", "!=", "<=", ">=", "&&", "||", "+", "-", "*", "/", "%", "!", "<", ">", "=", ";", ":", "{",
+    "}", "(", ")", ".", "&",
+];
+
+pub fn tokenize(source: &str) -> Result<Vec<Token>, Error> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+
+    loop {
+        rest = rest.trim_start();
+        if rest.starts_with("//") {
+            rest = rest.find('\n').map_or("", |end| &rest[end..]);
+            continue;
+        }
+        let c = match rest.chars().next() {
+            Some(c) => c,
+            None => return Ok(tokens),
+        };
+
+        let length = if c.is_ascii_digit() {
+            let (token, length) = number(rest)?;
+            tokens.push(token);
+            length
+        } else if c == '"' {
+            let (text, length) = string(rest)?;
+            tokens.push(Token::Str(text));
+            length
+        } else if c.is_alphabetic() || c == '_' {
+            let length = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            tokens.push(match &rest[..length] {
+                "let" => Token::Let,
+                "mut" => Token::Mut,
+                "if" => Token::If,
+                "else" => Token::Else,
+                "true" => Token::True,
+                "false" => Token::False,
+                name => Token::Ident(name.to_string()),
+            });
+            length
+        } else {
+            let symbol = SYMBOLS
+                .iter()
+                .find(|symbol| rest.starts_with(*symbol))
+                .ok_or_else(|| Error::Syntax(format!("unexpected character `{}`", c)))?;
+            tokens.push(Token::Symbol(symbol));
+            symbol.len()
+        };
+        rest = &rest[length..];
+    }
+}
+
+/// `42`, `1_000`, `5usize`, `2.5`; a `.` only makes a float when a digit follows, so
+/// `5.len()` would still be a method call.
+fn number(text: &str) -> Result<(Token, usize), Error> {
+    let digits = |from: usize| {
+        from + text[from..]
+            .find(|c: char| !(c.is_ascii_digit() || c == '_'))
+            .unwrap_or(text.len() - from)
+    };
+    let mut end = digits(0);
+
+    let is_float =
+        text[end..].starts_with('.') && text[end + 1..].starts_with(|c: char| c.is_ascii_digit());
+    if is_float {
+        end = digits(end + 1);
+        let value = text[..end]
+            .replace('_', "")
+            .parse()
+            .expect("digits and a point");
+        return Ok((Token::Float(value), end));
+    }
+
+    let value: i128 = text[..end]
+        .replace('_', "")
+        .parse()
+        .map_err(|_| Error::Syntax(format!("integer literal `{}` is too large", &text[..end])))?;
+    let suffix_end = end
+        + text[end..]
+            .find(|c: char| !c.is_alphanumeric())
+            .unwrap_or(text.len() - end);
+    let suffix = match &text[end..suffix_end] {
+        "" => None,
+        "i32" => Some(Type::I32),
+        "usize" => Some(Type::Usize),
+        other => {
+            return Err(Error::Syntax(format!(
+                "invalid suffix `{}` for number literal (use `i32` or `usize`)",
+                other
+            )))
+        }
+    };
+    Ok((Token::Int(value, suffix), suffix_end))
+}
+
+/// A string literal with `\n`, `\t`, `\\` and `\"` escapes.
+fn string(text: &str) -> Result<(String, usize), Error> {
+    let mut value = String::new();
+    let mut chars = text.char_indices().skip(1);
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '"' => return Ok((value, index + 1)),
+            '\\' => match chars.next() {
+                Some((_, 'n')) => value.push('\n'),
+                Some((_, 't')) => value.push('\t'),
+                Some((_, '\\')) => value.push('\\'),
+                Some((_, '"')) => value.push('"'),
+                Some((_, other)) => {
+                    return Err(Error::Syntax(format!(
+                        "unknown character escape `\\{}`",
+                        other
+                    )))
+                }
+                None => break,
+            },
+            c => value.push(c),
+        }
+    }
+
+    Err(Error::Syntax(String::from("unterminated string literal")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tokenize, Token};
+    use crate::interpreter::Type;
+
+    #[test]
+    fn splits_rust_like_source() {
+        assert_eq!(
+            tokenize("let x = 5usize + 2.5; // five\n x >= \"a\\n\"").unwrap(),
+            [
+                Token::Let,
+                Token::Ident(String::from("x")),
+                Token::Symbol("="),
+                Token::Int(5, Some(Type::Usize)),
+                Token::Symbol("+"),
+                Token::Float(2.5),
+                Token::Symbol(";"),
+                Token::Ident(String::from("x")),
+                Token::Symbol(">="),
+                Token::Str(String::from("a\n")),
+            ]
+        );
+        assert!(tokenize("\"open").is_err());
+        assert!(tokenize("5u8").is_err());
+        assert!(tokenize("#").is_err());
+    }
+}
@@ -0,0 +1,531 @@
+use super::lexer::Token;
+use super::{Error, Type};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+    Neg,
+    Not,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    And,
+    Or,
+}
+
+impl BinaryOp {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Sub => "-",
+            BinaryOp::Mul => "*",
+            BinaryOp::Div => "/",
+            BinaryOp::Rem => "%",
+            BinaryOp::Eq => "==",
+            BinaryOp::Ne => "!=",
+            BinaryOp::Lt => "<",
+            BinaryOp::Le => "<=",
+            BinaryOp::Gt => ">",
+            BinaryOp::Ge => ">=",
+            BinaryOp::And => "&&",
+            BinaryOp::Or => "||",
+        }
+    }
+    pub fn is_comparison(&self) -> bool {
+        matches!(
+            self,
+            BinaryOp::Eq | BinaryOp::Ne | BinaryOp::Lt | BinaryOp::Le | BinaryOp::Gt | BinaryOp::Ge
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// An integer literal; `Type::Integer` until something decides its type.
+    Int(i128, Type),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+    Unit,
+    Var(String),
+    Unary(UnaryOp, Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    /// A method call without arguments, like `spaces.len()`.
+    Method(Box<Expr>, String),
+    /// `if condition { ... } else ...`, where the `else` part is a block or another `if`.
+    If(Box<Expr>, Block, Option<Box<Expr>>),
+    Block(Block),
+}
+
+impl Expr {
+    /// Blocks and `if`s can stand as statements without a semicolon, as long as they are `()`.
+    fn is_block_like(&self) -> bool {
+        matches!(self, Expr::If(..) | Expr::Block(_))
+    }
+}
+
+/// Statements, then the expression without a semicolon that gives the block its value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block {
+    pub statements: Vec<Statement>,
+    pub tail: Option<Box<Expr>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    Let {
+        name: String,
+        mutable: bool,
+        ty: Option<Type>,
+        value: Expr,
+    },
+    Assign {
+        name: String,
+        value: Expr,
+    },
+    /// An expression whose value is thrown away.
+    Expr(Expr),
+    /// A block or `if` standing as a statement without a semicolon, which must be `()`.
+    BlockLike(Expr),
+}
+
+/// How deeply expressions and blocks may nest. Parsing, checking and evaluating all recurse
+/// once per level, so this keeps a pile of `(` from overflowing the stack.
+pub const MAX_DEPTH: usize = 128;
+
+/// Parses a whole input as the inside of a block: statements and an optional final expression.
+pub fn parse(tokens: &[Token]) -> Result<Block, Error> {
+    let mut parser = Parser {
+        tokens,
+        position: 0,
+        depth: 0,
+    };
+    let block = parser.statements(None)?;
+    match parser.peek() {
+        None => Ok(block),
+        Some(token) => Err(Error::Syntax(format!("unexpected {}", token))),
+    }
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    position: usize,
+    /// How many expressions and blocks enclose the one being parsed.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.tokens.get(self.position);
+        self.position += 1;
+        token
+    }
+
+    fn is_symbol(&self, symbol: &str) -> bool {
+        matches!(self.peek(), Some(Token::Symbol(s)) if *s == symbol)
+    }
+
+    fn eat(&mut self, symbol: &str) -> bool {
+        let found = self.is_symbol(symbol);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, symbol: &str) -> Result<(), Error> {
+        if self.eat(symbol) {
+            Ok(())
+        } else {
+            Err(self.expected(&format!("`{}`", symbol)))
+        }
+    }
+
+    fn expected(&self, what: &str) -> Error {
+        match self.peek() {
+            Some(token) => Error::Syntax(format!("expected {}, found {}", what, token)),
+            None => Error::Syntax(format!("expected {}, found end of input", what)),
+        }
+    }
+
+    /// Goes one level deeper, unless that is deeper than [`MAX_DEPTH`].
+    fn deeper(&mut self) -> Result<(), Error> {
+        if self.depth == MAX_DEPTH {
+            return Err(Error::Syntax(format!(
+                "expressions nest more than {} levels deep",
+                MAX_DEPTH
+            )));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// Runs `parse` one level deeper.
+    fn nested<T>(&mut self, parse: impl FnOnce(&mut Self) -> Result<T, Error>) -> Result<T, Error> {
+        self.deeper()?;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn identifier(&mut self) -> Result<String, Error> {
+        match self.peek() {
+            Some(Token::Ident(name)) => {
+                self.position += 1;
+                Ok(name.clone())
+            }
+            _ => Err(self.expected("an identifier")),
+        }
+    }
+
+    /// Statements up to `end` (`}`), or to the end of the input when `end` is `None`.
+    fn statements(&mut self, end: Option<&str>) -> Result<Block, Error> {
+        let mut statements = Vec::new();
+
+        loop {
+            let at_end = match end {
+                Some(symbol) => self.is_symbol(symbol),
+                None => self.peek().is_none(),
+            };
+            if at_end {
+                return Ok(Block {
+                    statements,
+                    tail: None,
+                });
+            }
+
+            if self.peek() == Some(&Token::Let) {
+                statements.push(self.let_statement()?);
+                continue;
+            }
+
+            let expr = self.expression()?;
+            if let (Expr::Var(name), true) = (&expr, self.is_symbol("=")) {
+                self.position += 1;
+                let value = self.expression()?;
+                statements.push(Statement::Assign {
+                    name: name.clone(),
+                    value,
+                });
+                let at_end = match end {
+                    Some(symbol) => self.is_symbol(symbol),
+                    None => self.peek().is_none(),
+                };
+                if !at_end {
+                    self.expect(";")?;
+                }
+                continue;
+            }
+
+            if self.eat(";") {
+                statements.push(Statement::Expr(expr));
+                continue;
+            }
+            let at_end = match end {
+                Some(symbol) => self.is_symbol(symbol),
+                None => self.peek().is_none(),
+            };
+            if at_end {
+                return Ok(Block {
+                    statements,
+                    tail: Some(Box::new(expr)),
+                });
+            }
+            if expr.is_block_like() {
+                statements.push(Statement::BlockLike(expr));
+                continue;
+            }
+            return Err(self.expected("`;`"));
+        }
+    }
+
+    fn let_statement(&mut self) -> Result<Statement, Error> {
+        self.next();
+        let mutable = self.peek() == Some(&Token::Mut);
+        if mutable {
+            self.position += 1;
+        }
+        let name = self.identifier()?;
+        let ty = if self.eat(":") {
+            Some(self.type_name()?)
+        } else {
+            None
+        };
+        self.expect("=")?;
+        if self.peek() == Some(&Token::Let) {
+            // `let x = (let y = 6);` in chapter 3.
+            return Err(Error::Syntax(String::from(
+                "expected expression, found `let` statement: `let` is a statement and has no value",
+            )));
+        }
+        let value = self.expression()?;
+        self.expect(";")?;
+        Ok(Statement::Let {
+            name,
+            mutable,
+            ty,
+            value,
+        })
+    }
+
+    fn type_name(&mut self) -> Result<Type, Error> {
+        if self.eat("&") {
+            return match self.peek() {
+                Some(Token::Ident(name)) if name == "str" => {
+                    self.position += 1;
+                    Ok(Type::Str)
+                }
+                _ => Err(self.expected("`str`")),
+            };
+        }
+        if self.eat("(") {
+            self.expect(")")?;
+            return Ok(Type::Unit);
+        }
+        match self.identifier()?.as_str() {
+            "i32" => Ok(Type::I32),
+            "usize" => Ok(Type::Usize),
+            "f64" => Ok(Type::F64),
+            "bool" => Ok(Type::Bool),
+            other => Err(Error::Syntax(format!(
+                "unknown type `{}` (i32, usize, f64, bool, &str and () are supported)",
+                other
+            ))),
+        }
+    }
+
+    fn expression(&mut self) -> Result<Expr, Error> {
+        self.binary(0)
+    }
+
+    /// Precedence climbing over the binary operators, loosest first.
+    fn binary(&mut self, level: usize) -> Result<Expr, Error> {
+        const LEVELS: &[&[(&str, BinaryOp)]] = &[
+            &[("||", BinaryOp::Or)],
+            &[("&&", BinaryOp::And)],
+            &[
+                ("==", BinaryOp::Eq),
+                ("!=", BinaryOp::Ne),
+                ("<=", BinaryOp::Le),
+                (">=", BinaryOp::Ge),
+                ("<", BinaryOp::Lt),
+                (">", BinaryOp::Gt),
+            ],
+            &[("+", BinaryOp::Add), ("-", BinaryOp::Sub)],
+            &[
+                ("*", BinaryOp::Mul),
+                ("/", BinaryOp::Div),
+                ("%", BinaryOp::Rem),
+            ],
+        ];
+
+        if level == LEVELS.len() {
+            return self.unary();
+        }
+
+        let mut left = self.binary(level + 1)?;
+        // Each operator nests everything before it one level deeper: `1 + 2 + 3` is
+        // `(1 + 2) + 3`.
+        let depth = self.depth;
+        let result = loop {
+            let op = match LEVELS[level].iter().find(|&&(symbol, _)| self.eat(symbol)) {
+                Some(&(_, op)) => op,
+                None => break Ok(left),
+            };
+            if let Err(error) = self.deeper() {
+                break Err(error);
+            }
+            match self.binary(level + 1) {
+                Ok(right) => left = Expr::Binary(op, Box::new(left), Box::new(right)),
+                Err(error) => break Err(error),
+            }
+        };
+        self.depth = depth;
+        result
+    }
+
+    fn unary(&mut self) -> Result<Expr, Error> {
+        if self.eat("-") {
+            let operand = self.nested(Parser::unary)?;
+            return Ok(Expr::Unary(UnaryOp::Neg, Box::new(operand)));
+        }
+        if self.eat("!") {
+            let operand = self.nested(Parser::unary)?;
+            return Ok(Expr::Unary(UnaryOp::Not, Box::new(operand)));
+        }
+
+        let mut expr = self.primary()?;
+        while self.eat(".") {
+            let method = self.identifier()?;
+            self.expect("(")?;
+            self.expect(")")?;
+            expr = Expr::Method(Box::new(expr), method);
+        }
+        Ok(expr)
+    }
+
+    fn primary(&mut self) -> Result<Expr, Error> {
+        let token = match self.peek() {
+            Some(token) => token,
+            None => return Err(self.expected("an expression")),
+        };
+
+        let expr = match token {
+            Token::Int(value, ty) => Expr::Int(*value, ty.unwrap_or(Type::Integer)),
+            Token::Float(value) => Expr::Float(*value),
+            Token::Str(text) => Expr::Str(text.clone()),
+            Token::True => Expr::Bool(true),
+            Token::False => Expr::Bool(false),
+            Token::Ident(name) => Expr::Var(name.clone()),
+            Token::If => return self.nested(Parser::if_expression),
+            Token::Symbol("{") => return Ok(Expr::Block(self.block()?)),
+            Token::Symbol("(") => {
+                self.position += 1;
+                if self.eat(")") {
+                    return Ok(Expr::Unit);
+                }
+                let inner = self.nested(Parser::expression)?;
+                self.expect(")")?;
+                return Ok(inner);
+            }
+            Token::Let => return Err(Error::Syntax(String::from(
+                "expected expression, found `let` statement: `let` is a statement and has no value",
+            ))),
+            _ => return Err(self.expected("an expression")),
+        };
+        self.position += 1;
+        Ok(expr)
+    }
+
+    fn block(&mut self) -> Result<Block, Error> {
+        self.expect("{")?;
+        let block = self.nested(|parser| parser.statements(Some("}")))?;
+        self.expect("}")?;
+        Ok(block)
+    }
+
+    fn if_expression(&mut self) -> Result<Expr, Error> {
+        self.next();
+        let condition = self.expression()?;
+        let then = self.block()?;
+        let otherwise = if self.peek() == Some(&Token::Else) {
+            self.position += 1;
+            if self.peek() == Some(&Token::If) {
+                Some(Box::new(self.nested(Parser::if_expression)?))
+            } else {
+                Some(Box::new(Expr::Block(self.block()?)))
+            }
+        } else {
+            None
+        };
+        Ok(Expr::If(Box::new(condition), then, otherwise))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, BinaryOp, Expr, Statement, MAX_DEPTH};
+    use crate::interpreter::lexer::tokenize;
+    use crate::interpreter::Type;
+
+    fn parse_source(source: &str) -> Result<super::Block, crate::interpreter::Error> {
+        parse(&tokenize(source)?)
+    }
+
+    #[test]
+    fn multiplication_binds_tighter_than_addition() {
+        let block = parse_source("1 + 2 * 3").unwrap();
+        assert_eq!(
+            block.tail.map(|tail| *tail),
+            Some(Expr::Binary(
+                BinaryOp::Add,
+                Box::new(Expr::Int(1, Type::Integer)),
+                Box::new(Expr::Binary(
+                    BinaryOp::Mul,
+                    Box::new(Expr::Int(2, Type::Integer)),
+                    Box::new(Expr::Int(3, Type::Integer)),
+                )),
+            ))
+        );
+    }
+
+    #[test]
+    fn a_semicolon_turns_the_last_expression_into_a_statement() {
+        let with = parse_source("let y = { let x = 3; x + 1 };").unwrap();
+        match &with.statements[0] {
+            Statement::Let {
+                value: Expr::Block(block),
+                ..
+            } => assert!(block.tail.is_some()),
+            other => panic!("unexpected statement: {:?}", other),
+        }
+
+        let without = parse_source("let y = { let x = 3; x + 1; };").unwrap();
+        match &without.statements[0] {
+            Statement::Let {
+                value: Expr::Block(block),
+                ..
+            } => assert!(block.tail.is_none()),
+            other => panic!("unexpected statement: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn let_is_not_an_expression() {
+        let error = parse_source("let x = (let y = 6);").unwrap_err();
+        assert!(error.to_string().contains("`let` is a statement"));
+        assert!(parse_source("let x = 5 let y = 6;").is_err());
+    }
+
+    #[test]
+    fn reports_what_it_expected() {
+        let error = |source: &str| parse_source(source).unwrap_err().to_string();
+        assert_eq!(
+            error("1 +"),
+            "syntax error: expected an expression, found end of input"
+        );
+        assert_eq!(
+            error("(1 + 2"),
+            "syntax error: expected `)`, found end of input"
+        );
+        assert!(error("let = 5;").starts_with("syntax error: expected "));
+        assert!(error("if true { 1 } else 2").starts_with("syntax error: expected `{`"));
+        assert!(error("1 2").starts_with("syntax error: "));
+    }
+
+    #[test]
+    fn nesting_is_limited() {
+        let parens = |depth: usize| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+        assert!(parse_source(&parens(MAX_DEPTH)).is_ok());
+        assert_eq!(
+            parse_source(&parens(MAX_DEPTH + 1))
+                .unwrap_err()
+                .to_string(),
+            format!(
+                "syntax error: expressions nest more than {} levels deep",
+                MAX_DEPTH
+            )
+        );
+
+        // Blocks, unary operators and long chains of binary operators nest too.
+        assert!(parse_source(&"{".repeat(100_000)).is_err());
+        assert!(parse_source(&format!("{}1", "-".repeat(100_000))).is_err());
+        assert!(parse_source(&format!("1{}", " + 1".repeat(100_000))).is_err());
+        assert!(parse_source(&format!("1{}", " + 1".repeat(MAX_DEPTH))).is_ok());
+    }
+}
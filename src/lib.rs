@@ -0,0 +1,41 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_mut)]
+#![allow(clippy::let_and_return)]
+#![allow(clippy::manual_map)]
+#![allow(clippy::ptr_arg)]
+#![allow(clippy::redundant_field_names)]
+#![allow(clippy::useless_vec)]
+#![allow(clippy::vec_init_then_push)]
+// ^ So we can write examples without the compiler complaining with warnings.
+
+pub mod allocations;
+pub mod chapters;
+pub mod cli;
+pub mod exercises;
+pub mod float;
+pub mod guessing_game;
+pub mod inspect;
+pub mod interpreter;
+pub mod literal;
+pub mod overflow;
+pub mod trace;
+pub mod unicode;
+pub mod words;
+
+pub use crate::chapters::chapter02::c02;
+pub use crate::chapters::chapter03::c03;
+pub use crate::chapters::chapter04::c04;
+pub use crate::chapters::chapter05::c05;
+pub use crate::chapters::chapter06::c06;
+pub use crate::chapters::chapter07::c07;
+pub use crate::chapters::chapter08::c08;
+pub use crate::chapters::chapter09::c09;
+pub use crate::chapters::chapter10::c10;
+pub use crate::chapters::chapter11::c11;
+pub use crate::chapters::{Chapter, Section};
+
+// Only for this crate's own unit tests: programs using the library choose their allocator.
+#[cfg(all(test, feature = "count-allocations"))]
+#[global_allocator]
+static ALLOCATOR: allocations::CountingAllocator = allocations::CountingAllocator;
@@ -0,0 +1,553 @@
+//! Rust's integer literals, parsed the way the compiler reads them: `98_222`, `0xff`, `0o77`,
+//! `0b1111_0000`, `b'A'`, with an optional type suffix such as `255u8`, and an optional leading
+//! `-` as in `-1i64`.
+
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+/// The integer types a suffix can name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntType {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+}
+
+impl IntType {
+    pub const ALL: [IntType; 12] = [
+        IntType::I8,
+        IntType::I16,
+        IntType::I32,
+        IntType::I64,
+        IntType::I128,
+        IntType::Isize,
+        IntType::U8,
+        IntType::U16,
+        IntType::U32,
+        IntType::U64,
+        IntType::U128,
+        IntType::Usize,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            IntType::I8 => "i8",
+            IntType::I16 => "i16",
+            IntType::I32 => "i32",
+            IntType::I64 => "i64",
+            IntType::I128 => "i128",
+            IntType::Isize => "isize",
+            IntType::U8 => "u8",
+            IntType::U16 => "u16",
+            IntType::U32 => "u32",
+            IntType::U64 => "u64",
+            IntType::U128 => "u128",
+            IntType::Usize => "usize",
+        }
+    }
+
+    pub fn bits(&self) -> u32 {
+        match self {
+            IntType::I8 | IntType::U8 => 8,
+            IntType::I16 | IntType::U16 => 16,
+            IntType::I32 | IntType::U32 => 32,
+            IntType::I64 | IntType::U64 => 64,
+            IntType::I128 | IntType::U128 => 128,
+            IntType::Isize | IntType::Usize => usize::BITS,
+        }
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            IntType::I8
+                | IntType::I16
+                | IntType::I32
+                | IntType::I64
+                | IntType::I128
+                | IntType::Isize
+        )
+    }
+
+    /// The largest value, as a magnitude.
+    pub fn max(&self) -> u128 {
+        let bits = if self.is_signed() {
+            self.bits() - 1
+        } else {
+            self.bits()
+        };
+        u128::MAX >> (128 - bits)
+    }
+
+    /// The magnitude of the smallest value: 0 for unsigned types, `max + 1` for signed ones.
+    pub fn min_magnitude(&self) -> u128 {
+        if self.is_signed() {
+            self.max() + 1
+        } else {
+            0
+        }
+    }
+
+    fn range(&self) -> String {
+        if self.is_signed() {
+            format!("-{}..={}", self.min_magnitude(), self.max())
+        } else {
+            format!("0..={}", self.max())
+        }
+    }
+}
+
+impl fmt::Display for IntType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for IntType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<IntType, String> {
+        IntType::ALL
+            .iter()
+            .copied()
+            .find(|ty| ty.name() == s)
+            .ok_or_else(|| format!("`{}` is not an integer type", s))
+    }
+}
+
+/// A parsed literal. The value is kept as sign and magnitude so every `u128` and `i128` fits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Literal {
+    pub ty: IntType,
+    /// `false` when no suffix was given and the type fell back to `i32`.
+    pub suffixed: bool,
+    pub negative: bool,
+    pub magnitude: u128,
+    pub radix: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LiteralError {
+    Empty,
+    /// A prefix (`0x`, `0o`, `0b`) or sign with no digits after it.
+    NoDigits,
+    /// Decimal literals have to start with a digit; `_1` is an identifier.
+    LeadingUnderscore,
+    /// A digit that doesn't exist in the literal's base, like the `8` in `0o78`.
+    InvalidDigit {
+        digit: char,
+        position: usize,
+        radix: u32,
+    },
+    UnknownSuffix(String),
+    /// `f32` and `f64` make a decimal literal a float.
+    FloatSuffix(String),
+    /// The value doesn't fit the suffix type (or `i32`, without a suffix).
+    OutOfRange {
+        literal: String,
+        ty: IntType,
+    },
+    /// The value doesn't even fit 128 bits.
+    TooLarge,
+    /// `-` in front of an unsigned literal.
+    NegativeUnsigned(IntType),
+    InvalidByte(String),
+}
+
+impl fmt::Display for LiteralError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LiteralError::Empty => write!(f, "there is no literal"),
+            LiteralError::NoDigits => write!(f, "expected at least one digit"),
+            LiteralError::LeadingUnderscore => {
+                write!(
+                    f,
+                    "a literal can't start with `_`; that makes it an identifier"
+                )
+            }
+            LiteralError::InvalidDigit {
+                digit,
+                position,
+                radix,
+            } => write!(
+                f,
+                "invalid digit `{}` at position {} for a base {} literal",
+                digit, position, radix
+            ),
+            LiteralError::UnknownSuffix(suffix) => {
+                write!(f, "invalid suffix `{}` for an integer literal", suffix)
+            }
+            LiteralError::FloatSuffix(suffix) => write!(
+                f,
+                "`{}` is a float suffix; this is a floating-point literal, not an integer",
+                suffix
+            ),
+            LiteralError::OutOfRange { literal, ty } => write!(
+                f,
+                "literal out of range for `{}`: `{}` does not fit into {}",
+                ty,
+                literal,
+                ty.range()
+            ),
+            LiteralError::TooLarge => {
+                write!(f, "integer literal is too large for any integer type")
+            }
+            LiteralError::NegativeUnsigned(ty) => {
+                write!(f, "cannot apply unary operator `-` to type `{}`", ty)
+            }
+            LiteralError::InvalidByte(text) => write!(f, "`{}` is not a valid byte literal", text),
+        }
+    }
+}
+
+impl Error for LiteralError {}
+
+impl FromStr for Literal {
+    type Err = LiteralError;
+
+    fn from_str(s: &str) -> Result<Literal, LiteralError> {
+        parse(s)
+    }
+}
+
+/// Parses one literal; see the [module docs](self) for what is accepted.
+pub fn parse(text: &str) -> Result<Literal, LiteralError> {
+    parse_checked(text, true)
+}
+
+/// Like [`parse`], but a literal without a suffix takes whatever type its context needs, so
+/// only suffixed literals are range-checked. Unsuffixed ones still report `i32` as their type.
+pub fn parse_operand(text: &str) -> Result<Literal, LiteralError> {
+    parse_checked(text, false)
+}
+
+fn parse_checked(text: &str, default_to_i32: bool) -> Result<Literal, LiteralError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(LiteralError::Empty);
+    }
+
+    let (negative, body) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    if body.is_empty() {
+        return Err(LiteralError::NoDigits);
+    }
+
+    let literal = if body.starts_with("b'") {
+        Literal {
+            ty: IntType::U8,
+            suffixed: true,
+            negative,
+            magnitude: u128::from(parse_byte(body)?),
+            radix: 10,
+        }
+    } else {
+        parse_number(body, negative, text.len() - body.len())?
+    };
+    if !literal.suffixed && !default_to_i32 {
+        return Ok(literal);
+    }
+
+    if literal.negative && !literal.ty.is_signed() {
+        return Err(LiteralError::NegativeUnsigned(literal.ty));
+    }
+    let limit = if literal.negative {
+        literal.ty.min_magnitude()
+    } else {
+        literal.ty.max()
+    };
+    if literal.magnitude > limit {
+        return Err(LiteralError::OutOfRange {
+            literal: text.to_string(),
+            ty: literal.ty,
+        });
+    }
+
+    Ok(literal)
+}
+
+/// `offset` is where `body` starts in the original text, so positions point at the right place.
+fn parse_number(body: &str, negative: bool, offset: usize) -> Result<Literal, LiteralError> {
+    let (radix, digits_start) = match body.get(..2) {
+        Some("0x") => (16, 2),
+        Some("0o") => (8, 2),
+        Some("0b") => (2, 2),
+        _ => (10, 0),
+    };
+    if radix == 10 && body.starts_with('_') {
+        return Err(LiteralError::LeadingUnderscore);
+    }
+
+    // Digits run until the first character that can't be one. In hex that includes `a`-`f`, so
+    // `0x1f32` is the number 0x1f32, not 0x1 with an `f32` suffix, just like in rustc.
+    let rest = &body[digits_start..];
+    let digits_len = rest
+        .find(|c: char| !(c.is_ascii_digit() || c == '_' || (radix == 16 && c.is_ascii_hexdigit())))
+        .unwrap_or(rest.len());
+    let (digits, suffix) = rest.split_at(digits_len);
+
+    let mut magnitude: u128 = 0;
+    let mut seen_digit = false;
+    for (index, c) in digits.char_indices() {
+        if c == '_' {
+            continue;
+        }
+        let digit = c.to_digit(radix).ok_or(LiteralError::InvalidDigit {
+            digit: c,
+            position: offset + digits_start + index,
+            radix,
+        })?;
+        seen_digit = true;
+        magnitude = magnitude
+            .checked_mul(u128::from(radix))
+            .and_then(|m| m.checked_add(u128::from(digit)))
+            .ok_or(LiteralError::TooLarge)?;
+    }
+    if !seen_digit {
+        return Err(LiteralError::NoDigits);
+    }
+
+    let (ty, suffixed) = match suffix {
+        "" => (IntType::I32, false),
+        "f32" | "f64" if radix == 10 => return Err(LiteralError::FloatSuffix(suffix.to_string())),
+        _ => match suffix.parse() {
+            Ok(ty) => (ty, true),
+            Err(_) => return Err(LiteralError::UnknownSuffix(suffix.to_string())),
+        },
+    };
+
+    Ok(Literal {
+        ty,
+        suffixed,
+        negative,
+        magnitude,
+        radix,
+    })
+}
+
+/// `b'A'`, `b'\n'`, `b'\x7f'` and the other escapes a byte literal allows.
+fn parse_byte(text: &str) -> Result<u8, LiteralError> {
+    let invalid = || LiteralError::InvalidByte(text.to_string());
+    let inner = text
+        .strip_prefix("b'")
+        .and_then(|rest| rest.strip_suffix('\''))
+        .ok_or_else(invalid)?;
+
+    let byte = match inner.as_bytes() {
+        [b'\\', b'n'] => b'\n',
+        [b'\\', b'r'] => b'\r',
+        [b'\\', b't'] => b'\t',
+        [b'\\', b'\\'] => b'\\',
+        [b'\\', b'0'] => b'\0',
+        [b'\\', b'\''] => b'\'',
+        [b'\\', b'"'] => b'"',
+        [b'\\', b'x', high, low] => {
+            let hex = std::str::from_utf8(&[*high, *low])
+                .map_err(|_| invalid())?
+                .to_string();
+            u8::from_str_radix(&hex, 16).map_err(|_| invalid())?
+        }
+        // A lone quote has to be escaped, and only ASCII is allowed unescaped.
+        [byte]
+            if *byte != b'\'' && *byte != b'\\' && byte.is_ascii() && !byte.is_ascii_control() =>
+        {
+            *byte
+        }
+        _ => return Err(invalid()),
+    };
+
+    Ok(byte)
+}
+
+impl Literal {
+    /// The value as an `i128`, when it fits.
+    pub fn to_i128(&self) -> Option<i128> {
+        let magnitude = i128::try_from(self.magnitude).ok();
+        match (self.negative, magnitude) {
+            (false, magnitude) => magnitude,
+            (true, Some(magnitude)) => Some(-magnitude),
+            (true, None) if self.magnitude == 1 << 127 => Some(i128::MIN),
+            (true, None) => None,
+        }
+    }
+
+    /// The bits of the value in its type, as two's complement for negative values.
+    pub fn bits(&self) -> u128 {
+        let mask = u128::MAX >> (128 - self.ty.bits());
+        if self.negative {
+            self.magnitude.wrapping_neg() & mask
+        } else {
+            self.magnitude
+        }
+    }
+
+    /// The value written in `radix`, with `_` between groups of digits like in the book.
+    pub fn in_radix(&self, radix: u32) -> String {
+        let (prefix, digits, group) = match radix {
+            2 => ("0b", format!("{:b}", self.magnitude), 4),
+            8 => ("0o", format!("{:o}", self.magnitude), 3),
+            16 => ("0x", format!("{:x}", self.magnitude), 4),
+            _ => ("", self.magnitude.to_string(), 3),
+        };
+        let sign = if self.negative { "-" } else { "" };
+        format!("{}{}{}", sign, prefix, group_digits(&digits, group))
+    }
+
+    /// Writes the type and value, then the value in every base.
+    pub fn render(&self, out: &mut dyn Write) -> io::Result<()> {
+        let note = if self.suffixed {
+            ""
+        } else {
+            " (no suffix, so the default integer type)"
+        };
+        writeln!(out, "type:    {}{}", self.ty, note)?;
+        writeln!(out, "decimal: {}", self.in_radix(10))?;
+        writeln!(out, "hex:     {}", self.in_radix(16))?;
+        writeln!(out, "octal:   {}", self.in_radix(8))?;
+        writeln!(out, "binary:  {}", self.in_radix(2))?;
+
+        if self.negative {
+            let digits = format!(
+                "{:0width$x}",
+                self.bits(),
+                width = (self.ty.bits() / 4) as usize
+            );
+            writeln!(
+                out,
+                "bits:    0x{} (two's complement)",
+                group_digits(&digits, 4)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn group_digits(digits: &str, group: usize) -> String {
+    let mut grouped = String::new();
+    for (index, c) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(group) {
+            grouped.push('_');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, parse_operand, IntType, LiteralError};
+
+    fn value(text: &str) -> (IntType, i128) {
+        let literal = parse(text).unwrap();
+        (literal.ty, literal.to_i128().unwrap())
+    }
+
+    #[test]
+    fn reads_every_form_from_chapter_3() {
+        assert_eq!(value("98_222"), (IntType::I32, 98_222));
+        assert_eq!(value("0xff"), (IntType::I32, 0xff));
+        assert_eq!(value("0o77"), (IntType::I32, 0o77));
+        assert_eq!(value("0b1111_0000"), (IntType::I32, 0b1111_0000));
+        assert_eq!(value("b'A'"), (IntType::U8, 65));
+        assert_eq!(value("b'\\n'"), (IntType::U8, 10));
+        assert_eq!(value("b'\\xff'"), (IntType::U8, 255));
+    }
+
+    #[test]
+    fn honours_suffixes_and_signs() {
+        assert_eq!(value("255u8"), (IntType::U8, 255));
+        assert_eq!(value("-1i64"), (IntType::I64, -1));
+        assert_eq!(value("-128i8"), (IntType::I8, -128));
+        assert_eq!(value("1_000_usize"), (IntType::Usize, 1000));
+        assert_eq!(value("0x1f32"), (IntType::I32, 0x1f32));
+        assert_eq!(value("0x_ff_u16"), (IntType::U16, 255));
+    }
+
+    #[test]
+    fn reports_overflow_against_the_type() {
+        assert_eq!(
+            parse("256u8"),
+            Err(LiteralError::OutOfRange {
+                literal: String::from("256u8"),
+                ty: IntType::U8
+            })
+        );
+        assert!(parse("128i8").is_err());
+        assert!(parse("2147483648").is_err());
+        assert_eq!(
+            parse("-1u32"),
+            Err(LiteralError::NegativeUnsigned(IntType::U32))
+        );
+        assert_eq!(
+            parse("340282366920938463463374607431768211456u128"),
+            Err(LiteralError::TooLarge)
+        );
+        assert_eq!(
+            parse("256u8").unwrap_err().to_string(),
+            "literal out of range for `u8`: `256u8` does not fit into 0..=255"
+        );
+    }
+
+    #[test]
+    fn operands_without_a_suffix_are_not_held_to_i32() {
+        assert!(parse("4294967295").is_err());
+        assert_eq!(
+            parse_operand("4294967295").unwrap().magnitude,
+            4_294_967_295
+        );
+        assert!(parse_operand("256u8").is_err());
+    }
+
+    #[test]
+    fn rejects_what_rustc_rejects() {
+        assert_eq!(
+            parse("0o78"),
+            Err(LiteralError::InvalidDigit {
+                digit: '8',
+                position: 3,
+                radix: 8
+            })
+        );
+        assert_eq!(parse("0x"), Err(LiteralError::NoDigits));
+        assert_eq!(parse("0b__"), Err(LiteralError::NoDigits));
+        assert_eq!(parse("_1"), Err(LiteralError::LeadingUnderscore));
+        assert_eq!(
+            parse("1f32"),
+            Err(LiteralError::FloatSuffix(String::from("f32")))
+        );
+        assert_eq!(
+            parse("0XFF"),
+            Err(LiteralError::UnknownSuffix(String::from("XFF")))
+        );
+        assert!(parse("b'ab'").is_err());
+        assert!(parse("b'''").is_err());
+    }
+
+    #[test]
+    fn prints_the_value_in_every_base() {
+        let literal = parse("-1i16").unwrap();
+        assert_eq!(literal.in_radix(16), "-0x1");
+        assert_eq!(literal.bits(), 0xffff);
+
+        let literal = parse("0b1111_0000").unwrap();
+        assert_eq!(literal.in_radix(10), "240");
+        assert_eq!(literal.in_radix(8), "0o360");
+        assert_eq!(literal.in_radix(2), "0b1111_0000");
+        assert_eq!(parse("98222").unwrap().in_radix(10), "98_222");
+    }
+}
@@ -1,30 +1,29 @@
-#![allow(dead_code)]
-#![allow(unused_variables)]
-#![allow(unused_mut)]
-// ^ So we can write examples without the compiler complaining with warnings.
+use std::env;
+use std::io;
+use std::process;
 
-mod chapters;
+use the_rust_programming_language::cli;
 
-pub use crate::chapters::chapter02::c02;
-pub use crate::chapters::chapter03::c03;
-pub use crate::chapters::chapter04::c04;
-pub use crate::chapters::chapter05::c05;
-pub use crate::chapters::chapter06::c06;
-pub use crate::chapters::chapter07::c07;
-pub use crate::chapters::chapter08::c08;
-pub use crate::chapters::chapter09::c09;
-pub use crate::chapters::chapter10::c10;
-pub use crate::chapters::chapter11::c11;
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: the_rust_programming_language::allocations::CountingAllocator =
+    the_rust_programming_language::allocations::CountingAllocator;
 
 fn main() {
-    // c02::guessing_game();
-    // c03::common_concepts();
-    // c04::ownership();
-    // c05::structs();
-    // c06::enums();
-    // c07::packages_crates_modules();
-    // c08::common_collections();
-    // c09::error_handling();
-    // c10::generics_traits_lifetimes();
-    c11::writing_automated_tests();
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = match cli::parse(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    // The arguments were fine, so the usage wouldn't help with what went wrong.
+    if let Err(message) = cli::execute(command, &mut out) {
+        eprintln!("error: {}", message);
+        process::exit(1);
+    }
 }
@@ -0,0 +1,447 @@
+//! What each integer type does at its limits: one operation, every width, and the result under
+//! checked, wrapping, saturating and overflowing arithmetic, next to what the plain operator
+//! does in a debug build (panics) and a release build (wraps).
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use crate::literal::{IntType, Literal};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+    Shl,
+    Shr,
+    Neg,
+    Abs,
+}
+
+impl Op {
+    /// Names `FromStr` accepts, for usage messages.
+    pub const NAMES: &'static str =
+        "add (+), sub (-), mul (*), div (/), rem (%), pow, shl (<<), shr (>>), neg, abs";
+
+    pub fn is_unary(&self) -> bool {
+        matches!(self, Op::Neg | Op::Abs)
+    }
+
+    /// How the operation is written with the plain operator (or method).
+    fn expression(&self, a: &str, b: &str) -> String {
+        match self {
+            Op::Add => format!("{} + {}", a, b),
+            Op::Sub => format!("{} - {}", a, b),
+            Op::Mul => format!("{} * {}", a, b),
+            Op::Div => format!("{} / {}", a, b),
+            Op::Rem => format!("{} % {}", a, b),
+            Op::Pow => format!("{}.pow({})", a, b),
+            Op::Shl => format!("{} << {}", a, b),
+            Op::Shr => format!("{} >> {}", a, b),
+            Op::Neg => format!("-({})", a),
+            Op::Abs => format!("({}).abs()", a),
+        }
+    }
+
+    /// The message a debug build panics with when the operation overflows.
+    fn overflow_panic(&self) -> &'static str {
+        match self {
+            Op::Add => "attempt to add with overflow",
+            Op::Sub => "attempt to subtract with overflow",
+            Op::Mul | Op::Pow => "attempt to multiply with overflow",
+            Op::Div => "attempt to divide with overflow",
+            Op::Rem => "attempt to calculate the remainder with overflow",
+            Op::Shl => "attempt to shift left with overflow",
+            Op::Shr => "attempt to shift right with overflow",
+            Op::Neg | Op::Abs => "attempt to negate with overflow",
+        }
+    }
+}
+
+impl FromStr for Op {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Op, String> {
+        Ok(match s {
+            "add" | "+" => Op::Add,
+            "sub" | "-" => Op::Sub,
+            "mul" | "*" => Op::Mul,
+            "div" | "/" => Op::Div,
+            "rem" | "%" => Op::Rem,
+            "pow" => Op::Pow,
+            "shl" | "<<" => Op::Shl,
+            "shr" | ">>" => Op::Shr,
+            "neg" => Op::Neg,
+            "abs" => Op::Abs,
+            _ => {
+                return Err(format!(
+                    "unknown operation `{}` (available: {})",
+                    s,
+                    Op::NAMES
+                ))
+            }
+        })
+    }
+}
+
+/// One type's results, already formatted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cells {
+    pub checked: String,
+    pub wrapping: String,
+    pub saturating: String,
+    pub overflowing: String,
+    /// The plain operator with overflow checks on, as in a debug build.
+    pub debug: String,
+    /// The plain operator without overflow checks, as in a release build.
+    pub release: String,
+}
+
+impl Cells {
+    /// Division and remainder by zero panic in every flavour except `checked_*`; the message is
+    /// only spelled out once, in the debug column.
+    fn by_zero(op: Op) -> Cells {
+        let message = match op {
+            Op::Div => "panics: attempt to divide by zero",
+            _ => "panics: attempt to calculate the remainder with a divisor of zero",
+        };
+        Cells {
+            checked: String::from("None"),
+            wrapping: String::from("panics"),
+            saturating: String::from(if op == Op::Div { "panics" } else { "n/a" }),
+            overflowing: String::from("panics"),
+            debug: message.to_string(),
+            release: String::from("panics"),
+        }
+    }
+
+    fn new<T: fmt::Display>(
+        op: Op,
+        checked: Option<T>,
+        wrapping: T,
+        saturating: Option<T>,
+        overflowing: (T, bool),
+    ) -> Cells {
+        let panic = format!("panics: {}", op.overflow_panic());
+        let (debug, release) = match (op, overflowing.1) {
+            (_, false) => (wrapping.to_string(), wrapping.to_string()),
+            // Overflowing division panics whether or not overflow checks are on.
+            (Op::Div, true) | (Op::Rem, true) => (panic.clone(), panic),
+            (_, true) => (panic, wrapping.to_string()),
+        };
+
+        Cells {
+            checked: match checked {
+                Some(value) => format!("Some({})", value),
+                None => String::from("None"),
+            },
+            wrapping: wrapping.to_string(),
+            saturating: match saturating {
+                Some(value) => value.to_string(),
+                None => String::from("n/a"),
+            },
+            overflowing: format!("({}, {})", overflowing.0, overflowing.1),
+            debug,
+            release,
+        }
+    }
+}
+
+/// A row of the table: the results for one type, or why there are none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub ty: IntType,
+    pub cells: Result<Cells, String>,
+}
+
+/// Everything the explorer needs from an integer type; implemented for all twelve of them.
+trait Explore: Sized + Copy + fmt::Display {
+    fn from_literal(literal: &Literal) -> Option<Self>;
+
+    fn explore(op: Op, a: Self, b: Option<&Literal>) -> Result<Cells, String>;
+}
+
+/// The right-hand side of a shift or `pow` is always a `u32`.
+fn amount(b: Option<&Literal>) -> Result<u32, String> {
+    b.and_then(|b| b.to_i128())
+        .and_then(|b| u32::try_from(b).ok())
+        .ok_or_else(|| String::from("the right-hand side has to fit a u32"))
+}
+
+macro_rules! explore_binary {
+    ($op:expr, $a:expr, $b:expr, $checked:ident, $wrapping:ident, $saturating:expr, $overflowing:ident) => {{
+        let b = $b;
+        Cells::new(
+            $op,
+            $a.$checked(b),
+            $a.$wrapping(b),
+            $saturating,
+            $a.$overflowing(b),
+        )
+    }};
+}
+
+macro_rules! impl_explore {
+    ($signed:tt: $($t:ty),*) => {
+        $(
+            impl Explore for $t {
+                fn from_literal(literal: &Literal) -> Option<$t> {
+                    match literal.to_i128() {
+                        Some(value) => <$t>::try_from(value).ok(),
+                        None => <$t>::try_from(literal.magnitude).ok().filter(|_| !literal.negative),
+                    }
+                }
+
+                fn explore(op: Op, a: $t, b: Option<&Literal>) -> Result<Cells, String> {
+                    let rhs = || -> Result<$t, String> {
+                        b.and_then(<$t>::from_literal)
+                            .ok_or_else(|| format!("the right-hand side doesn't fit {}", stringify!($t)))
+                    };
+
+                    Ok(match op {
+                        Op::Add => {
+                            let b = rhs()?;
+                            explore_binary!(op, a, b, checked_add, wrapping_add, Some(a.saturating_add(b)), overflowing_add)
+                        }
+                        Op::Sub => {
+                            let b = rhs()?;
+                            explore_binary!(op, a, b, checked_sub, wrapping_sub, Some(a.saturating_sub(b)), overflowing_sub)
+                        }
+                        Op::Mul => {
+                            let b = rhs()?;
+                            explore_binary!(op, a, b, checked_mul, wrapping_mul, Some(a.saturating_mul(b)), overflowing_mul)
+                        }
+                        Op::Div | Op::Rem if rhs()? == 0 => Cells::by_zero(op),
+                        Op::Div => {
+                            let b = rhs()?;
+                            explore_binary!(op, a, b, checked_div, wrapping_div, Some(a.saturating_div(b)), overflowing_div)
+                        }
+                        Op::Rem => {
+                            // There is no `saturating_rem`.
+                            let b = rhs()?;
+                            explore_binary!(op, a, b, checked_rem, wrapping_rem, None, overflowing_rem)
+                        }
+                        Op::Pow => {
+                            let b = amount(b)?;
+                            explore_binary!(op, a, b, checked_pow, wrapping_pow, Some(a.saturating_pow(b)), overflowing_pow)
+                        }
+                        // Shifts have no saturating version either.
+                        Op::Shl => {
+                            let b = amount(b)?;
+                            explore_binary!(op, a, b, checked_shl, wrapping_shl, None, overflowing_shl)
+                        }
+                        Op::Shr => {
+                            let b = amount(b)?;
+                            explore_binary!(op, a, b, checked_shr, wrapping_shr, None, overflowing_shr)
+                        }
+                        Op::Neg | Op::Abs => impl_explore!(@unary $signed, op, a),
+                    })
+                }
+            }
+        )*
+    };
+    (@unary signed, $op:expr, $a:expr) => {
+        match $op {
+            Op::Neg => Cells::new($op, $a.checked_neg(), $a.wrapping_neg(), Some($a.saturating_neg()), $a.overflowing_neg()),
+            _ => Cells::new($op, $a.checked_abs(), $a.wrapping_abs(), Some($a.saturating_abs()), $a.overflowing_abs()),
+        }
+    };
+    (@unary unsigned, $op:expr, $a:expr) => {
+        match $op {
+            // `-x` doesn't compile for unsigned types, but the methods exist: only 0 can be negated.
+            Op::Neg => Cells {
+                debug: String::from("does not compile"),
+                release: String::from("does not compile"),
+                ..Cells::new($op, $a.checked_neg(), $a.wrapping_neg(), None, $a.overflowing_neg())
+            },
+            _ => return Err(String::from("`abs` only exists for signed types")),
+        }
+    };
+}
+
+impl_explore!(signed: i8, i16, i32, i64, i128, isize);
+impl_explore!(unsigned: u8, u16, u32, u64, u128, usize);
+
+fn row<T: Explore>(ty: IntType, op: Op, a: &Literal, b: Option<&Literal>) -> Row {
+    let cells = match T::from_literal(a) {
+        Some(a) => T::explore(op, a, b),
+        None => Err(format!("the left-hand side doesn't fit {}", ty)),
+    };
+    Row { ty, cells }
+}
+
+/// `op` applied to `a` (and `b`, unless the operation is unary) in every integer type.
+pub fn explore(op: Op, a: &Literal, b: Option<&Literal>) -> Vec<Row> {
+    IntType::ALL
+        .iter()
+        .map(|&ty| match ty {
+            IntType::I8 => row::<i8>(ty, op, a, b),
+            IntType::I16 => row::<i16>(ty, op, a, b),
+            IntType::I32 => row::<i32>(ty, op, a, b),
+            IntType::I64 => row::<i64>(ty, op, a, b),
+            IntType::I128 => row::<i128>(ty, op, a, b),
+            IntType::Isize => row::<isize>(ty, op, a, b),
+            IntType::U8 => row::<u8>(ty, op, a, b),
+            IntType::U16 => row::<u16>(ty, op, a, b),
+            IntType::U32 => row::<u32>(ty, op, a, b),
+            IntType::U64 => row::<u64>(ty, op, a, b),
+            IntType::U128 => row::<u128>(ty, op, a, b),
+            IntType::Usize => row::<usize>(ty, op, a, b),
+        })
+        .collect()
+}
+
+/// The rows side by side, each column as wide as its widest cell.
+pub fn render(
+    out: &mut dyn Write,
+    op: Op,
+    a: &str,
+    b: Option<&str>,
+    rows: &[Row],
+) -> io::Result<()> {
+    let expression = op.expression(a, b.unwrap_or(""));
+    writeln!(out, "{}", expression)?;
+    writeln!(out)?;
+
+    let header = [
+        "type",
+        "checked",
+        "wrapping",
+        "saturating",
+        "overflowing",
+        "debug build",
+        "release build",
+    ];
+    let lines: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| match &row.cells {
+            Ok(cells) => vec![
+                row.ty.to_string(),
+                cells.checked.clone(),
+                cells.wrapping.clone(),
+                cells.saturating.clone(),
+                cells.overflowing.clone(),
+                cells.debug.clone(),
+                cells.release.clone(),
+            ],
+            Err(reason) => vec![row.ty.to_string(), format!("({})", reason)],
+        })
+        .collect();
+
+    // A row that only carries a reason spans the whole table, so it doesn't widen any column.
+    let mut widths: Vec<usize> = header.iter().map(|title| title.len()).collect();
+    for line in lines.iter().filter(|line| line.len() == header.len()) {
+        for (width, cell) in widths.iter_mut().zip(line) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let write_line = |out: &mut dyn Write, cells: &[String]| -> io::Result<()> {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        writeln!(out, "{}", line.join("  ").trim_end())
+    };
+
+    let header: Vec<String> = header.iter().map(|title| title.to_string()).collect();
+    write_line(out, &header)?;
+    for line in &lines {
+        write_line(out, line)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{explore, Op, Row};
+    use crate::literal::{parse_operand, IntType};
+
+    fn rows(op: Op, a: &str, b: Option<&str>) -> Vec<Row> {
+        let a = parse_operand(a).unwrap();
+        let b = b.map(|b| parse_operand(b).unwrap());
+        explore(op, &a, b.as_ref())
+    }
+
+    fn row(rows: &[Row], ty: IntType) -> &Row {
+        rows.iter().find(|row| row.ty == ty).unwrap()
+    }
+
+    #[test]
+    fn adding_past_the_limit_behaves_differently_per_flavour() {
+        let rows = rows(Op::Add, "200", Some("100"));
+
+        let u8_row = row(&rows, IntType::U8).cells.as_ref().unwrap();
+        assert_eq!(u8_row.checked, "None");
+        assert_eq!(u8_row.wrapping, "44");
+        assert_eq!(u8_row.saturating, "255");
+        assert_eq!(u8_row.overflowing, "(44, true)");
+        assert_eq!(u8_row.debug, "panics: attempt to add with overflow");
+        assert_eq!(u8_row.release, "44");
+
+        let u16_row = row(&rows, IntType::U16).cells.as_ref().unwrap();
+        assert_eq!(u16_row.debug, "300");
+
+        // 200 doesn't fit an i8 to begin with.
+        assert!(row(&rows, IntType::I8).cells.is_err());
+    }
+
+    #[test]
+    fn dividing_min_by_minus_one_panics_even_in_release() {
+        let rows = rows(Op::Div, "-128", Some("-1"));
+
+        let i8_row = row(&rows, IntType::I8).cells.as_ref().unwrap();
+        assert_eq!(i8_row.checked, "None");
+        assert_eq!(i8_row.saturating, "127");
+        assert_eq!(i8_row.release, "panics: attempt to divide with overflow");
+    }
+
+    #[test]
+    fn dividing_by_zero_panics_unless_checked() {
+        let rows = rows(Op::Rem, "7", Some("0"));
+
+        let cells = row(&rows, IntType::U32).cells.as_ref().unwrap();
+        assert_eq!(cells.checked, "None");
+        assert_eq!(cells.wrapping, "panics");
+        assert_eq!(cells.release, "panics");
+        assert_eq!(
+            cells.debug,
+            "panics: attempt to calculate the remainder with a divisor of zero"
+        );
+    }
+
+    #[test]
+    fn shifts_overflow_on_the_amount_not_the_bits() {
+        let rows = rows(Op::Shl, "1", Some("8"));
+
+        let u8_row = row(&rows, IntType::U8).cells.as_ref().unwrap();
+        assert_eq!(u8_row.wrapping, "1");
+        assert_eq!(u8_row.debug, "panics: attempt to shift left with overflow");
+        assert_eq!(
+            row(&rows, IntType::U16).cells.as_ref().unwrap().debug,
+            "256"
+        );
+    }
+
+    #[test]
+    fn negation_is_only_for_signed_types() {
+        let rows = rows(Op::Neg, "-128", None);
+        let i8_row = row(&rows, IntType::I8).cells.as_ref().unwrap();
+        assert_eq!(i8_row.wrapping, "-128");
+        assert_eq!(i8_row.saturating, "127");
+
+        let rows = self::rows(Op::Abs, "5", None);
+        assert!(row(&rows, IntType::U8).cells.is_err());
+        assert_eq!(
+            row(&rows, IntType::U8).cells.as_ref().unwrap_err(),
+            "`abs` only exists for signed types"
+        );
+    }
+}
@@ -0,0 +1,318 @@
+//! Watching ownership happen: [`Traced`] wraps a value and logs when it is created, cloned and
+//! dropped, and [`Tracer::render`] prints the log indented by scope, so it shows exactly where
+//! each value died.
+//!
+//! Moves are plain memory copies that run no code, so they can't be observed; they are logged
+//! by moving through [`Traced::moved`] instead of a bare `let`.
+//!
+//! ```
+//! use the_rust_programming_language::trace::Tracer;
+//!
+//! let trace = Tracer::new();
+//! {
+//!     let _scope = trace.scope("main");
+//!     let s1 = trace.track("s1", String::from("hello"));
+//!     let _s2 = s1.moved("s2");
+//! }
+//! let mut out = Vec::new();
+//! trace.render(&mut out).unwrap();
+//! assert_eq!(
+//!     String::from_utf8(out).unwrap(),
+//!     "{ main\n    s1 = \"hello\"\n    s1 moved to s2\n    s2 dropped\n} main\n"
+//! );
+//! ```
+
+use std::cell::RefCell;
+use std::fmt;
+use std::io::{self, Write};
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventKind {
+    /// A value was tracked; holds its `Debug` form.
+    Created(String),
+    Moved {
+        to: String,
+    },
+    Cloned {
+        to: String,
+    },
+    Dropped,
+    ScopeEntered,
+    ScopeExited,
+    /// Free text, to say what the code is doing between events.
+    Note,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    /// How many scopes were open when it happened.
+    pub depth: usize,
+    /// The value's name, the scope's label, or the note's text.
+    pub subject: String,
+    pub kind: EventKind,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let subject = &self.subject;
+        match &self.kind {
+            EventKind::Created(value) => write!(f, "{} = {}", subject, value),
+            EventKind::Moved { to } => write!(f, "{} moved to {}", subject, to),
+            EventKind::Cloned { to } => write!(f, "{} cloned into {}", subject, to),
+            EventKind::Dropped => write!(f, "{} dropped", subject),
+            EventKind::ScopeEntered => write!(f, "{{ {}", subject),
+            EventKind::ScopeExited => write!(f, "}} {}", subject),
+            EventKind::Note => write!(f, "// {}", subject),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Log {
+    events: Vec<Event>,
+    depth: usize,
+}
+
+/// The shared log that [`Traced`] values and [`Scope`]s write to. Cloning it gives another
+/// handle on the same log.
+#[derive(Debug, Clone, Default)]
+pub struct Tracer {
+    log: Rc<RefCell<Log>>,
+}
+
+impl Tracer {
+    pub fn new() -> Tracer {
+        Tracer::default()
+    }
+
+    fn record(&self, subject: &str, kind: EventKind) {
+        let mut log = self.log.borrow_mut();
+        let depth = log.depth;
+        log.events.push(Event {
+            depth,
+            subject: subject.to_string(),
+            kind,
+        });
+    }
+
+    /// Starts tracing `value` under `name`.
+    pub fn track<T: fmt::Debug>(&self, name: &str, value: T) -> Traced<T> {
+        self.record(name, EventKind::Created(format!("{:?}", value)));
+        Traced {
+            value,
+            name: name.to_string(),
+            tracer: self.clone(),
+        }
+    }
+
+    /// Opens a scope that closes when the returned guard is dropped. Bind the guard first in
+    /// a block: locals are dropped in reverse order, so everything after it dies inside it.
+    pub fn scope(&self, label: &str) -> Scope {
+        self.record(label, EventKind::ScopeEntered);
+        self.log.borrow_mut().depth += 1;
+        Scope {
+            label: label.to_string(),
+            tracer: self.clone(),
+        }
+    }
+
+    pub fn note(&self, text: &str) {
+        self.record(text, EventKind::Note);
+    }
+
+    pub fn events(&self) -> Vec<Event> {
+        self.log.borrow().events.clone()
+    }
+
+    /// The timeline: one event per line, indented four spaces per open scope.
+    pub fn render(&self, out: &mut dyn Write) -> io::Result<()> {
+        for event in &self.log.borrow().events {
+            writeln!(out, "{:indent$}{}", "", event, indent = event.depth * 4)?;
+        }
+        Ok(())
+    }
+}
+
+/// Guard for a scope opened with [`Tracer::scope`].
+#[derive(Debug)]
+pub struct Scope {
+    label: String,
+    tracer: Tracer,
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        self.tracer.log.borrow_mut().depth -= 1;
+        self.tracer.record(&self.label, EventKind::ScopeExited);
+    }
+}
+
+/// A value that logs its clones and its drop. It derefs to the value, so it can mostly be
+/// used like one.
+pub struct Traced<T> {
+    value: T,
+    name: String,
+    tracer: Tracer,
+}
+
+impl<T> Traced<T> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Moves the value to a new owner called `to`, logging the move, as in `let s2 = s1;`.
+    pub fn moved(mut self, to: &str) -> Traced<T> {
+        self.tracer
+            .record(&self.name, EventKind::Moved { to: to.to_string() });
+        self.name = to.to_string();
+        self
+    }
+
+    /// A deep copy called `to`, as in `let s2 = s1.clone();`.
+    pub fn clone_as(&self, to: &str) -> Traced<T>
+    where
+        T: Clone,
+    {
+        self.tracer
+            .record(&self.name, EventKind::Cloned { to: to.to_string() });
+        Traced {
+            value: self.value.clone(),
+            name: to.to_string(),
+            tracer: self.tracer.clone(),
+        }
+    }
+}
+
+impl<T: Clone> Clone for Traced<T> {
+    fn clone(&self) -> Traced<T> {
+        self.clone_as(&format!("{}.clone()", self.name))
+    }
+}
+
+impl<T> Deref for Traced<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Traced<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Traced<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Traced<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T> Drop for Traced<T> {
+    fn drop(&mut self) {
+        // Runs before `value`'s own fields are dropped, so a traced `Vec` of traced elements
+        // logs itself first and then each element.
+        self.tracer.record(&self.name, EventKind::Dropped);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventKind, Tracer};
+
+    fn timeline(trace: &Tracer) -> Vec<String> {
+        trace
+            .events()
+            .iter()
+            .map(|event| format!("{}{}", "  ".repeat(event.depth), event))
+            .collect()
+    }
+
+    #[test]
+    fn values_are_dropped_in_reverse_order_at_the_end_of_their_scope() {
+        let trace = Tracer::new();
+        let outer = trace.track("outer", 1);
+        {
+            let _scope = trace.scope("inner");
+            let _a = trace.track("a", "first");
+            let _b = trace.track("b", "second");
+            trace.note("end of block");
+        }
+        drop(outer);
+
+        assert_eq!(
+            timeline(&trace),
+            [
+                "outer = 1",
+                "{ inner",
+                "  a = \"first\"",
+                "  b = \"second\"",
+                "  // end of block",
+                "  b dropped",
+                "  a dropped",
+                "} inner",
+                "outer dropped",
+            ]
+        );
+    }
+
+    #[test]
+    fn a_move_drops_nothing_and_a_clone_is_dropped_separately() {
+        let trace = Tracer::new();
+        {
+            let _scope = trace.scope("block");
+            let s1 = trace.track("s1", String::from("hello"));
+            let mut s2 = s1.moved("s2");
+            s2.push_str(", world");
+            let s3 = s2.clone();
+            assert_eq!((s3.name(), s3.as_str()), ("s2.clone()", "hello, world"));
+        }
+
+        let kinds: Vec<_> = trace
+            .events()
+            .into_iter()
+            .map(|event| (event.subject, event.kind))
+            .collect();
+        assert_eq!(
+            kinds[1..5],
+            [
+                (String::from("s1"), EventKind::Created("\"hello\"".into())),
+                (String::from("s1"), EventKind::Moved { to: "s2".into() }),
+                (
+                    String::from("s2"),
+                    EventKind::Cloned {
+                        to: "s2.clone()".into()
+                    }
+                ),
+                (String::from("s2.clone()"), EventKind::Dropped),
+            ]
+        );
+        assert_eq!(kinds[5], (String::from("s2"), EventKind::Dropped));
+        assert_eq!(kinds.len(), 7);
+    }
+
+    #[test]
+    fn dropping_a_vector_drops_its_elements() {
+        let trace = Tracer::new();
+        {
+            let _scope = trace.scope("block");
+            let _v = trace.track("v", vec![trace.track("v[0]", 1), trace.track("v[1]", 2)]);
+        }
+
+        let mut out = Vec::new();
+        trace.render(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{ block\n    v[0] = 1\n    v[1] = 2\n    v = [1, 2]\n    v dropped\n    v[0] dropped\n    v[1] dropped\n} block\n"
+        );
+    }
+}
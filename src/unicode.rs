@@ -0,0 +1,488 @@
+//! What a `char` is: one Unicode scalar value, 1 to 4 bytes of UTF-8, 1 or 2 UTF-16 code units,
+//! and not necessarily one visible character. Chapter 3's `'😻'` and chapter 8's "Hindi word"
+//! example, taken apart.
+
+mod table;
+
+use std::fmt;
+use std::io::{self, Write};
+use std::ops::Range;
+
+/// The Unicode general category, abbreviated the way the standard does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneralCategory {
+    Lu,
+    Ll,
+    Lt,
+    Lm,
+    Lo,
+    Mn,
+    Mc,
+    Me,
+    Nd,
+    Nl,
+    No,
+    Pc,
+    Pd,
+    Ps,
+    Pe,
+    Pi,
+    Pf,
+    Po,
+    Sm,
+    Sc,
+    Sk,
+    So,
+    Zs,
+    Zl,
+    Zp,
+    Cc,
+    Cf,
+    Cs,
+    Co,
+    Cn,
+}
+
+impl GeneralCategory {
+    pub fn description(&self) -> &'static str {
+        use GeneralCategory::*;
+
+        match self {
+            Lu => "Uppercase Letter",
+            Ll => "Lowercase Letter",
+            Lt => "Titlecase Letter",
+            Lm => "Modifier Letter",
+            Lo => "Other Letter",
+            Mn => "Nonspacing Mark",
+            Mc => "Spacing Mark",
+            Me => "Enclosing Mark",
+            Nd => "Decimal Number",
+            Nl => "Letter Number",
+            No => "Other Number",
+            Pc => "Connector Punctuation",
+            Pd => "Dash Punctuation",
+            Ps => "Open Punctuation",
+            Pe => "Close Punctuation",
+            Pi => "Initial Punctuation",
+            Pf => "Final Punctuation",
+            Po => "Other Punctuation",
+            Sm => "Math Symbol",
+            Sc => "Currency Symbol",
+            Sk => "Modifier Symbol",
+            So => "Other Symbol",
+            Zs => "Space Separator",
+            Zl => "Line Separator",
+            Zp => "Paragraph Separator",
+            Cc => "Control",
+            Cf => "Format",
+            Cs => "Surrogate",
+            Co => "Private Use",
+            Cn => "Unassigned",
+        }
+    }
+
+    pub fn is_mark(&self) -> bool {
+        matches!(
+            self,
+            GeneralCategory::Mn | GeneralCategory::Mc | GeneralCategory::Me
+        )
+    }
+
+    pub fn is_punctuation(&self) -> bool {
+        use GeneralCategory::*;
+        matches!(self, Pc | Pd | Ps | Pe | Pi | Pf | Po)
+    }
+}
+
+impl fmt::Display for GeneralCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} ({})", self, self.description())
+    }
+}
+
+/// The category of `c`, from the embedded table or, for letters and numbers, from `char`'s
+/// own methods. `None` for characters the table doesn't cover.
+pub fn category(c: char) -> Option<GeneralCategory> {
+    use GeneralCategory::*;
+
+    let code = c as u32;
+    let found = table::CATEGORIES.binary_search_by(|&(first, last, _)| {
+        if last < code {
+            std::cmp::Ordering::Less
+        } else if first > code {
+            std::cmp::Ordering::Greater
+        } else {
+            std::cmp::Ordering::Equal
+        }
+    });
+    if let Ok(index) = found {
+        return Some(table::CATEGORIES[index].2);
+    }
+
+    Some(if c.is_control() {
+        Cc
+    } else if c.is_uppercase() {
+        Lu
+    } else if c.is_lowercase() {
+        Ll
+    } else if c.is_alphabetic() {
+        Lo
+    } else if c.is_ascii_digit() {
+        Nd
+    } else if c.is_numeric() {
+        No
+    } else if c.is_whitespace() {
+        Zs
+    } else {
+        return None;
+    })
+}
+
+/// The character's name, e.g. `LATIN SMALL LETTER E WITH ACUTE`; `None` outside the table.
+pub fn name(c: char) -> Option<String> {
+    table::name(c)
+}
+
+/// Everything the inspector shows about one `char`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharInfo {
+    pub char: char,
+    /// Where the `char` starts in the string, in bytes.
+    pub offset: usize,
+    pub utf8: Vec<u8>,
+    pub utf16: Vec<u16>,
+    pub category: Option<GeneralCategory>,
+    pub name: Option<String>,
+}
+
+impl CharInfo {
+    pub fn new(offset: usize, c: char) -> CharInfo {
+        let mut utf8 = [0; 4];
+        let mut utf16 = [0; 2];
+        CharInfo {
+            char: c,
+            offset,
+            utf8: c.encode_utf8(&mut utf8).as_bytes().to_vec(),
+            utf16: c.encode_utf16(&mut utf16).to_vec(),
+            category: category(c),
+            name: name(c),
+        }
+    }
+
+    pub fn code_point(&self) -> String {
+        format!("U+{:04X}", self.char as u32)
+    }
+
+    /// The character as it can be shown on its own: marks on a dotted circle, invisible ones
+    /// escaped.
+    pub fn display(&self) -> String {
+        match self.category {
+            Some(category) if category.is_mark() => format!("\u{25CC}{}", self.char),
+            Some(GeneralCategory::Cc)
+            | Some(GeneralCategory::Cf)
+            | Some(GeneralCategory::Zl)
+            | Some(GeneralCategory::Zp) => self.char.escape_unicode().to_string(),
+            _ if self.char.is_whitespace() => self.char.escape_unicode().to_string(),
+            _ => self.char.to_string(),
+        }
+    }
+}
+
+pub fn inspect(text: &str) -> Vec<CharInfo> {
+    text.char_indices()
+        .map(|(offset, c)| CharInfo::new(offset, c))
+        .collect()
+}
+
+/// How a `char` takes part in joining characters into one glyph: a simplified version of
+/// Unicode's grapheme cluster rules (UAX #29).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Joining {
+    /// Attaches to whatever came before: combining marks, variation selectors, skin tones, tags.
+    Extend,
+    ZeroWidthJoiner,
+    RegionalIndicator,
+    /// Conjoining Hangul jamo: leading consonant, vowel, trailing consonant.
+    HangulLead,
+    HangulVowel,
+    HangulTail,
+    /// A precomposed syllable without (`Lv`) or with (`Lvt`) a trailing consonant.
+    HangulLv,
+    HangulLvt,
+    CarriageReturn,
+    LineFeed,
+    Other,
+}
+
+fn joining(c: char) -> Joining {
+    let code = c as u32;
+    match code {
+        0x000D => Joining::CarriageReturn,
+        0x000A => Joining::LineFeed,
+        0x200D => Joining::ZeroWidthJoiner,
+        0x200C | 0xFE00..=0xFE0F | 0x1F3FB..=0x1F3FF | 0xE0020..=0xE007F | 0xE0100..=0xE01EF => {
+            Joining::Extend
+        }
+        0x1F1E6..=0x1F1FF => Joining::RegionalIndicator,
+        0x1100..=0x115F => Joining::HangulLead,
+        0x1160..=0x11A7 => Joining::HangulVowel,
+        0x11A8..=0x11FF => Joining::HangulTail,
+        _ if table::HANGUL_SYLLABLES.contains(&code) => {
+            if (code - table::HANGUL_SYLLABLES.start()).is_multiple_of(table::HANGUL_TAIL_COUNT) {
+                Joining::HangulLv
+            } else {
+                Joining::HangulLvt
+            }
+        }
+        _ if category(c).is_some_and(|category| category.is_mark()) => Joining::Extend,
+        _ => Joining::Other,
+    }
+}
+
+/// The byte ranges of what a reader sees as single characters.
+pub fn clusters(text: &str) -> Vec<Range<usize>> {
+    let mut clusters: Vec<Range<usize>> = Vec::new();
+    let mut previous = None;
+    // Regional indicators pair up into flags, so an odd one starts a new flag.
+    let mut indicators = 0;
+
+    for (offset, c) in text.char_indices() {
+        let current = joining(c);
+        let joins = match (previous, current) {
+            (None, _) => false,
+            (Some(Joining::CarriageReturn), Joining::LineFeed) => true,
+            (Some(Joining::CarriageReturn), _)
+            | (Some(Joining::LineFeed), _)
+            | (_, Joining::CarriageReturn)
+            | (_, Joining::LineFeed) => false,
+            (_, Joining::Extend) | (_, Joining::ZeroWidthJoiner) => true,
+            (Some(Joining::ZeroWidthJoiner), _) => true,
+            (Some(Joining::RegionalIndicator), Joining::RegionalIndicator) => indicators % 2 == 1,
+            (Some(Joining::HangulLead), Joining::HangulLead)
+            | (Some(Joining::HangulLead), Joining::HangulVowel)
+            | (Some(Joining::HangulLead), Joining::HangulLv)
+            | (Some(Joining::HangulLead), Joining::HangulLvt)
+            | (Some(Joining::HangulLv), Joining::HangulVowel)
+            | (Some(Joining::HangulVowel), Joining::HangulVowel)
+            | (Some(Joining::HangulLv), Joining::HangulTail)
+            | (Some(Joining::HangulVowel), Joining::HangulTail)
+            | (Some(Joining::HangulLvt), Joining::HangulTail)
+            | (Some(Joining::HangulTail), Joining::HangulTail) => true,
+            _ => false,
+        };
+
+        indicators = match current {
+            Joining::RegionalIndicator => indicators + 1,
+            _ => 0,
+        };
+        let end = offset + c.len_utf8();
+        match clusters.last_mut() {
+            Some(cluster) if joins => cluster.end = end,
+            _ => clusters.push(offset..end),
+        }
+        previous = Some(current);
+    }
+
+    clusters
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    format!("{} {}{}", count, noun, if count == 1 { "" } else { "s" })
+}
+
+/// One row per `char`, then the glyphs that take more than one.
+pub fn render(out: &mut dyn Write, text: &str) -> io::Result<()> {
+    let chars = inspect(text);
+    let clusters = clusters(text);
+    writeln!(
+        out,
+        "{:?}: {}, {}, {}, {}",
+        text,
+        plural(chars.len(), "char"),
+        plural(text.len(), "UTF-8 byte"),
+        plural(text.encode_utf16().count(), "UTF-16 unit"),
+        plural(clusters.len(), "glyph")
+    )?;
+
+    let rows: Vec<[String; 6]> = chars
+        .iter()
+        .map(|info| {
+            let hex = |units: Vec<String>| units.join(" ");
+            [
+                info.code_point(),
+                hex(info.utf8.iter().map(|b| format!("{:02X}", b)).collect()),
+                hex(info.utf16.iter().map(|u| format!("{:04X}", u)).collect()),
+                info.category
+                    .map_or_else(|| String::from("?"), |category| category.to_string()),
+                info.name.clone().unwrap_or_else(|| String::from("?")),
+                info.display(),
+            ]
+        })
+        .collect();
+
+    let header = ["code point", "UTF-8", "UTF-16", "category", "name", "char"];
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    // The character goes last: emoji and CJK take two columns in a terminal, marks none.
+    let line = |cells: &[String]| -> String {
+        let mut line: Vec<String> = cells[..5]
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        line.push(cells[5].clone());
+        line.join("  ")
+    };
+    writeln!(out, "{}", line(&header.map(String::from)))?;
+    for row in &rows {
+        writeln!(out, "{}", line(row))?;
+    }
+
+    let combined: Vec<&Range<usize>> = clusters
+        .iter()
+        .filter(|cluster| text[(*cluster).clone()].chars().count() > 1)
+        .collect();
+    if !combined.is_empty() {
+        writeln!(out, "Combining sequences (one glyph, several chars):")?;
+        for cluster in combined {
+            let glyph = &text[cluster.clone()];
+            let code_points: Vec<String> = glyph
+                .chars()
+                .map(|c| format!("U+{:04X}", c as u32))
+                .collect();
+            writeln!(out, "  {} = {}", glyph, code_points.join(" "))?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{category, clusters, inspect, name, render, table, GeneralCategory};
+
+    #[test]
+    fn a_heart_eyed_cat_is_one_char_of_four_bytes() {
+        let cat = &inspect("😻")[0];
+        assert_eq!(cat.code_point(), "U+1F63B");
+        assert_eq!(cat.utf8, [0xF0, 0x9F, 0x98, 0xBB]);
+        assert_eq!(cat.utf16, [0xD83D, 0xDE3B]);
+        assert_eq!(cat.category, Some(GeneralCategory::So));
+        assert_eq!(
+            cat.name.as_deref(),
+            Some("SMILING CAT FACE WITH HEART-SHAPED EYES")
+        );
+    }
+
+    #[test]
+    fn names_come_from_the_table_or_unicode_s_own_rules() {
+        assert_eq!(
+            name('é').as_deref(),
+            Some("LATIN SMALL LETTER E WITH ACUTE")
+        );
+        assert_eq!(name('7').as_deref(), Some("DIGIT SEVEN"));
+        assert_eq!(name('ς').as_deref(), Some("GREEK SMALL LETTER FINAL SIGMA"));
+        assert_eq!(name('Ω').as_deref(), Some("GREEK CAPITAL LETTER OMEGA"));
+        assert_eq!(name('中').as_deref(), Some("CJK UNIFIED IDEOGRAPH-4E2D"));
+        assert_eq!(name('한').as_deref(), Some("HANGUL SYLLABLE HAN"));
+        assert_eq!(name('글').as_deref(), Some("HANGUL SYLLABLE GEUL"));
+        assert_eq!(
+            name('\t').as_deref(),
+            Some("<control> (CHARACTER TABULATION)")
+        );
+        assert_eq!(name('ж'), None);
+    }
+
+    #[test]
+    fn tables_are_sorted_for_binary_search() {
+        assert!(table::CATEGORIES
+            .windows(2)
+            .all(|pair| pair[0].0 <= pair[0].1 && pair[0].1 < pair[1].0));
+        assert!(table::NAMES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn categories_cover_letters_marks_and_symbols() {
+        use GeneralCategory::*;
+
+        assert_eq!(category('A'), Some(Lu));
+        assert_eq!(category('ж'), Some(Ll));
+        assert_eq!(category('中'), Some(Lo));
+        assert_eq!(category('\u{301}'), Some(Mn));
+        assert_eq!(category('\u{A0}'), Some(Zs));
+        assert_eq!(category('€'), Some(Sc));
+        assert_eq!(category('_'), Some(Pc));
+        assert_eq!(category('٣'), Some(Nd));
+        assert_eq!(category('½'), Some(No));
+    }
+
+    #[test]
+    fn letters_that_look_cased_keep_their_own_category() {
+        use GeneralCategory::*;
+
+        assert_eq!(category('ǅ'), Some(Lt));
+        assert_eq!(category('Ⅰ'), Some(Nl));
+        assert_eq!(category('ⅰ'), Some(Nl));
+        assert_eq!(category('ʰ'), Some(Lm));
+        assert_eq!(category('Ⓐ'), Some(So));
+        // Thai vowel sign I and tone mark mai ek are marks, not letters.
+        assert_eq!(category('\u{E35}'), Some(Mn));
+        assert_eq!(category('\u{E48}'), Some(Mn));
+    }
+
+    #[test]
+    fn categories_cover_punctuation_beyond_ascii() {
+        use GeneralCategory::*;
+
+        for c in [
+            '※', '‽', '⁂', '،', '؛', '؟', '।', '॥', '〔', '・', '！', '，', '｡',
+        ] {
+            assert_eq!(category(c).map(|c| c.is_punctuation()), Some(true), "{}", c);
+        }
+        assert_eq!(category('‿'), Some(Pc));
+        assert_eq!(category('〜'), Some(Pd));
+        assert_eq!(category('⁄'), Some(Sm));
+    }
+
+    #[test]
+    fn the_summary_counts_in_the_singular_too() {
+        let mut out = Vec::new();
+        render(&mut out, "a").unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(
+            out.lines().next(),
+            Some("\"a\": 1 char, 1 UTF-8 byte, 1 UTF-16 unit, 1 glyph")
+        );
+    }
+
+    #[test]
+    fn combining_sequences_are_one_glyph() {
+        let glyphs = |text: &str| -> Vec<String> {
+            clusters(text)
+                .into_iter()
+                .map(|range| text[range].to_string())
+                .collect()
+        };
+
+        // An e and a combining acute accent, next to a precomposed é.
+        assert_eq!(glyphs("e\u{301}é"), ["e\u{301}", "é"]);
+        // Man, zero width joiner, woman, zero width joiner, girl: one family.
+        assert_eq!(glyphs("👨\u{200D}👩\u{200D}👧!").len(), 2);
+        // A thumbs up with a skin tone, and two flags made of regional indicators.
+        assert_eq!(glyphs("👍🏽🇫🇷🇯🇵"), ["👍🏽", "🇫🇷", "🇯🇵"]);
+        // Hangul written as separate jamo.
+        assert_eq!(
+            glyphs("\u{1112}\u{1161}\u{11AB}"),
+            ["\u{1112}\u{1161}\u{11AB}"]
+        );
+        // Chapter 8's Hindi word: 6 chars, but the book's 4 grapheme clusters.
+        assert_eq!(glyphs("नमस्ते"), ["न", "म", "स्", "ते"]);
+        // Thai stacks a vowel sign and a tone mark on each consonant: "here".
+        assert_eq!(glyphs("ที่นี่"), ["ที่", "นี่"]);
+        assert_eq!(glyphs("\r\n"), ["\r\n"]);
+    }
+}
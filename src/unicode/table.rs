@@ -0,0 +1,1191 @@
+//! The slice of the Unicode Character Database the inspector knows about.
+//!
+//! The full database is megabytes, so this embeds the common blocks (ASCII, Latin-1, Greek,
+//! combining marks, the General Punctuation block, the punctuation of Arabic, Devanagari, CJK
+//! and fullwidth forms, chapter 8's Hindi word, the emoji used around this crate) and derives
+//! the names Unicode itself derives (Hangul syllables, CJK ideographs, regional indicators).
+//! Letters and numbers outside the table get their category from `char`'s own methods, so the
+//! table also lists every character those methods would misreport (Unicode 14).
+
+use super::GeneralCategory::{self, *};
+
+/// `(first, last, category)` for the ranges `char`'s methods can't classify, sorted. That
+/// includes the marks, titlecase and modifier letters and letter numbers that `is_alphabetic`
+/// or `is_uppercase` would take for plain letters, and the non-ASCII decimal digits, which
+/// `is_numeric` can't tell from other numbers.
+pub(super) const CATEGORIES: &[(u32, u32, GeneralCategory)] = &[
+    (0x0020, 0x0020, Zs),
+    (0x0021, 0x0023, Po),
+    (0x0024, 0x0024, Sc),
+    (0x0025, 0x0027, Po),
+    (0x0028, 0x0028, Ps),
+    (0x0029, 0x0029, Pe),
+    (0x002A, 0x002A, Po),
+    (0x002B, 0x002B, Sm),
+    (0x002C, 0x002C, Po),
+    (0x002D, 0x002D, Pd),
+    (0x002E, 0x002F, Po),
+    (0x003A, 0x003B, Po),
+    (0x003C, 0x003E, Sm),
+    (0x003F, 0x0040, Po),
+    (0x005B, 0x005B, Ps),
+    (0x005C, 0x005C, Po),
+    (0x005D, 0x005D, Pe),
+    (0x005E, 0x005E, Sk),
+    (0x005F, 0x005F, Pc),
+    (0x0060, 0x0060, Sk),
+    (0x007B, 0x007B, Ps),
+    (0x007C, 0x007C, Sm),
+    (0x007D, 0x007D, Pe),
+    (0x007E, 0x007E, Sm),
+    (0x00A0, 0x00A0, Zs),
+    (0x00A1, 0x00A1, Po),
+    (0x00A2, 0x00A5, Sc),
+    (0x00A6, 0x00A6, So),
+    (0x00A7, 0x00A7, Po),
+    (0x00A8, 0x00A8, Sk),
+    (0x00A9, 0x00A9, So),
+    (0x00AA, 0x00AA, Lo),
+    (0x00AB, 0x00AB, Pi),
+    (0x00AC, 0x00AC, Sm),
+    (0x00AD, 0x00AD, Cf),
+    (0x00AE, 0x00AE, So),
+    (0x00AF, 0x00AF, Sk),
+    (0x00B0, 0x00B0, So),
+    (0x00B1, 0x00B1, Sm),
+    (0x00B2, 0x00B3, No),
+    (0x00B4, 0x00B4, Sk),
+    (0x00B5, 0x00B5, Ll),
+    (0x00B6, 0x00B7, Po),
+    (0x00B8, 0x00B8, Sk),
+    (0x00B9, 0x00B9, No),
+    (0x00BA, 0x00BA, Lo),
+    (0x00BB, 0x00BB, Pf),
+    (0x00BC, 0x00BE, No),
+    (0x00BF, 0x00BF, Po),
+    (0x00D7, 0x00D7, Sm),
+    (0x00F7, 0x00F7, Sm),
+    (0x01C5, 0x01C5, Lt),
+    (0x01C8, 0x01C8, Lt),
+    (0x01CB, 0x01CB, Lt),
+    (0x01F2, 0x01F2, Lt),
+    (0x02B0, 0x02C1, Lm),
+    (0x02C6, 0x02D1, Lm),
+    (0x02E0, 0x02E4, Lm),
+    (0x02EC, 0x02EC, Lm),
+    (0x02EE, 0x02EE, Lm),
+    (0x0300, 0x036F, Mn),
+    (0x0374, 0x0374, Lm),
+    (0x037A, 0x037A, Lm),
+    (0x0483, 0x0487, Mn),
+    (0x0488, 0x0489, Me),
+    (0x0559, 0x0559, Lm),
+    (0x0591, 0x05BD, Mn),
+    (0x05BF, 0x05BF, Mn),
+    (0x05C1, 0x05C2, Mn),
+    (0x05C4, 0x05C5, Mn),
+    (0x05C7, 0x05C7, Mn),
+    (0x0609, 0x060A, Po),
+    (0x060C, 0x060D, Po),
+    (0x0610, 0x061A, Mn),
+    (0x061B, 0x061B, Po),
+    (0x061D, 0x061F, Po),
+    (0x0640, 0x0640, Lm),
+    (0x064B, 0x065F, Mn),
+    (0x0660, 0x0669, Nd),
+    (0x066A, 0x066D, Po),
+    (0x0670, 0x0670, Mn),
+    (0x06D4, 0x06D4, Po),
+    (0x06D6, 0x06DC, Mn),
+    (0x06DF, 0x06E4, Mn),
+    (0x06E5, 0x06E6, Lm),
+    (0x06E7, 0x06E8, Mn),
+    (0x06EA, 0x06ED, Mn),
+    (0x06F0, 0x06F9, Nd),
+    (0x0711, 0x0711, Mn),
+    (0x0730, 0x074A, Mn),
+    (0x07A6, 0x07B0, Mn),
+    (0x07C0, 0x07C9, Nd),
+    (0x07EB, 0x07F3, Mn),
+    (0x07F4, 0x07F5, Lm),
+    (0x07FA, 0x07FA, Lm),
+    (0x07FD, 0x07FD, Mn),
+    (0x0816, 0x0819, Mn),
+    (0x081A, 0x081A, Lm),
+    (0x081B, 0x0823, Mn),
+    (0x0824, 0x0824, Lm),
+    (0x0825, 0x0827, Mn),
+    (0x0828, 0x0828, Lm),
+    (0x0829, 0x082D, Mn),
+    (0x0859, 0x085B, Mn),
+    (0x0898, 0x089F, Mn),
+    (0x08C9, 0x08C9, Lm),
+    (0x08CA, 0x08E1, Mn),
+    (0x08E3, 0x0902, Mn),
+    (0x0903, 0x0903, Mc),
+    (0x093A, 0x093A, Mn),
+    (0x093B, 0x093B, Mc),
+    (0x093C, 0x093C, Mn),
+    (0x093E, 0x0940, Mc),
+    (0x0941, 0x0948, Mn),
+    (0x0949, 0x094C, Mc),
+    (0x094D, 0x094D, Mn),
+    (0x094E, 0x094F, Mc),
+    (0x0951, 0x0957, Mn),
+    (0x0962, 0x0963, Mn),
+    (0x0964, 0x0965, Po),
+    (0x0966, 0x096F, Nd),
+    (0x0970, 0x0970, Po),
+    (0x0971, 0x0971, Lm),
+    (0x0981, 0x0981, Mn),
+    (0x0982, 0x0983, Mc),
+    (0x09BC, 0x09BC, Mn),
+    (0x09BE, 0x09C0, Mc),
+    (0x09C1, 0x09C4, Mn),
+    (0x09C7, 0x09C8, Mc),
+    (0x09CB, 0x09CC, Mc),
+    (0x09CD, 0x09CD, Mn),
+    (0x09D7, 0x09D7, Mc),
+    (0x09E2, 0x09E3, Mn),
+    (0x09E6, 0x09EF, Nd),
+    (0x09FE, 0x09FE, Mn),
+    (0x0A01, 0x0A02, Mn),
+    (0x0A03, 0x0A03, Mc),
+    (0x0A3C, 0x0A3C, Mn),
+    (0x0A3E, 0x0A40, Mc),
+    (0x0A41, 0x0A42, Mn),
+    (0x0A47, 0x0A48, Mn),
+    (0x0A4B, 0x0A4D, Mn),
+    (0x0A51, 0x0A51, Mn),
+    (0x0A66, 0x0A6F, Nd),
+    (0x0A70, 0x0A71, Mn),
+    (0x0A75, 0x0A75, Mn),
+    (0x0A81, 0x0A82, Mn),
+    (0x0A83, 0x0A83, Mc),
+    (0x0ABC, 0x0ABC, Mn),
+    (0x0ABE, 0x0AC0, Mc),
+    (0x0AC1, 0x0AC5, Mn),
+    (0x0AC7, 0x0AC8, Mn),
+    (0x0AC9, 0x0AC9, Mc),
+    (0x0ACB, 0x0ACC, Mc),
+    (0x0ACD, 0x0ACD, Mn),
+    (0x0AE2, 0x0AE3, Mn),
+    (0x0AE6, 0x0AEF, Nd),
+    (0x0AFA, 0x0AFF, Mn),
+    (0x0B01, 0x0B01, Mn),
+    (0x0B02, 0x0B03, Mc),
+    (0x0B3C, 0x0B3C, Mn),
+    (0x0B3E, 0x0B3E, Mc),
+    (0x0B3F, 0x0B3F, Mn),
+    (0x0B40, 0x0B40, Mc),
+    (0x0B41, 0x0B44, Mn),
+    (0x0B47, 0x0B48, Mc),
+    (0x0B4B, 0x0B4C, Mc),
+    (0x0B4D, 0x0B4D, Mn),
+    (0x0B55, 0x0B56, Mn),
+    (0x0B57, 0x0B57, Mc),
+    (0x0B62, 0x0B63, Mn),
+    (0x0B66, 0x0B6F, Nd),
+    (0x0B82, 0x0B82, Mn),
+    (0x0BBE, 0x0BBF, Mc),
+    (0x0BC0, 0x0BC0, Mn),
+    (0x0BC1, 0x0BC2, Mc),
+    (0x0BC6, 0x0BC8, Mc),
+    (0x0BCA, 0x0BCC, Mc),
+    (0x0BCD, 0x0BCD, Mn),
+    (0x0BD7, 0x0BD7, Mc),
+    (0x0BE6, 0x0BEF, Nd),
+    (0x0C00, 0x0C00, Mn),
+    (0x0C01, 0x0C03, Mc),
+    (0x0C04, 0x0C04, Mn),
+    (0x0C3C, 0x0C3C, Mn),
+    (0x0C3E, 0x0C40, Mn),
+    (0x0C41, 0x0C44, Mc),
+    (0x0C46, 0x0C48, Mn),
+    (0x0C4A, 0x0C4D, Mn),
+    (0x0C55, 0x0C56, Mn),
+    (0x0C62, 0x0C63, Mn),
+    (0x0C66, 0x0C6F, Nd),
+    (0x0C81, 0x0C81, Mn),
+    (0x0C82, 0x0C83, Mc),
+    (0x0CBC, 0x0CBC, Mn),
+    (0x0CBE, 0x0CBE, Mc),
+    (0x0CBF, 0x0CBF, Mn),
+    (0x0CC0, 0x0CC4, Mc),
+    (0x0CC6, 0x0CC6, Mn),
+    (0x0CC7, 0x0CC8, Mc),
+    (0x0CCA, 0x0CCB, Mc),
+    (0x0CCC, 0x0CCD, Mn),
+    (0x0CD5, 0x0CD6, Mc),
+    (0x0CE2, 0x0CE3, Mn),
+    (0x0CE6, 0x0CEF, Nd),
+    (0x0D00, 0x0D01, Mn),
+    (0x0D02, 0x0D03, Mc),
+    (0x0D3B, 0x0D3C, Mn),
+    (0x0D3E, 0x0D40, Mc),
+    (0x0D41, 0x0D44, Mn),
+    (0x0D46, 0x0D48, Mc),
+    (0x0D4A, 0x0D4C, Mc),
+    (0x0D4D, 0x0D4D, Mn),
+    (0x0D57, 0x0D57, Mc),
+    (0x0D62, 0x0D63, Mn),
+    (0x0D66, 0x0D6F, Nd),
+    (0x0D81, 0x0D81, Mn),
+    (0x0D82, 0x0D83, Mc),
+    (0x0DCA, 0x0DCA, Mn),
+    (0x0DCF, 0x0DD1, Mc),
+    (0x0DD2, 0x0DD4, Mn),
+    (0x0DD6, 0x0DD6, Mn),
+    (0x0DD8, 0x0DDF, Mc),
+    (0x0DE6, 0x0DEF, Nd),
+    (0x0DF2, 0x0DF3, Mc),
+    (0x0E31, 0x0E31, Mn),
+    (0x0E34, 0x0E3A, Mn),
+    (0x0E46, 0x0E46, Lm),
+    (0x0E47, 0x0E4E, Mn),
+    (0x0E50, 0x0E59, Nd),
+    (0x0EB1, 0x0EB1, Mn),
+    (0x0EB4, 0x0EBC, Mn),
+    (0x0EC6, 0x0EC6, Lm),
+    (0x0EC8, 0x0ECD, Mn),
+    (0x0ED0, 0x0ED9, Nd),
+    (0x0F18, 0x0F19, Mn),
+    (0x0F20, 0x0F29, Nd),
+    (0x0F35, 0x0F35, Mn),
+    (0x0F37, 0x0F37, Mn),
+    (0x0F39, 0x0F39, Mn),
+    (0x0F3E, 0x0F3F, Mc),
+    (0x0F71, 0x0F7E, Mn),
+    (0x0F7F, 0x0F7F, Mc),
+    (0x0F80, 0x0F84, Mn),
+    (0x0F86, 0x0F87, Mn),
+    (0x0F8D, 0x0F97, Mn),
+    (0x0F99, 0x0FBC, Mn),
+    (0x0FC6, 0x0FC6, Mn),
+    (0x102B, 0x102C, Mc),
+    (0x102D, 0x1030, Mn),
+    (0x1031, 0x1031, Mc),
+    (0x1032, 0x1037, Mn),
+    (0x1038, 0x1038, Mc),
+    (0x1039, 0x103A, Mn),
+    (0x103B, 0x103C, Mc),
+    (0x103D, 0x103E, Mn),
+    (0x1040, 0x1049, Nd),
+    (0x1056, 0x1057, Mc),
+    (0x1058, 0x1059, Mn),
+    (0x105E, 0x1060, Mn),
+    (0x1062, 0x1064, Mc),
+    (0x1067, 0x106D, Mc),
+    (0x1071, 0x1074, Mn),
+    (0x1082, 0x1082, Mn),
+    (0x1083, 0x1084, Mc),
+    (0x1085, 0x1086, Mn),
+    (0x1087, 0x108C, Mc),
+    (0x108D, 0x108D, Mn),
+    (0x108F, 0x108F, Mc),
+    (0x1090, 0x1099, Nd),
+    (0x109A, 0x109C, Mc),
+    (0x109D, 0x109D, Mn),
+    (0x10FC, 0x10FC, Lm),
+    (0x135D, 0x135F, Mn),
+    (0x16EE, 0x16F0, Nl),
+    (0x1712, 0x1714, Mn),
+    (0x1715, 0x1715, Mc),
+    (0x1732, 0x1733, Mn),
+    (0x1734, 0x1734, Mc),
+    (0x1752, 0x1753, Mn),
+    (0x1772, 0x1773, Mn),
+    (0x17B4, 0x17B5, Mn),
+    (0x17B6, 0x17B6, Mc),
+    (0x17B7, 0x17BD, Mn),
+    (0x17BE, 0x17C5, Mc),
+    (0x17C6, 0x17C6, Mn),
+    (0x17C7, 0x17C8, Mc),
+    (0x17C9, 0x17D3, Mn),
+    (0x17D7, 0x17D7, Lm),
+    (0x17DD, 0x17DD, Mn),
+    (0x17E0, 0x17E9, Nd),
+    (0x180B, 0x180D, Mn),
+    (0x180F, 0x180F, Mn),
+    (0x1810, 0x1819, Nd),
+    (0x1843, 0x1843, Lm),
+    (0x1885, 0x1886, Mn),
+    (0x18A9, 0x18A9, Mn),
+    (0x1920, 0x1922, Mn),
+    (0x1923, 0x1926, Mc),
+    (0x1927, 0x1928, Mn),
+    (0x1929, 0x192B, Mc),
+    (0x1930, 0x1931, Mc),
+    (0x1932, 0x1932, Mn),
+    (0x1933, 0x1938, Mc),
+    (0x1939, 0x193B, Mn),
+    (0x1946, 0x194F, Nd),
+    (0x19D0, 0x19D9, Nd),
+    (0x1A17, 0x1A18, Mn),
+    (0x1A19, 0x1A1A, Mc),
+    (0x1A1B, 0x1A1B, Mn),
+    (0x1A55, 0x1A55, Mc),
+    (0x1A56, 0x1A56, Mn),
+    (0x1A57, 0x1A57, Mc),
+    (0x1A58, 0x1A5E, Mn),
+    (0x1A60, 0x1A60, Mn),
+    (0x1A61, 0x1A61, Mc),
+    (0x1A62, 0x1A62, Mn),
+    (0x1A63, 0x1A64, Mc),
+    (0x1A65, 0x1A6C, Mn),
+    (0x1A6D, 0x1A72, Mc),
+    (0x1A73, 0x1A7C, Mn),
+    (0x1A7F, 0x1A7F, Mn),
+    (0x1A80, 0x1A89, Nd),
+    (0x1A90, 0x1A99, Nd),
+    (0x1AA7, 0x1AA7, Lm),
+    (0x1AB0, 0x1ABD, Mn),
+    (0x1ABE, 0x1ABE, Me),
+    (0x1ABF, 0x1ACE, Mn),
+    (0x1B00, 0x1B03, Mn),
+    (0x1B04, 0x1B04, Mc),
+    (0x1B34, 0x1B34, Mn),
+    (0x1B35, 0x1B35, Mc),
+    (0x1B36, 0x1B3A, Mn),
+    (0x1B3B, 0x1B3B, Mc),
+    (0x1B3C, 0x1B3C, Mn),
+    (0x1B3D, 0x1B41, Mc),
+    (0x1B42, 0x1B42, Mn),
+    (0x1B43, 0x1B44, Mc),
+    (0x1B50, 0x1B59, Nd),
+    (0x1B6B, 0x1B73, Mn),
+    (0x1B80, 0x1B81, Mn),
+    (0x1B82, 0x1B82, Mc),
+    (0x1BA1, 0x1BA1, Mc),
+    (0x1BA2, 0x1BA5, Mn),
+    (0x1BA6, 0x1BA7, Mc),
+    (0x1BA8, 0x1BA9, Mn),
+    (0x1BAA, 0x1BAA, Mc),
+    (0x1BAB, 0x1BAD, Mn),
+    (0x1BB0, 0x1BB9, Nd),
+    (0x1BE6, 0x1BE6, Mn),
+    (0x1BE7, 0x1BE7, Mc),
+    (0x1BE8, 0x1BE9, Mn),
+    (0x1BEA, 0x1BEC, Mc),
+    (0x1BED, 0x1BED, Mn),
+    (0x1BEE, 0x1BEE, Mc),
+    (0x1BEF, 0x1BF1, Mn),
+    (0x1BF2, 0x1BF3, Mc),
+    (0x1C24, 0x1C2B, Mc),
+    (0x1C2C, 0x1C33, Mn),
+    (0x1C34, 0x1C35, Mc),
+    (0x1C36, 0x1C37, Mn),
+    (0x1C40, 0x1C49, Nd),
+    (0x1C50, 0x1C59, Nd),
+    (0x1C78, 0x1C7D, Lm),
+    (0x1CD0, 0x1CD2, Mn),
+    (0x1CD4, 0x1CE0, Mn),
+    (0x1CE1, 0x1CE1, Mc),
+    (0x1CE2, 0x1CE8, Mn),
+    (0x1CED, 0x1CED, Mn),
+    (0x1CF4, 0x1CF4, Mn),
+    (0x1CF7, 0x1CF7, Mc),
+    (0x1CF8, 0x1CF9, Mn),
+    (0x1D2C, 0x1D6A, Lm),
+    (0x1D78, 0x1D78, Lm),
+    (0x1D9B, 0x1DBF, Lm),
+    (0x1DC0, 0x1DFF, Mn),
+    (0x1F88, 0x1F8F, Lt),
+    (0x1F98, 0x1F9F, Lt),
+    (0x1FA8, 0x1FAF, Lt),
+    (0x1FBC, 0x1FBC, Lt),
+    (0x1FCC, 0x1FCC, Lt),
+    (0x1FFC, 0x1FFC, Lt),
+    (0x2000, 0x200A, Zs),
+    (0x200B, 0x200F, Cf),
+    (0x2010, 0x2015, Pd),
+    (0x2016, 0x2017, Po),
+    (0x2018, 0x2018, Pi),
+    (0x2019, 0x2019, Pf),
+    (0x201A, 0x201A, Ps),
+    (0x201B, 0x201C, Pi),
+    (0x201D, 0x201D, Pf),
+    (0x201E, 0x201E, Ps),
+    (0x201F, 0x201F, Pi),
+    (0x2020, 0x2027, Po),
+    (0x2028, 0x2028, Zl),
+    (0x2029, 0x2029, Zp),
+    (0x202A, 0x202E, Cf),
+    (0x202F, 0x202F, Zs),
+    (0x2030, 0x2038, Po),
+    (0x2039, 0x2039, Pi),
+    (0x203A, 0x203A, Pf),
+    (0x203B, 0x203E, Po),
+    (0x203F, 0x2040, Pc),
+    (0x2041, 0x2043, Po),
+    (0x2044, 0x2044, Sm),
+    (0x2045, 0x2045, Ps),
+    (0x2046, 0x2046, Pe),
+    (0x2047, 0x2051, Po),
+    (0x2052, 0x2052, Sm),
+    (0x2053, 0x2053, Po),
+    (0x2054, 0x2054, Pc),
+    (0x2055, 0x205E, Po),
+    (0x205F, 0x205F, Zs),
+    (0x2060, 0x2064, Cf),
+    (0x2066, 0x206F, Cf),
+    (0x2071, 0x2071, Lm),
+    (0x207F, 0x207F, Lm),
+    (0x2090, 0x209C, Lm),
+    (0x20A0, 0x20C0, Sc),
+    (0x20D0, 0x20DC, Mn),
+    (0x20DD, 0x20E0, Me),
+    (0x20E1, 0x20E1, Mn),
+    (0x20E2, 0x20E4, Me),
+    (0x20E5, 0x20F0, Mn),
+    (0x2160, 0x2182, Nl),
+    (0x2185, 0x2188, Nl),
+    (0x2190, 0x2194, Sm),
+    (0x2195, 0x2199, So),
+    (0x2200, 0x22FF, Sm),
+    (0x24B6, 0x24E9, So),
+    (0x2600, 0x266E, So),
+    (0x266F, 0x266F, Sm),
+    (0x2670, 0x2767, So),
+    (0x2B00, 0x2B2F, So),
+    (0x2C7C, 0x2C7D, Lm),
+    (0x2CEF, 0x2CF1, Mn),
+    (0x2D6F, 0x2D6F, Lm),
+    (0x2D7F, 0x2D7F, Mn),
+    (0x2DE0, 0x2DFF, Mn),
+    (0x2E2F, 0x2E2F, Lm),
+    (0x3000, 0x3000, Zs),
+    (0x3001, 0x3003, Po),
+    (0x3005, 0x3005, Lm),
+    (0x3007, 0x3007, Nl),
+    (0x3008, 0x3008, Ps),
+    (0x3009, 0x3009, Pe),
+    (0x300A, 0x300A, Ps),
+    (0x300B, 0x300B, Pe),
+    (0x300C, 0x300C, Ps),
+    (0x300D, 0x300D, Pe),
+    (0x300E, 0x300E, Ps),
+    (0x300F, 0x300F, Pe),
+    (0x3010, 0x3010, Ps),
+    (0x3011, 0x3011, Pe),
+    (0x3014, 0x3014, Ps),
+    (0x3015, 0x3015, Pe),
+    (0x3016, 0x3016, Ps),
+    (0x3017, 0x3017, Pe),
+    (0x3018, 0x3018, Ps),
+    (0x3019, 0x3019, Pe),
+    (0x301A, 0x301A, Ps),
+    (0x301B, 0x301B, Pe),
+    (0x301C, 0x301C, Pd),
+    (0x301D, 0x301D, Ps),
+    (0x301E, 0x301F, Pe),
+    (0x3021, 0x3029, Nl),
+    (0x302A, 0x302D, Mn),
+    (0x302E, 0x302F, Mc),
+    (0x3030, 0x3030, Pd),
+    (0x3031, 0x3035, Lm),
+    (0x3038, 0x303A, Nl),
+    (0x303B, 0x303B, Lm),
+    (0x303D, 0x303D, Po),
+    (0x3099, 0x309A, Mn),
+    (0x309D, 0x309E, Lm),
+    (0x30A0, 0x30A0, Pd),
+    (0x30FB, 0x30FB, Po),
+    (0x30FC, 0x30FE, Lm),
+    (0xA015, 0xA015, Lm),
+    (0xA4F8, 0xA4FD, Lm),
+    (0xA60C, 0xA60C, Lm),
+    (0xA620, 0xA629, Nd),
+    (0xA66F, 0xA66F, Mn),
+    (0xA670, 0xA672, Me),
+    (0xA674, 0xA67D, Mn),
+    (0xA67F, 0xA67F, Lm),
+    (0xA69C, 0xA69D, Lm),
+    (0xA69E, 0xA69F, Mn),
+    (0xA6E6, 0xA6EF, Nl),
+    (0xA6F0, 0xA6F1, Mn),
+    (0xA717, 0xA71F, Lm),
+    (0xA770, 0xA770, Lm),
+    (0xA788, 0xA788, Lm),
+    (0xA7F2, 0xA7F4, Lm),
+    (0xA7F8, 0xA7F9, Lm),
+    (0xA802, 0xA802, Mn),
+    (0xA806, 0xA806, Mn),
+    (0xA80B, 0xA80B, Mn),
+    (0xA823, 0xA824, Mc),
+    (0xA825, 0xA826, Mn),
+    (0xA827, 0xA827, Mc),
+    (0xA82C, 0xA82C, Mn),
+    (0xA880, 0xA881, Mc),
+    (0xA8B4, 0xA8C3, Mc),
+    (0xA8C4, 0xA8C5, Mn),
+    (0xA8D0, 0xA8D9, Nd),
+    (0xA8E0, 0xA8F1, Mn),
+    (0xA8FF, 0xA8FF, Mn),
+    (0xA900, 0xA909, Nd),
+    (0xA926, 0xA92D, Mn),
+    (0xA947, 0xA951, Mn),
+    (0xA952, 0xA953, Mc),
+    (0xA980, 0xA982, Mn),
+    (0xA983, 0xA983, Mc),
+    (0xA9B3, 0xA9B3, Mn),
+    (0xA9B4, 0xA9B5, Mc),
+    (0xA9B6, 0xA9B9, Mn),
+    (0xA9BA, 0xA9BB, Mc),
+    (0xA9BC, 0xA9BD, Mn),
+    (0xA9BE, 0xA9C0, Mc),
+    (0xA9CF, 0xA9CF, Lm),
+    (0xA9D0, 0xA9D9, Nd),
+    (0xA9E5, 0xA9E5, Mn),
+    (0xA9E6, 0xA9E6, Lm),
+    (0xA9F0, 0xA9F9, Nd),
+    (0xAA29, 0xAA2E, Mn),
+    (0xAA2F, 0xAA30, Mc),
+    (0xAA31, 0xAA32, Mn),
+    (0xAA33, 0xAA34, Mc),
+    (0xAA35, 0xAA36, Mn),
+    (0xAA43, 0xAA43, Mn),
+    (0xAA4C, 0xAA4C, Mn),
+    (0xAA4D, 0xAA4D, Mc),
+    (0xAA50, 0xAA59, Nd),
+    (0xAA70, 0xAA70, Lm),
+    (0xAA7B, 0xAA7B, Mc),
+    (0xAA7C, 0xAA7C, Mn),
+    (0xAA7D, 0xAA7D, Mc),
+    (0xAAB0, 0xAAB0, Mn),
+    (0xAAB2, 0xAAB4, Mn),
+    (0xAAB7, 0xAAB8, Mn),
+    (0xAABE, 0xAABF, Mn),
+    (0xAAC1, 0xAAC1, Mn),
+    (0xAADD, 0xAADD, Lm),
+    (0xAAEB, 0xAAEB, Mc),
+    (0xAAEC, 0xAAED, Mn),
+    (0xAAEE, 0xAAEF, Mc),
+    (0xAAF3, 0xAAF4, Lm),
+    (0xAAF5, 0xAAF5, Mc),
+    (0xAAF6, 0xAAF6, Mn),
+    (0xAB5C, 0xAB5F, Lm),
+    (0xAB69, 0xAB69, Lm),
+    (0xABE3, 0xABE4, Mc),
+    (0xABE5, 0xABE5, Mn),
+    (0xABE6, 0xABE7, Mc),
+    (0xABE8, 0xABE8, Mn),
+    (0xABE9, 0xABEA, Mc),
+    (0xABEC, 0xABEC, Mc),
+    (0xABED, 0xABED, Mn),
+    (0xABF0, 0xABF9, Nd),
+    (0xE000, 0xF8FF, Co),
+    (0xFB1E, 0xFB1E, Mn),
+    (0xFE00, 0xFE0F, Mn),
+    (0xFE20, 0xFE2F, Mn),
+    (0xFEFF, 0xFEFF, Cf),
+    (0xFF01, 0xFF03, Po),
+    (0xFF04, 0xFF04, Sc),
+    (0xFF05, 0xFF07, Po),
+    (0xFF08, 0xFF08, Ps),
+    (0xFF09, 0xFF09, Pe),
+    (0xFF0A, 0xFF0A, Po),
+    (0xFF0B, 0xFF0B, Sm),
+    (0xFF0C, 0xFF0C, Po),
+    (0xFF0D, 0xFF0D, Pd),
+    (0xFF0E, 0xFF0F, Po),
+    (0xFF10, 0xFF19, Nd),
+    (0xFF1A, 0xFF1B, Po),
+    (0xFF1C, 0xFF1E, Sm),
+    (0xFF1F, 0xFF20, Po),
+    (0xFF3B, 0xFF3B, Ps),
+    (0xFF3C, 0xFF3C, Po),
+    (0xFF3D, 0xFF3D, Pe),
+    (0xFF3F, 0xFF3F, Pc),
+    (0xFF5B, 0xFF5B, Ps),
+    (0xFF5D, 0xFF5D, Pe),
+    (0xFF5F, 0xFF5F, Ps),
+    (0xFF60, 0xFF60, Pe),
+    (0xFF61, 0xFF61, Po),
+    (0xFF62, 0xFF62, Ps),
+    (0xFF63, 0xFF63, Pe),
+    (0xFF64, 0xFF65, Po),
+    (0xFF70, 0xFF70, Lm),
+    (0xFF9E, 0xFF9F, Lm),
+    (0xFFFC, 0xFFFD, So),
+    (0x10140, 0x10174, Nl),
+    (0x101FD, 0x101FD, Mn),
+    (0x102E0, 0x102E0, Mn),
+    (0x10341, 0x10341, Nl),
+    (0x1034A, 0x1034A, Nl),
+    (0x10376, 0x1037A, Mn),
+    (0x103D1, 0x103D5, Nl),
+    (0x104A0, 0x104A9, Nd),
+    (0x10780, 0x10785, Lm),
+    (0x10787, 0x107B0, Lm),
+    (0x107B2, 0x107BA, Lm),
+    (0x10A01, 0x10A03, Mn),
+    (0x10A05, 0x10A06, Mn),
+    (0x10A0C, 0x10A0F, Mn),
+    (0x10A38, 0x10A3A, Mn),
+    (0x10A3F, 0x10A3F, Mn),
+    (0x10AE5, 0x10AE6, Mn),
+    (0x10D24, 0x10D27, Mn),
+    (0x10D30, 0x10D39, Nd),
+    (0x10EAB, 0x10EAC, Mn),
+    (0x10F46, 0x10F50, Mn),
+    (0x10F82, 0x10F85, Mn),
+    (0x11000, 0x11000, Mc),
+    (0x11001, 0x11001, Mn),
+    (0x11002, 0x11002, Mc),
+    (0x11038, 0x11046, Mn),
+    (0x11066, 0x1106F, Nd),
+    (0x11070, 0x11070, Mn),
+    (0x11073, 0x11074, Mn),
+    (0x1107F, 0x11081, Mn),
+    (0x11082, 0x11082, Mc),
+    (0x110B0, 0x110B2, Mc),
+    (0x110B3, 0x110B6, Mn),
+    (0x110B7, 0x110B8, Mc),
+    (0x110B9, 0x110BA, Mn),
+    (0x110C2, 0x110C2, Mn),
+    (0x110F0, 0x110F9, Nd),
+    (0x11100, 0x11102, Mn),
+    (0x11127, 0x1112B, Mn),
+    (0x1112C, 0x1112C, Mc),
+    (0x1112D, 0x11134, Mn),
+    (0x11136, 0x1113F, Nd),
+    (0x11145, 0x11146, Mc),
+    (0x11173, 0x11173, Mn),
+    (0x11180, 0x11181, Mn),
+    (0x11182, 0x11182, Mc),
+    (0x111B3, 0x111B5, Mc),
+    (0x111B6, 0x111BE, Mn),
+    (0x111BF, 0x111C0, Mc),
+    (0x111C9, 0x111CC, Mn),
+    (0x111CE, 0x111CE, Mc),
+    (0x111CF, 0x111CF, Mn),
+    (0x111D0, 0x111D9, Nd),
+    (0x1122C, 0x1122E, Mc),
+    (0x1122F, 0x11231, Mn),
+    (0x11232, 0x11233, Mc),
+    (0x11234, 0x11234, Mn),
+    (0x11235, 0x11235, Mc),
+    (0x11236, 0x11237, Mn),
+    (0x1123E, 0x1123E, Mn),
+    (0x112DF, 0x112DF, Mn),
+    (0x112E0, 0x112E2, Mc),
+    (0x112E3, 0x112EA, Mn),
+    (0x112F0, 0x112F9, Nd),
+    (0x11300, 0x11301, Mn),
+    (0x11302, 0x11303, Mc),
+    (0x1133B, 0x1133C, Mn),
+    (0x1133E, 0x1133F, Mc),
+    (0x11340, 0x11340, Mn),
+    (0x11341, 0x11344, Mc),
+    (0x11347, 0x11348, Mc),
+    (0x1134B, 0x1134D, Mc),
+    (0x11357, 0x11357, Mc),
+    (0x11362, 0x11363, Mc),
+    (0x11366, 0x1136C, Mn),
+    (0x11370, 0x11374, Mn),
+    (0x11435, 0x11437, Mc),
+    (0x11438, 0x1143F, Mn),
+    (0x11440, 0x11441, Mc),
+    (0x11442, 0x11444, Mn),
+    (0x11445, 0x11445, Mc),
+    (0x11446, 0x11446, Mn),
+    (0x11450, 0x11459, Nd),
+    (0x1145E, 0x1145E, Mn),
+    (0x114B0, 0x114B2, Mc),
+    (0x114B3, 0x114B8, Mn),
+    (0x114B9, 0x114B9, Mc),
+    (0x114BA, 0x114BA, Mn),
+    (0x114BB, 0x114BE, Mc),
+    (0x114BF, 0x114C0, Mn),
+    (0x114C1, 0x114C1, Mc),
+    (0x114C2, 0x114C3, Mn),
+    (0x114D0, 0x114D9, Nd),
+    (0x115AF, 0x115B1, Mc),
+    (0x115B2, 0x115B5, Mn),
+    (0x115B8, 0x115BB, Mc),
+    (0x115BC, 0x115BD, Mn),
+    (0x115BE, 0x115BE, Mc),
+    (0x115BF, 0x115C0, Mn),
+    (0x115DC, 0x115DD, Mn),
+    (0x11630, 0x11632, Mc),
+    (0x11633, 0x1163A, Mn),
+    (0x1163B, 0x1163C, Mc),
+    (0x1163D, 0x1163D, Mn),
+    (0x1163E, 0x1163E, Mc),
+    (0x1163F, 0x11640, Mn),
+    (0x11650, 0x11659, Nd),
+    (0x116AB, 0x116AB, Mn),
+    (0x116AC, 0x116AC, Mc),
+    (0x116AD, 0x116AD, Mn),
+    (0x116AE, 0x116AF, Mc),
+    (0x116B0, 0x116B5, Mn),
+    (0x116B6, 0x116B6, Mc),
+    (0x116B7, 0x116B7, Mn),
+    (0x116C0, 0x116C9, Nd),
+    (0x1171D, 0x1171F, Mn),
+    (0x11720, 0x11721, Mc),
+    (0x11722, 0x11725, Mn),
+    (0x11726, 0x11726, Mc),
+    (0x11727, 0x1172B, Mn),
+    (0x11730, 0x11739, Nd),
+    (0x1182C, 0x1182E, Mc),
+    (0x1182F, 0x11837, Mn),
+    (0x11838, 0x11838, Mc),
+    (0x11839, 0x1183A, Mn),
+    (0x118E0, 0x118E9, Nd),
+    (0x11930, 0x11935, Mc),
+    (0x11937, 0x11938, Mc),
+    (0x1193B, 0x1193C, Mn),
+    (0x1193D, 0x1193D, Mc),
+    (0x1193E, 0x1193E, Mn),
+    (0x11940, 0x11940, Mc),
+    (0x11942, 0x11942, Mc),
+    (0x11943, 0x11943, Mn),
+    (0x11950, 0x11959, Nd),
+    (0x119D1, 0x119D3, Mc),
+    (0x119D4, 0x119D7, Mn),
+    (0x119DA, 0x119DB, Mn),
+    (0x119DC, 0x119DF, Mc),
+    (0x119E0, 0x119E0, Mn),
+    (0x119E4, 0x119E4, Mc),
+    (0x11A01, 0x11A0A, Mn),
+    (0x11A33, 0x11A38, Mn),
+    (0x11A39, 0x11A39, Mc),
+    (0x11A3B, 0x11A3E, Mn),
+    (0x11A47, 0x11A47, Mn),
+    (0x11A51, 0x11A56, Mn),
+    (0x11A57, 0x11A58, Mc),
+    (0x11A59, 0x11A5B, Mn),
+    (0x11A8A, 0x11A96, Mn),
+    (0x11A97, 0x11A97, Mc),
+    (0x11A98, 0x11A99, Mn),
+    (0x11C2F, 0x11C2F, Mc),
+    (0x11C30, 0x11C36, Mn),
+    (0x11C38, 0x11C3D, Mn),
+    (0x11C3E, 0x11C3E, Mc),
+    (0x11C3F, 0x11C3F, Mn),
+    (0x11C50, 0x11C59, Nd),
+    (0x11C92, 0x11CA7, Mn),
+    (0x11CA9, 0x11CA9, Mc),
+    (0x11CAA, 0x11CB0, Mn),
+    (0x11CB1, 0x11CB1, Mc),
+    (0x11CB2, 0x11CB3, Mn),
+    (0x11CB4, 0x11CB4, Mc),
+    (0x11CB5, 0x11CB6, Mn),
+    (0x11D31, 0x11D36, Mn),
+    (0x11D3A, 0x11D3A, Mn),
+    (0x11D3C, 0x11D3D, Mn),
+    (0x11D3F, 0x11D45, Mn),
+    (0x11D47, 0x11D47, Mn),
+    (0x11D50, 0x11D59, Nd),
+    (0x11D8A, 0x11D8E, Mc),
+    (0x11D90, 0x11D91, Mn),
+    (0x11D93, 0x11D94, Mc),
+    (0x11D95, 0x11D95, Mn),
+    (0x11D96, 0x11D96, Mc),
+    (0x11D97, 0x11D97, Mn),
+    (0x11DA0, 0x11DA9, Nd),
+    (0x11EF3, 0x11EF4, Mn),
+    (0x11EF5, 0x11EF6, Mc),
+    (0x12400, 0x1246E, Nl),
+    (0x16A60, 0x16A69, Nd),
+    (0x16AC0, 0x16AC9, Nd),
+    (0x16AF0, 0x16AF4, Mn),
+    (0x16B30, 0x16B36, Mn),
+    (0x16B40, 0x16B43, Lm),
+    (0x16B50, 0x16B59, Nd),
+    (0x16F4F, 0x16F4F, Mn),
+    (0x16F51, 0x16F87, Mc),
+    (0x16F8F, 0x16F92, Mn),
+    (0x16F93, 0x16F9F, Lm),
+    (0x16FE0, 0x16FE1, Lm),
+    (0x16FE3, 0x16FE3, Lm),
+    (0x16FE4, 0x16FE4, Mn),
+    (0x16FF0, 0x16FF1, Mc),
+    (0x1AFF0, 0x1AFF3, Lm),
+    (0x1AFF5, 0x1AFFB, Lm),
+    (0x1AFFD, 0x1AFFE, Lm),
+    (0x1BC9D, 0x1BC9E, Mn),
+    (0x1CF00, 0x1CF2D, Mn),
+    (0x1CF30, 0x1CF46, Mn),
+    (0x1D165, 0x1D166, Mc),
+    (0x1D167, 0x1D169, Mn),
+    (0x1D16D, 0x1D172, Mc),
+    (0x1D17B, 0x1D182, Mn),
+    (0x1D185, 0x1D18B, Mn),
+    (0x1D1AA, 0x1D1AD, Mn),
+    (0x1D242, 0x1D244, Mn),
+    (0x1D7CE, 0x1D7FF, Nd),
+    (0x1DA00, 0x1DA36, Mn),
+    (0x1DA3B, 0x1DA6C, Mn),
+    (0x1DA75, 0x1DA75, Mn),
+    (0x1DA84, 0x1DA84, Mn),
+    (0x1DA9B, 0x1DA9F, Mn),
+    (0x1DAA1, 0x1DAAF, Mn),
+    (0x1E000, 0x1E006, Mn),
+    (0x1E008, 0x1E018, Mn),
+    (0x1E01B, 0x1E021, Mn),
+    (0x1E023, 0x1E024, Mn),
+    (0x1E026, 0x1E02A, Mn),
+    (0x1E130, 0x1E136, Mn),
+    (0x1E137, 0x1E13D, Lm),
+    (0x1E140, 0x1E149, Nd),
+    (0x1E2AE, 0x1E2AE, Mn),
+    (0x1E2EC, 0x1E2EF, Mn),
+    (0x1E2F0, 0x1E2F9, Nd),
+    (0x1E8D0, 0x1E8D6, Mn),
+    (0x1E944, 0x1E94A, Mn),
+    (0x1E94B, 0x1E94B, Lm),
+    (0x1E950, 0x1E959, Nd),
+    (0x1F130, 0x1F149, So),
+    (0x1F150, 0x1F169, So),
+    (0x1F170, 0x1F189, So),
+    (0x1F1E6, 0x1F1FF, So),
+    (0x1F300, 0x1F3FA, So),
+    (0x1F3FB, 0x1F3FF, Sk),
+    (0x1F400, 0x1F64F, So),
+    (0x1F680, 0x1F6D7, So),
+    (0x1F900, 0x1F9FF, So),
+    (0x1FBF0, 0x1FBF9, Nd),
+    (0xE0001, 0xE0001, Cf),
+    (0xE0020, 0xE007F, Cf),
+    (0xE0100, 0xE01EF, Mn),
+    (0xF0000, 0xFFFFD, Co),
+    (0x100000, 0x10FFFD, Co),
+];
+
+/// Names that can't be derived, sorted by code point. Control characters have no name in
+/// Unicode, only aliases, which are shown in parentheses.
+pub(super) const NAMES: &[(u32, &str)] = &[
+    (0x0000, "<control> (NULL)"),
+    (0x0007, "<control> (BELL)"),
+    (0x0008, "<control> (BACKSPACE)"),
+    (0x0009, "<control> (CHARACTER TABULATION)"),
+    (0x000A, "<control> (LINE FEED)"),
+    (0x000B, "<control> (LINE TABULATION)"),
+    (0x000C, "<control> (FORM FEED)"),
+    (0x000D, "<control> (CARRIAGE RETURN)"),
+    (0x001B, "<control> (ESCAPE)"),
+    (0x0020, "SPACE"),
+    (0x0021, "EXCLAMATION MARK"),
+    (0x0022, "QUOTATION MARK"),
+    (0x0023, "NUMBER SIGN"),
+    (0x0024, "DOLLAR SIGN"),
+    (0x0025, "PERCENT SIGN"),
+    (0x0026, "AMPERSAND"),
+    (0x0027, "APOSTROPHE"),
+    (0x0028, "LEFT PARENTHESIS"),
+    (0x0029, "RIGHT PARENTHESIS"),
+    (0x002A, "ASTERISK"),
+    (0x002B, "PLUS SIGN"),
+    (0x002C, "COMMA"),
+    (0x002D, "HYPHEN-MINUS"),
+    (0x002E, "FULL STOP"),
+    (0x002F, "SOLIDUS"),
+    (0x003A, "COLON"),
+    (0x003B, "SEMICOLON"),
+    (0x003C, "LESS-THAN SIGN"),
+    (0x003D, "EQUALS SIGN"),
+    (0x003E, "GREATER-THAN SIGN"),
+    (0x003F, "QUESTION MARK"),
+    (0x0040, "COMMERCIAL AT"),
+    (0x005B, "LEFT SQUARE BRACKET"),
+    (0x005C, "REVERSE SOLIDUS"),
+    (0x005D, "RIGHT SQUARE BRACKET"),
+    (0x005E, "CIRCUMFLEX ACCENT"),
+    (0x005F, "LOW LINE"),
+    (0x0060, "GRAVE ACCENT"),
+    (0x007B, "LEFT CURLY BRACKET"),
+    (0x007C, "VERTICAL LINE"),
+    (0x007D, "RIGHT CURLY BRACKET"),
+    (0x007E, "TILDE"),
+    (0x007F, "<control> (DELETE)"),
+    (0x00A0, "NO-BREAK SPACE"),
+    (0x00A1, "INVERTED EXCLAMATION MARK"),
+    (0x00A2, "CENT SIGN"),
+    (0x00A3, "POUND SIGN"),
+    (0x00A4, "CURRENCY SIGN"),
+    (0x00A5, "YEN SIGN"),
+    (0x00A6, "BROKEN BAR"),
+    (0x00A7, "SECTION SIGN"),
+    (0x00A8, "DIAERESIS"),
+    (0x00A9, "COPYRIGHT SIGN"),
+    (0x00AA, "FEMININE ORDINAL INDICATOR"),
+    (0x00AB, "LEFT-POINTING DOUBLE ANGLE QUOTATION MARK"),
+    (0x00AC, "NOT SIGN"),
+    (0x00AD, "SOFT HYPHEN"),
+    (0x00AE, "REGISTERED SIGN"),
+    (0x00AF, "MACRON"),
+    (0x00B0, "DEGREE SIGN"),
+    (0x00B1, "PLUS-MINUS SIGN"),
+    (0x00B2, "SUPERSCRIPT TWO"),
+    (0x00B3, "SUPERSCRIPT THREE"),
+    (0x00B4, "ACUTE ACCENT"),
+    (0x00B5, "MICRO SIGN"),
+    (0x00B6, "PILCROW SIGN"),
+    (0x00B7, "MIDDLE DOT"),
+    (0x00B8, "CEDILLA"),
+    (0x00B9, "SUPERSCRIPT ONE"),
+    (0x00BA, "MASCULINE ORDINAL INDICATOR"),
+    (0x00BB, "RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK"),
+    (0x00BC, "VULGAR FRACTION ONE QUARTER"),
+    (0x00BD, "VULGAR FRACTION ONE HALF"),
+    (0x00BE, "VULGAR FRACTION THREE QUARTERS"),
+    (0x00BF, "INVERTED QUESTION MARK"),
+    (0x00C0, "LATIN CAPITAL LETTER A WITH GRAVE"),
+    (0x00C1, "LATIN CAPITAL LETTER A WITH ACUTE"),
+    (0x00C2, "LATIN CAPITAL LETTER A WITH CIRCUMFLEX"),
+    (0x00C3, "LATIN CAPITAL LETTER A WITH TILDE"),
+    (0x00C4, "LATIN CAPITAL LETTER A WITH DIAERESIS"),
+    (0x00C5, "LATIN CAPITAL LETTER A WITH RING ABOVE"),
+    (0x00C6, "LATIN CAPITAL LETTER AE"),
+    (0x00C7, "LATIN CAPITAL LETTER C WITH CEDILLA"),
+    (0x00C8, "LATIN CAPITAL LETTER E WITH GRAVE"),
+    (0x00C9, "LATIN CAPITAL LETTER E WITH ACUTE"),
+    (0x00CA, "LATIN CAPITAL LETTER E WITH CIRCUMFLEX"),
+    (0x00CB, "LATIN CAPITAL LETTER E WITH DIAERESIS"),
+    (0x00CC, "LATIN CAPITAL LETTER I WITH GRAVE"),
+    (0x00CD, "LATIN CAPITAL LETTER I WITH ACUTE"),
+    (0x00CE, "LATIN CAPITAL LETTER I WITH CIRCUMFLEX"),
+    (0x00CF, "LATIN CAPITAL LETTER I WITH DIAERESIS"),
+    (0x00D0, "LATIN CAPITAL LETTER ETH"),
+    (0x00D1, "LATIN CAPITAL LETTER N WITH TILDE"),
+    (0x00D2, "LATIN CAPITAL LETTER O WITH GRAVE"),
+    (0x00D3, "LATIN CAPITAL LETTER O WITH ACUTE"),
+    (0x00D4, "LATIN CAPITAL LETTER O WITH CIRCUMFLEX"),
+    (0x00D5, "LATIN CAPITAL LETTER O WITH TILDE"),
+    (0x00D6, "LATIN CAPITAL LETTER O WITH DIAERESIS"),
+    (0x00D7, "MULTIPLICATION SIGN"),
+    (0x00D8, "LATIN CAPITAL LETTER O WITH STROKE"),
+    (0x00D9, "LATIN CAPITAL LETTER U WITH GRAVE"),
+    (0x00DA, "LATIN CAPITAL LETTER U WITH ACUTE"),
+    (0x00DB, "LATIN CAPITAL LETTER U WITH CIRCUMFLEX"),
+    (0x00DC, "LATIN CAPITAL LETTER U WITH DIAERESIS"),
+    (0x00DD, "LATIN CAPITAL LETTER Y WITH ACUTE"),
+    (0x00DE, "LATIN CAPITAL LETTER THORN"),
+    (0x00DF, "LATIN SMALL LETTER SHARP S"),
+    (0x00E0, "LATIN SMALL LETTER A WITH GRAVE"),
+    (0x00E1, "LATIN SMALL LETTER A WITH ACUTE"),
+    (0x00E2, "LATIN SMALL LETTER A WITH CIRCUMFLEX"),
+    (0x00E3, "LATIN SMALL LETTER A WITH TILDE"),
+    (0x00E4, "LATIN SMALL LETTER A WITH DIAERESIS"),
+    (0x00E5, "LATIN SMALL LETTER A WITH RING ABOVE"),
+    (0x00E6, "LATIN SMALL LETTER AE"),
+    (0x00E7, "LATIN SMALL LETTER C WITH CEDILLA"),
+    (0x00E8, "LATIN SMALL LETTER E WITH GRAVE"),
+    (0x00E9, "LATIN SMALL LETTER E WITH ACUTE"),
+    (0x00EA, "LATIN SMALL LETTER E WITH CIRCUMFLEX"),
+    (0x00EB, "LATIN SMALL LETTER E WITH DIAERESIS"),
+    (0x00EC, "LATIN SMALL LETTER I WITH GRAVE"),
+    (0x00ED, "LATIN SMALL LETTER I WITH ACUTE"),
+    (0x00EE, "LATIN SMALL LETTER I WITH CIRCUMFLEX"),
+    (0x00EF, "LATIN SMALL LETTER I WITH DIAERESIS"),
+    (0x00F0, "LATIN SMALL LETTER ETH"),
+    (0x00F1, "LATIN SMALL LETTER N WITH TILDE"),
+    (0x00F2, "LATIN SMALL LETTER O WITH GRAVE"),
+    (0x00F3, "LATIN SMALL LETTER O WITH ACUTE"),
+    (0x00F4, "LATIN SMALL LETTER O WITH CIRCUMFLEX"),
+    (0x00F5, "LATIN SMALL LETTER O WITH TILDE"),
+    (0x00F6, "LATIN SMALL LETTER O WITH DIAERESIS"),
+    (0x00F7, "DIVISION SIGN"),
+    (0x00F8, "LATIN SMALL LETTER O WITH STROKE"),
+    (0x00F9, "LATIN SMALL LETTER U WITH GRAVE"),
+    (0x00FA, "LATIN SMALL LETTER U WITH ACUTE"),
+    (0x00FB, "LATIN SMALL LETTER U WITH CIRCUMFLEX"),
+    (0x00FC, "LATIN SMALL LETTER U WITH DIAERESIS"),
+    (0x00FD, "LATIN SMALL LETTER Y WITH ACUTE"),
+    (0x00FE, "LATIN SMALL LETTER THORN"),
+    (0x00FF, "LATIN SMALL LETTER Y WITH DIAERESIS"),
+    (0x0300, "COMBINING GRAVE ACCENT"),
+    (0x0301, "COMBINING ACUTE ACCENT"),
+    (0x0302, "COMBINING CIRCUMFLEX ACCENT"),
+    (0x0303, "COMBINING TILDE"),
+    (0x0304, "COMBINING MACRON"),
+    (0x0305, "COMBINING OVERLINE"),
+    (0x0306, "COMBINING BREVE"),
+    (0x0307, "COMBINING DOT ABOVE"),
+    (0x0308, "COMBINING DIAERESIS"),
+    (0x0309, "COMBINING HOOK ABOVE"),
+    (0x030A, "COMBINING RING ABOVE"),
+    (0x030B, "COMBINING DOUBLE ACUTE ACCENT"),
+    (0x030C, "COMBINING CARON"),
+    (0x0323, "COMBINING DOT BELOW"),
+    (0x0327, "COMBINING CEDILLA"),
+    (0x0328, "COMBINING OGONEK"),
+    (0x0331, "COMBINING MACRON BELOW"),
+    (0x0336, "COMBINING LONG STROKE OVERLAY"),
+    (0x0338, "COMBINING LONG SOLIDUS OVERLAY"),
+    (0x0924, "DEVANAGARI LETTER TA"),
+    (0x0928, "DEVANAGARI LETTER NA"),
+    (0x092E, "DEVANAGARI LETTER MA"),
+    (0x0938, "DEVANAGARI LETTER SA"),
+    (0x0947, "DEVANAGARI VOWEL SIGN E"),
+    (0x094D, "DEVANAGARI SIGN VIRAMA"),
+    (0x2002, "EN SPACE"),
+    (0x2003, "EM SPACE"),
+    (0x2009, "THIN SPACE"),
+    (0x200B, "ZERO WIDTH SPACE"),
+    (0x200C, "ZERO WIDTH NON-JOINER"),
+    (0x200D, "ZERO WIDTH JOINER"),
+    (0x200E, "LEFT-TO-RIGHT MARK"),
+    (0x200F, "RIGHT-TO-LEFT MARK"),
+    (0x2013, "EN DASH"),
+    (0x2014, "EM DASH"),
+    (0x2018, "LEFT SINGLE QUOTATION MARK"),
+    (0x2019, "RIGHT SINGLE QUOTATION MARK"),
+    (0x201C, "LEFT DOUBLE QUOTATION MARK"),
+    (0x201D, "RIGHT DOUBLE QUOTATION MARK"),
+    (0x2022, "BULLET"),
+    (0x2026, "HORIZONTAL ELLIPSIS"),
+    (0x2028, "LINE SEPARATOR"),
+    (0x2029, "PARAGRAPH SEPARATOR"),
+    (0x202F, "NARROW NO-BREAK SPACE"),
+    (0x2060, "WORD JOINER"),
+    (0x20AC, "EURO SIGN"),
+    (0x20DD, "COMBINING ENCLOSING CIRCLE"),
+    (0x20E3, "COMBINING ENCLOSING KEYCAP"),
+    (0x2122, "TRADE MARK SIGN"),
+    (0x2190, "LEFTWARDS ARROW"),
+    (0x2191, "UPWARDS ARROW"),
+    (0x2192, "RIGHTWARDS ARROW"),
+    (0x2193, "DOWNWARDS ARROW"),
+    (0x221E, "INFINITY"),
+    (0x2260, "NOT EQUAL TO"),
+    (0x25CC, "DOTTED CIRCLE"),
+    (0x2600, "BLACK SUN WITH RAYS"),
+    (0x2705, "WHITE HEAVY CHECK MARK"),
+    (0x2764, "HEAVY BLACK HEART"),
+    (0x2B06, "UPWARDS BLACK ARROW"),
+    (0x2B07, "DOWNWARDS BLACK ARROW"),
+    (0x3000, "IDEOGRAPHIC SPACE"),
+    (0x3001, "IDEOGRAPHIC COMMA"),
+    (0x3002, "IDEOGRAPHIC FULL STOP"),
+    (0x3099, "COMBINING KATAKANA-HIRAGANA VOICED SOUND MARK"),
+    (0xFE0E, "VARIATION SELECTOR-15"),
+    (0xFE0F, "VARIATION SELECTOR-16"),
+    (0xFEFF, "ZERO WIDTH NO-BREAK SPACE"),
+    (0xFFFD, "REPLACEMENT CHARACTER"),
+    (0x1F30D, "EARTH GLOBE EUROPE-AFRICA"),
+    (0x1F389, "PARTY POPPER"),
+    (0x1F3FB, "EMOJI MODIFIER FITZPATRICK TYPE-1-2"),
+    (0x1F3FC, "EMOJI MODIFIER FITZPATRICK TYPE-3"),
+    (0x1F3FD, "EMOJI MODIFIER FITZPATRICK TYPE-4"),
+    (0x1F3FE, "EMOJI MODIFIER FITZPATRICK TYPE-5"),
+    (0x1F3FF, "EMOJI MODIFIER FITZPATRICK TYPE-6"),
+    (0x1F408, "CAT"),
+    (0x1F431, "CAT FACE"),
+    (0x1F44B, "WAVING HAND SIGN"),
+    (0x1F44D, "THUMBS UP SIGN"),
+    (0x1F466, "BOY"),
+    (0x1F467, "GIRL"),
+    (0x1F468, "MAN"),
+    (0x1F469, "WOMAN"),
+    (0x1F4BB, "PERSONAL COMPUTER"),
+    (0x1F525, "FIRE"),
+    (0x1F52C, "MICROSCOPE"),
+    (0x1F600, "GRINNING FACE"),
+    (0x1F602, "FACE WITH TEARS OF JOY"),
+    (0x1F63B, "SMILING CAT FACE WITH HEART-SHAPED EYES"),
+    (0x1F680, "ROCKET"),
+    (0x1F980, "CRAB"),
+    (0x1F9D1, "ADULT"),
+];
+
+const DIGITS: &[&str] = &[
+    "ZERO", "ONE", "TWO", "THREE", "FOUR", "FIVE", "SIX", "SEVEN", "EIGHT", "NINE",
+];
+
+/// Greek letters in code point order from U+0391/U+03B1; the gap at U+03A2 holds final sigma
+/// in the lowercase block and nothing in the uppercase one.
+const GREEK: &[&str] = &[
+    "ALPHA",
+    "BETA",
+    "GAMMA",
+    "DELTA",
+    "EPSILON",
+    "ZETA",
+    "ETA",
+    "THETA",
+    "IOTA",
+    "KAPPA",
+    "LAMDA",
+    "MU",
+    "NU",
+    "XI",
+    "OMICRON",
+    "PI",
+    "RHO",
+    "FINAL SIGMA",
+    "SIGMA",
+    "TAU",
+    "UPSILON",
+    "PHI",
+    "CHI",
+    "PSI",
+    "OMEGA",
+];
+
+/// The short names Unicode builds Hangul syllable names from (chapter 3.12 of the standard).
+const HANGUL_LEADS: &[&str] = &[
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P",
+    "H",
+];
+const HANGUL_VOWELS: &[&str] = &[
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE",
+    "WI", "YU", "EU", "YI", "I",
+];
+const HANGUL_TAILS: &[&str] = &[
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M",
+    "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+
+pub(super) const HANGUL_SYLLABLES: std::ops::RangeInclusive<u32> = 0xAC00..=0xD7A3;
+pub(super) const HANGUL_TAIL_COUNT: u32 = 28;
+
+pub(super) fn name(c: char) -> Option<String> {
+    let code = c as u32;
+
+    match c {
+        'A'..='Z' => return Some(format!("LATIN CAPITAL LETTER {}", c)),
+        'a'..='z' => return Some(format!("LATIN SMALL LETTER {}", c.to_ascii_uppercase())),
+        '0'..='9' => return Some(format!("DIGIT {}", DIGITS[code as usize - 0x30])),
+        _ => (),
+    }
+    if let Ok(index) = NAMES.binary_search_by_key(&code, |&(code, _)| code) {
+        return Some(NAMES[index].1.to_string());
+    }
+
+    match code {
+        0x0391..=0x03A9 if code != 0x03A2 => Some(format!(
+            "GREEK CAPITAL LETTER {}",
+            GREEK[(code - 0x0391) as usize]
+        )),
+        0x03B1..=0x03C9 => Some(format!(
+            "GREEK SMALL LETTER {}",
+            GREEK[(code - 0x03B1) as usize]
+        )),
+        0x1F1E6..=0x1F1FF => Some(format!(
+            "REGIONAL INDICATOR SYMBOL LETTER {}",
+            char::from(b'A' + (code - 0x1F1E6) as u8)
+        )),
+        0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0x20000..=0x2A6DF => {
+            Some(format!("CJK UNIFIED IDEOGRAPH-{:04X}", code))
+        }
+        _ if HANGUL_SYLLABLES.contains(&code) => {
+            let index = code - HANGUL_SYLLABLES.start();
+            let per_lead = HANGUL_VOWELS.len() as u32 * HANGUL_TAIL_COUNT;
+            Some(format!(
+                "HANGUL SYLLABLE {}{}{}",
+                HANGUL_LEADS[(index / per_lead) as usize],
+                HANGUL_VOWELS[(index % per_lead / HANGUL_TAIL_COUNT) as usize],
+                HANGUL_TAILS[(index % HANGUL_TAIL_COUNT) as usize]
+            ))
+        }
+        _ if c.is_control() => Some(String::from("<control>")),
+        0xE000..=0xF8FF | 0xF0000..=0xFFFFD | 0x100000..=0x10FFFD => {
+            Some(String::from("<private use>"))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,237 @@
+//! Splitting text into words, generalizing chapter 4's `first_word` (which stops at the first
+//! ASCII space and takes a `&String`).
+//!
+//! A word is a run of characters that are neither whitespace (including tabs, newlines and
+//! no-break spaces) nor punctuation, except that an apostrophe between two letters stays in the
+//! word, as in "don't". Everything handed out borrows from the input; nothing is copied.
+//! Scripts written without spaces, such as Chinese, are split at their punctuation only.
+//!
+//! Punctuation is what [`unicode::category`] knows to be punctuation: ASCII, Latin-1, General
+//! Punctuation, and the punctuation of Arabic, Devanagari, CJK and fullwidth forms. Marks from
+//! other scripts, such as the Ethiopic full stop `።`, are not in its table and stay in words.
+//!
+//! ```
+//! use the_rust_programming_language::words;
+//!
+//! let text = "hello,\tworld\u{a0}你好。";
+//! assert_eq!(words::words(text).collect::<Vec<_>>(), ["hello", "world", "你好"]);
+//! assert_eq!(words::spans(text).nth(1), Some(7..12));
+//! assert_eq!(words::last_word(text), Some("你好"));
+//! ```
+
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::unicode;
+
+fn is_apostrophe(c: char) -> bool {
+    c == '\'' || c == '\u{2019}'
+}
+
+/// Whether the `c` at byte `index` of `text` ends a word.
+fn is_separator(text: &str, index: usize, c: char) -> bool {
+    if is_apostrophe(c) {
+        let before = text[..index].chars().next_back();
+        let after = text[index + c.len_utf8()..].chars().next();
+        let joins_letters =
+            before.is_some_and(char::is_alphanumeric) && after.is_some_and(char::is_alphanumeric);
+        return !joins_letters;
+    }
+
+    c.is_whitespace()
+        || c.is_ascii_punctuation()
+        || unicode::category(c).is_some_and(|category| category.is_punctuation())
+}
+
+/// The byte range of each word, from either end.
+#[derive(Debug, Clone)]
+pub struct Spans<'a> {
+    text: &'a str,
+    /// Everything outside `front..back` has been handed out already.
+    front: usize,
+    back: usize,
+}
+
+impl<'a> Iterator for Spans<'a> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Range<usize>> {
+        let (text, front) = (self.text, self.front);
+        let mut chars = text[front..self.back]
+            .char_indices()
+            .map(|(index, c)| (front + index, c));
+
+        let start = match chars.find(|&(index, c)| !is_separator(text, index, c)) {
+            Some((index, _)) => index,
+            None => {
+                self.front = self.back;
+                return None;
+            }
+        };
+        let end = chars
+            .find(|&(index, c)| is_separator(text, index, c))
+            .map_or(self.back, |(index, _)| index);
+
+        self.front = end;
+        Some(start..end)
+    }
+}
+
+impl<'a> DoubleEndedIterator for Spans<'a> {
+    fn next_back(&mut self) -> Option<Range<usize>> {
+        let (text, front) = (self.text, self.front);
+        let mut chars = text[front..self.back]
+            .char_indices()
+            .rev()
+            .map(|(index, c)| (front + index, c));
+
+        let end = match chars.find(|&(index, c)| !is_separator(text, index, c)) {
+            Some((index, c)) => index + c.len_utf8(),
+            None => {
+                self.back = self.front;
+                return None;
+            }
+        };
+        let start = chars
+            .find(|&(index, c)| is_separator(text, index, c))
+            .map_or(front, |(index, c)| index + c.len_utf8());
+
+        self.back = start;
+        Some(start..end)
+    }
+}
+
+impl<'a> FusedIterator for Spans<'a> {}
+
+/// The words themselves, as slices of the input.
+#[derive(Debug, Clone)]
+pub struct Words<'a> {
+    spans: Spans<'a>,
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let text = self.spans.text;
+        self.spans.next().map(|span| &text[span])
+    }
+}
+
+impl<'a> DoubleEndedIterator for Words<'a> {
+    fn next_back(&mut self) -> Option<&'a str> {
+        let text = self.spans.text;
+        self.spans.next_back().map(|span| &text[span])
+    }
+}
+
+impl<'a> FusedIterator for Words<'a> {}
+
+pub fn spans(text: &str) -> Spans<'_> {
+    Spans {
+        text,
+        front: 0,
+        back: text.len(),
+    }
+}
+
+pub fn words(text: &str) -> Words<'_> {
+    Words { spans: spans(text) }
+}
+
+pub fn first_word(text: &str) -> Option<&str> {
+    words(text).next()
+}
+
+/// The word at zero-based position `n`.
+pub fn nth_word(text: &str, n: usize) -> Option<&str> {
+    words(text).nth(n)
+}
+
+/// Found from the end, without walking the words before it.
+pub fn last_word(text: &str) -> Option<&str> {
+    words(text).next_back()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{first_word, last_word, nth_word, spans, words};
+
+    #[test]
+    fn splits_on_any_whitespace() {
+        let text = "\tone\ntwo\r\n  three\u{a0}four\u{3000}five ";
+        assert_eq!(
+            words(text).collect::<Vec<_>>(),
+            ["one", "two", "three", "four", "five"]
+        );
+        assert_eq!(words(text).next_back(), Some("five"));
+        assert_eq!(words(" \t\n\u{a0}").next(), None);
+        assert_eq!(words("").next_back(), None);
+    }
+
+    #[test]
+    fn splits_on_punctuation_but_keeps_apostrophes_inside_words() {
+        assert_eq!(
+            words("Hello, world! (It's the world’s end...) 'quoted'").collect::<Vec<_>>(),
+            ["Hello", "world", "It's", "the", "world’s", "end", "quoted"]
+        );
+        assert_eq!(
+            words("«Bonjour» — dit-il").collect::<Vec<_>>(),
+            ["Bonjour", "dit", "il"]
+        );
+    }
+
+    #[test]
+    fn handles_cjk_text() {
+        let text = "你好，世界。東京\u{3000}タワー「です」";
+        assert_eq!(
+            words(text).collect::<Vec<_>>(),
+            ["你好", "世界", "東京", "タワー", "です"]
+        );
+        assert_eq!(spans(text).next(), Some(0..6));
+        assert_eq!(last_word(text), Some("です"));
+    }
+
+    #[test]
+    fn splits_on_punctuation_from_other_scripts() {
+        assert_eq!(words("नमस्ते। दुनिया॥").collect::<Vec<_>>(), ["नमस्ते", "दुनिया"]);
+        assert_eq!(
+            words("مرحبا، كيف حالك؟").collect::<Vec<_>>(),
+            ["مرحبا", "كيف", "حالك"]
+        );
+        assert_eq!(
+            words("what‽ note※here").collect::<Vec<_>>(),
+            ["what", "note", "here"]
+        );
+        // Beyond the table's blocks, punctuation isn't recognized.
+        assert_eq!(words("ሰላም። ዓለም").collect::<Vec<_>>(), ["ሰላም።", "ዓለም"]);
+    }
+
+    #[test]
+    fn spans_are_byte_offsets_from_either_end() {
+        let text = "é a\u{a0}bc";
+        assert_eq!(spans(text).collect::<Vec<_>>(), [0..2, 3..4, 6..8]);
+        assert_eq!(spans(text).rev().collect::<Vec<_>>(), [6..8, 3..4, 0..2]);
+
+        // Meeting in the middle hands each word out once.
+        let mut both = spans("a b c");
+        assert_eq!(both.next(), Some(0..1));
+        assert_eq!(both.next_back(), Some(4..5));
+        assert_eq!(both.next(), Some(2..3));
+        assert_eq!(both.next_back(), None);
+        assert_eq!(both.next(), None);
+    }
+
+    #[test]
+    fn helpers_borrow_from_the_input() {
+        let text = String::from("hello world");
+        let word = first_word(&text).unwrap();
+        assert_eq!(word, "hello");
+        assert_eq!(word.as_ptr(), text.as_ptr());
+
+        assert_eq!(nth_word("zero one two", 1), Some("one"));
+        assert_eq!(nth_word("zero one two", 3), None);
+        assert_eq!(last_word("trailing punctuation!?"), Some("punctuation"));
+        assert_eq!(first_word("...!"), None);
+    }
+}
@@ -0,0 +1,16 @@
+// Integration tests: these only see the library's public API, exactly like any other crate would.
+use the_rust_programming_language::c11::{self, Rectangle};
+
+#[test]
+fn adder_adds_two_numbers() {
+    assert_eq!(c11::adder(2, 2), 4);
+}
+
+#[test]
+fn larger_rectangle_can_hold_smaller_one() {
+    let larger = Rectangle::new(8, 7);
+    let smaller = Rectangle::new(5, 1);
+
+    assert!(larger.can_hold(&smaller));
+    assert!(!smaller.can_hold(&larger));
+}
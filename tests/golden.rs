@@ -0,0 +1,49 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use the_rust_programming_language::chapters::CHAPTERS;
+
+// Chapter 2 is interactive (it reads guesses from stdin and picks a random secret),
+// so it has no fixed output to compare against.
+const INTERACTIVE: &[u8] = &[2];
+
+fn golden_path(number: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("chapter{:02}.txt", number))
+}
+
+// Run with `UPDATE_GOLDEN=1 cargo test` to rewrite the expected files after an
+// intended change to what a chapter prints.
+#[test]
+fn chapters_print_their_golden_output() {
+    let update = env::var_os("UPDATE_GOLDEN").is_some();
+
+    for chapter in CHAPTERS {
+        if INTERACTIVE.contains(&chapter.number()) {
+            continue;
+        }
+
+        let mut out = Vec::new();
+        chapter.run(&mut out).unwrap();
+        let actual = String::from_utf8(out).unwrap();
+        let path = golden_path(chapter.number());
+
+        if update {
+            fs::write(&path, &actual).unwrap();
+            continue;
+        }
+
+        let expected = fs::read_to_string(&path)
+            .unwrap_or_else(|error| panic!("could not read {}: {}", path.display(), error));
+        assert_eq!(
+            actual,
+            expected,
+            "chapter {} no longer prints what {} expects",
+            chapter.number(),
+            path.display()
+        );
+    }
+}